@@ -0,0 +1,259 @@
+use bevy::{input::mouse::AccumulatedMouseMotion, prelude::*, window::PrimaryWindow};
+
+use crate::enemies::{Enemy, Reaper};
+use crate::map::WallGrid;
+use crate::player::{Facing, FacingDirection, Player};
+use crate::stats::StatSheet;
+use crate::weapons::{WeaponInventory, WeaponRegistry, fire_weapon};
+use crate::{GameEntity, GameState};
+
+/// Stick input below this magnitude is treated as centred.
+const STICK_DEADZONE: f32 = 0.25;
+/// Shots leave this far in front of the player along the aim.
+const MUZZLE_OFFSET: f32 = 16.0;
+/// How far ahead of the player the reticle sits when aiming with a stick.
+const STICK_RETICLE_DIST: f32 = 140.0;
+
+/// Half-width of the aim-assist cone, in radians.
+const ASSIST_HALF_ANGLE: f32 = 0.22;
+const ASSIST_RANGE: f32 = 600.0;
+/// How far the aim is bent toward an assisted target. 1.0 snaps onto it.
+const ASSIST_STRENGTH: f32 = 0.8;
+
+const RETICLE_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.85);
+const RETICLE_LOCKED_COLOR: Color = Color::srgba(1.0, 0.35, 0.3, 0.95);
+
+/// The input that last moved the aim.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum AimDevice {
+    #[default]
+    Mouse,
+    Gamepad,
+}
+
+/// Where the player is aiming, independent of which way they are walking.
+/// Every weapon and the player's facing read from this.
+#[derive(Component)]
+pub struct Aim {
+    /// Final aim direction, after assist.
+    pub dir: Vec2,
+    /// Direction straight from the device, before assist bends it.
+    pub raw: Vec2,
+    /// World-space point the reticle is drawn at.
+    pub point: Vec2,
+    pub device: AimDevice,
+    /// Enemy the assist cone pulled the aim onto this frame.
+    pub target: Option<Entity>,
+}
+
+impl Default for Aim {
+    fn default() -> Self {
+        Self {
+            dir: Vec2::NEG_Y,
+            raw: Vec2::NEG_Y,
+            point: Vec2::ZERO,
+            device: AimDevice::Mouse,
+            target: None,
+        }
+    }
+}
+
+/// Whether aim assist is on. Toggled from the settings panel.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AimAssist(pub bool);
+
+impl AimAssist {
+    pub fn label(self) -> &'static str {
+        if self.0 { "On" } else { "Off" }
+    }
+}
+
+#[derive(Component)]
+struct Reticle;
+
+pub struct AimPlugin;
+
+impl Plugin for AimPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AimAssist>()
+            .add_systems(OnEnter(GameState::Playing), spawn_reticle)
+            .add_systems(
+                Update,
+                (update_aim, fire_held_weapon)
+                    .chain()
+                    .after(crate::player::move_player)
+                    .run_if(in_state(GameState::Playing))
+                    .run_if(not(resource_exists::<crate::pause::IsPaused>)),
+            )
+            .add_systems(
+                Update,
+                update_reticle
+                    .after(update_aim)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Snaps an aim direction to the nearest of the eight facings.
+pub fn facing_from_dir(dir: Vec2) -> FacingDirection {
+    let octant = (dir.y.atan2(dir.x) / std::f32::consts::FRAC_PI_4).round() as i32;
+    match octant.rem_euclid(8) {
+        0 => FacingDirection::Right,
+        1 => FacingDirection::UpRight,
+        2 => FacingDirection::Up,
+        3 => FacingDirection::UpLeft,
+        4 => FacingDirection::Left,
+        5 => FacingDirection::DownLeft,
+        6 => FacingDirection::Down,
+        _ => FacingDirection::DownRight,
+    }
+}
+
+/// The first gamepad whose right stick is pushed past the deadzone.
+fn right_stick(gamepads: &Query<&Gamepad>) -> Option<Vec2> {
+    gamepads
+        .iter()
+        .map(|g| g.right_stick())
+        .find(|s| s.length() > STICK_DEADZONE)
+}
+
+/// Reads the mouse and right stick, whichever moved last wins, then bends
+/// the result toward a nearby enemy if aim assist is on.
+pub fn update_aim(
+    assist: Res<AimAssist>,
+    mouse_motion: Res<AccumulatedMouseMotion>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+    q_window: Query<&Window, With<PrimaryWindow>>,
+    q_camera: Query<(&Camera, &GlobalTransform)>,
+    wall_grid: Res<WallGrid>,
+    mut player_q: Query<(&Transform, &mut Aim, &mut Facing), With<Player>>,
+    enemies: Query<(Entity, &Transform), (With<Enemy>, Without<Reaper>, Without<Player>)>,
+) {
+    let Ok((player_tf, mut aim, mut facing)) = player_q.single_mut() else { return; };
+    let player_pos = player_tf.translation.truncate();
+
+    if let Some(stick) = right_stick(&gamepads) {
+        aim.device = AimDevice::Gamepad;
+        aim.raw = stick.normalize();
+    } else if mouse_motion.delta != Vec2::ZERO || buttons.just_pressed(MouseButton::Left) {
+        aim.device = AimDevice::Mouse;
+    }
+
+    match aim.device {
+        AimDevice::Mouse => {
+            let cursor = q_window
+                .single()
+                .ok()
+                .and_then(|w| w.cursor_position())
+                .zip(q_camera.single().ok())
+                .and_then(|(pos, (cam, cam_tf))| cam.viewport_to_world_2d(cam_tf, pos).ok());
+            if let Some(world) = cursor {
+                let to_cursor = (world - player_pos).normalize_or_zero();
+                if to_cursor != Vec2::ZERO {
+                    aim.raw = to_cursor;
+                }
+                aim.point = world;
+            }
+        }
+        // A released stick keeps pointing the way it was last pushed.
+        AimDevice::Gamepad => aim.point = player_pos + aim.raw * STICK_RETICLE_DIST,
+    }
+
+    aim.dir = aim.raw;
+    aim.target = None;
+    if assist.0 {
+        let mut best: Option<(f32, Entity, Vec2)> = None;
+        for (entity, tf) in &enemies {
+            let offset = tf.translation.truncate() - player_pos;
+            let dist = offset.length();
+            if !(1.0..=ASSIST_RANGE).contains(&dist) { continue; }
+            let angle = aim.raw.angle_to(offset).abs();
+            if angle > ASSIST_HALF_ANGLE { continue; }
+            // Don't pull the aim onto something behind a wall.
+            if wall_grid.raycast(player_pos, offset / dist, dist) < dist { continue; }
+            if best.is_none_or(|(a, _, _)| angle < a) {
+                best = Some((angle, entity, offset / dist));
+            }
+        }
+        if let Some((_, entity, to_target)) = best {
+            aim.dir = aim.raw.lerp(to_target, ASSIST_STRENGTH).normalize_or(aim.raw);
+            aim.target = Some(entity);
+        }
+    }
+
+    facing.0 = facing_from_dir(aim.dir);
+}
+
+/// The one fire path for every weapon: left click, Space or a gamepad
+/// trigger, always along the current aim.
+pub fn fire_held_weapon(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+    weapon_registry: Res<WeaponRegistry>,
+    mut player_q: Query<(&Transform, &Aim, &StatSheet, &mut WeaponInventory), With<Player>>,
+) {
+    let Ok((player_tf, aim, sheet, mut inventory)) = player_q.single_mut() else { return; };
+
+    let held = buttons.pressed(MouseButton::Left)
+        || keys.pressed(KeyCode::Space)
+        || gamepads
+            .iter()
+            .any(|g| g.any_pressed([GamepadButton::RightTrigger2, GamepadButton::RightTrigger]));
+    if !held {
+        return;
+    }
+
+    let spawn_pos = player_tf.translation.truncate() + aim.dir * MUZZLE_OFFSET;
+    fire_weapon(&mut commands, &mut inventory, sheet, &weapon_registry, spawn_pos, aim.dir);
+}
+
+fn spawn_reticle(mut commands: Commands) {
+    commands
+        .spawn((
+            Transform::from_xyz(0.0, 0.0, 950.0),
+            Visibility::default(),
+            Reticle,
+            GameEntity,
+        ))
+        .with_children(|r| {
+            // Four ticks around an open centre, plus a dot.
+            for (offset, size) in [
+                (Vec2::new(0.0, 7.0), Vec2::new(2.0, 6.0)),
+                (Vec2::new(0.0, -7.0), Vec2::new(2.0, 6.0)),
+                (Vec2::new(7.0, 0.0), Vec2::new(6.0, 2.0)),
+                (Vec2::new(-7.0, 0.0), Vec2::new(6.0, 2.0)),
+                (Vec2::ZERO, Vec2::splat(2.0)),
+            ] {
+                r.spawn((
+                    Sprite::from_color(RETICLE_COLOR, size),
+                    Transform::from_translation(offset.extend(0.0)),
+                ));
+            }
+        });
+}
+
+/// Keeps the reticle on the aim point. When assist has a target the
+/// reticle sits on it and turns red.
+fn update_reticle(
+    player_q: Query<&Aim, With<Player>>,
+    targets: Query<&Transform, (With<Enemy>, Without<Reticle>)>,
+    mut reticle_q: Query<(&mut Transform, &Children), With<Reticle>>,
+    mut sprites: Query<&mut Sprite>,
+) {
+    let Ok(aim) = player_q.single() else { return; };
+    let Ok((mut tf, children)) = reticle_q.single_mut() else { return; };
+
+    let locked = aim.target.and_then(|e| targets.get(e).ok());
+    let point = locked.map_or(aim.point, |t| t.translation.truncate());
+    tf.translation = point.extend(tf.translation.z);
+
+    let color = if locked.is_some() { RETICLE_LOCKED_COLOR } else { RETICLE_COLOR };
+    for &child in children {
+        if let Ok(mut sprite) = sprites.get_mut(child) {
+            sprite.color = color;
+        }
+    }
+}
@@ -1,20 +1,106 @@
 use bevy::prelude::*;
-use crate::bullet::aabb_overlap;
+use std::collections::HashSet;
+use crate::bullet::{aabb_overlap, BulletOwner, HitEnemies, MarkedForDespawn};
 use crate::{TILE_SIZE, GameState};
 use crate::player::{Player, Facing, FacingDirection};
 use crate::collidable::Collider;
 use crate::enemies::Enemy;
+use crate::damage::{DamageEvent, DamageKind, DamageSource};
 use crate::window::{Health, GlassState, Window};
 use crate::table::Table;
 use crate::enemies::Velocity;
+use crate::weapons::BulletDamage;
+
+const BROOM_LENGTH: f32 = TILE_SIZE * 2.5;
+const BROOM_WIDTH: f32 = TILE_SIZE * 1.0;
+
+const SWING_SECS: f32 = 0.25;
+/// Time after a swing ends in which the next press continues the combo.
+const COMBO_GAP: f32 = 0.35;
+const COMBO_DAMAGE: [f32; 3] = [12.0, 16.0, 28.0];
+const COMBO_KNOCKBACK: [f32; 3] = [180.0, 300.0, 520.0];
+/// Arc each combo hit sweeps through. The finisher goes wide.
+const COMBO_ARC_DEG: [f32; 3] = [180.0, 180.0, 300.0];
+
+const SPIN_SECS: f32 = 0.4;
+const SPIN_DAMAGE: f32 = 30.0;
+const SPIN_KNOCKBACK: f32 = 450.0;
+/// Everything within this distance of the player is caught by a spin.
+const SPIN_RADIUS: f32 = BROOM_LENGTH;
+
+/// Damage multiplier a swept bullet picks up on its way back out. A parry
+/// (hit during the window at the start of a swing) earns the bigger one and
+/// leaves faster than it came.
+const DEFLECT_DAMAGE_MULT: f32 = 1.5;
+const PARRY_DAMAGE_MULT: f32 = 2.5;
+const PARRY_SPEED_MULT: f32 = 1.25;
+/// Parried shots home in on the nearest enemy within this range.
+const PARRY_SEEK_RANGE: f32 = 700.0;
 
 #[derive(Component)]
 pub struct Broom;
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SwingKind {
+    /// Hit 0, 1 or 2 of the combo chain.
+    Combo(usize),
+    /// Charged 360° sweep.
+    Spin,
+}
+
 #[derive(Component)]
 pub struct BroomSwing {
     pub timer: Timer,
     pub active: bool,
+    pub kind: SwingKind,
+    /// Enemy bullets the broom touches before this runs out are parried
+    /// back instead of swept away. Spins have no parry window.
+    pub parry: Option<Timer>,
+    pub damage: f32,
+    pub knockback: f32,
+    /// `BroomStats::power` when the swing started; scales deflected shots too.
+    pub power: f32,
+    /// Enemies this swing has already struck; each is hit once per swing.
+    pub hit: HashSet<Entity>,
+}
+
+/// The player's melee upgrades. Carried over between stations.
+#[derive(Component, Clone, Copy)]
+pub struct BroomStats {
+    /// Multiplier on swing damage and knockback.
+    pub power: f32,
+    /// Length of the parry window at the start of each combo swing.
+    pub parry_secs: f32,
+    /// How long the button has to be held to release a spin.
+    pub spin_charge_secs: f32,
+}
+
+impl Default for BroomStats {
+    fn default() -> Self {
+        Self { power: 1.0, parry_secs: 0.12, spin_charge_secs: 0.9 }
+    }
+}
+
+/// An enemy bullet was knocked back by the broom and now belongs to the
+/// player. `parried` is set when it was caught in the parry window.
+#[derive(Event, Clone, Copy)]
+pub struct BulletDeflectedEvent {
+    pub bullet: Entity,
+    pub pos: Vec2,
+    pub parried: bool,
+}
+
+/// An enemy bullet the broom sent back. It belongs to the player now but
+/// never picks up the equipped weapon's mods.
+#[derive(Component)]
+pub struct Deflected;
+
+/// Where the player is in the combo and how long the button has been held.
+#[derive(Default)]
+struct ComboState {
+    step: usize,
+    since_swing: f32,
+    held: f32,
 }
 
 use crate::bullet::Bullet;
@@ -24,90 +110,171 @@ pub struct BroomPlugin;
 
 impl Plugin for BroomPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, broom_input.run_if(in_state(GameState::Playing)).run_if(not(resource_exists::<crate::pause::IsPaused>)))
+        app.add_event::<BulletDeflectedEvent>()
+           .add_systems(Update, broom_input.run_if(in_state(GameState::Playing)).run_if(not(resource_exists::<crate::pause::IsPaused>)))
            .add_systems(Update, broom_swing_system.run_if(in_state(GameState::Playing)))
            .add_systems(Update, broom_hit_enemies_system.run_if(in_state(GameState::Playing)))
            .add_systems(Update, broom_push_tables_system.run_if(in_state(GameState::Playing)))
            .add_systems(Update, broom_fix_window.run_if(in_state(GameState::Playing)))
-           .add_systems(Update, broom_hit_bullets_system.run_if(in_state(GameState::Playing)));
+           .add_systems(Update, broom_hit_bullets_system.before(crate::bullet::bullet_collision).run_if(in_state(GameState::Playing)))
+           .add_systems(Update, parry_popups.after(broom_hit_bullets_system).run_if(in_state(GameState::Playing)));
+    }
+}
+
+/// True when the swing's sweep reaches `pos` (half-size `half`): the broom's
+/// box for combo hits, a circle around the player for spins.
+fn swing_reaches(swing: &BroomSwing, broom_tf: &Transform, broom_col: &Collider, player_pos: Vec2, pos: Vec2, half: Vec2) -> bool {
+    match swing.kind {
+        SwingKind::Spin => pos.distance(player_pos) <= SPIN_RADIUS + half.max_element(),
+        SwingKind::Combo(_) => aabb_overlap(
+            broom_tf.translation.x, broom_tf.translation.y, broom_col.half_extents,
+            pos.x, pos.y, half,
+        ),
     }
 }
 
+/// Knocks enemy bullets back out as player shots. Ordinary swings send them
+/// off along the broom; parries send them faster and harder at the nearest
+/// enemy.
 pub fn broom_hit_bullets_system(
     mut commands: Commands,
-    broom_query: Query<(&Transform, &Collider), With<Broom>>,
-    bullet_query: Query<(Entity, &Transform, &Collider), With<Bullet>>,
+    broom_query: Query<(&Transform, &Collider, &BroomSwing), With<Broom>>,
+    player_query: Query<&Transform, (With<Player>, Without<Broom>, Without<Bullet>)>,
+    enemies: Query<&Transform, (With<Enemy>, Without<crate::enemies::Reaper>, Without<Bullet>)>,
+    mut bullet_query: Query<
+        (Entity, &Transform, &Collider, &mut BulletOwner, &mut crate::bullet::Velocity, &mut BulletDamage),
+        (With<Bullet>, Without<MarkedForDespawn>),
+    >,
+    mut deflected: EventWriter<BulletDeflectedEvent>,
 ) {
-    let (broom_transform, broom_collider) = match broom_query.single() {
+    let (broom_transform, broom_collider, swing) = match broom_query.single() {
         Ok(b) => b,
         Err(_) => return, // No broom active
     };
+    let Ok(player_tf) = player_query.single() else { return; };
+    let player_pos = player_tf.translation.truncate();
+    let parrying = swing.parry.as_ref().is_some_and(|p| !p.finished());
+    // Direction the broom currently points, out from the player.
+    let along_arc = (broom_transform.rotation * Vec3::X).truncate();
 
-    let broom_center = broom_transform.translation.truncate();
-    let broom_half = broom_collider.half_extents;
-
-    for (bullet_entity, bullet_transform, bullet_collider) in bullet_query.iter() {
+    for (bullet_entity, bullet_transform, bullet_collider, mut owner, mut vel, mut damage) in &mut bullet_query {
+        if !matches!(*owner, BulletOwner::Enemy) { continue; }
         let bullet_center = bullet_transform.translation.truncate();
-        let bullet_half = bullet_collider.half_extents;
+        if !swing_reaches(swing, broom_transform, broom_collider, player_pos, bullet_center, bullet_collider.half_extents) {
+            continue;
+        }
 
-        let overlap =
-            (broom_center.x - bullet_center.x).abs() < (broom_half.x + bullet_half.x) &&
-            (broom_center.y - bullet_center.y).abs() < (broom_half.y + bullet_half.y);
+        let speed = vel.0.length();
+        let dir = if parrying {
+            enemies
+                .iter()
+                .map(|tf| tf.translation.truncate() - bullet_center)
+                .filter(|offset| offset.length() <= PARRY_SEEK_RANGE)
+                .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+                .map_or(along_arc, |offset| offset.normalize_or(along_arc))
+        } else if swing.kind == SwingKind::Spin {
+            // A spin flings everything outward.
+            (bullet_center - player_pos).normalize_or(along_arc)
+        } else {
+            along_arc
+        };
+
+        *owner = BulletOwner::Player;
+        vel.0 = dir * speed * if parrying { PARRY_SPEED_MULT } else { 1.0 };
+        damage.0 *= swing.power * if parrying { PARRY_DAMAGE_MULT } else { DEFLECT_DAMAGE_MULT };
+        commands.entity(bullet_entity)
+            .insert((HitEnemies::default(), Deflected))
+            .remove::<crate::enemies::patterns::HomingBullet>();
+        deflected.write(BulletDeflectedEvent { bullet: bullet_entity, pos: bullet_center, parried: parrying });
+    }
+}
 
-        if overlap {
-            if let Ok(mut ec) = commands.get_entity(bullet_entity) { ec.despawn(); }
-        }
+fn parry_popups(
+    mut commands: Commands,
+    mut deflected: EventReader<BulletDeflectedEvent>,
+    font: Res<crate::rewards::RewardFont>,
+) {
+    // One popup per frame is plenty even if a parry catches a whole volley.
+    if let Some(ev) = deflected.read().filter(|ev| ev.parried).last() {
+        crate::rewards::spawn_popup(&mut commands, &font, ev.pos, "PARRY!");
     }
 }
 
+fn spawn_swing(commands: &mut Commands, image: Handle<Image>, player_tf: &Transform, kind: SwingKind, stats: &BroomStats) {
+    let (secs, parry, damage, knockback) = match kind {
+        SwingKind::Combo(step) => (
+            SWING_SECS,
+            Some(Timer::from_seconds(stats.parry_secs, TimerMode::Once)),
+            COMBO_DAMAGE[step],
+            COMBO_KNOCKBACK[step],
+        ),
+        SwingKind::Spin => (SPIN_SECS, None, SPIN_DAMAGE, SPIN_KNOCKBACK),
+    };
+
+    commands.spawn((
+        Sprite {
+            image,
+            custom_size: Some(Vec2::new(BROOM_LENGTH, BROOM_WIDTH)),
+            anchor: bevy::sprite::Anchor::CenterLeft,
+            ..default()
+        },
+        // broom_swing_system places it against the player from the first frame.
+        Transform::from_translation(player_tf.translation + Vec3::Z),
+        Broom,
+        BroomSwing {
+            timer: Timer::from_seconds(secs, TimerMode::Once),
+            active: true,
+            kind,
+            parry,
+            damage: damage * stats.power,
+            knockback: knockback * stats.power,
+            power: stats.power,
+            hit: HashSet::new(),
+        },
+        // Collider kept for bullet-deflect size query; Collidable intentionally
+        // omitted so the sweeping broom is NOT treated as a wall by collision systems.
+        Collider::from_size(Vec2::new(BROOM_LENGTH, BROOM_WIDTH)),
+        GameEntity,
+    ));
+}
+
+/// Right click (or the gamepad's west button). Taps chain into a three-hit
+/// combo; holding past `spin_charge_secs` and letting go releases a spin.
 fn broom_input(
+    time: Res<Time>,
     mouse_buttons: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    player_query: Query<(&Transform, &Facing), (With<Player>, Without<Broom>)>,
+    player_query: Query<(&Transform, &BroomStats), (With<Player>, Without<Broom>)>,
     broom_q: Query<Entity, (With<Broom>, Without<Player>)>,
+    mut combo: Local<ComboState>,
 ) {
-    if mouse_buttons.just_pressed(MouseButton::Right) && broom_q.is_empty() {
-        if let Some((player_tf, facing)) = player_query.iter().next() {
-
-            let broom_length = TILE_SIZE * 2.5;
-            let broom_width  = TILE_SIZE * 1.0;
-
-            let broom_pos = player_tf.translation + match facing.0 {
-                FacingDirection::Up        => Vec3::new(0.0,  broom_length/2.0, 1.0),
-                FacingDirection::Down      => Vec3::new(0.0, -broom_length/2.0, 1.0),
-                FacingDirection::Left      => Vec3::new(-broom_length/2.0, 0.0, 1.0),
-                FacingDirection::Right     => Vec3::new( broom_length/2.0, 0.0, 1.0),
-                FacingDirection::UpRight   => Vec3::new( broom_length/2.0,  broom_length/2.0, 1.0),
-                FacingDirection::UpLeft    => Vec3::new(-broom_length/2.0,  broom_length/2.0, 1.0),
-                FacingDirection::DownRight => Vec3::new( broom_length/2.0, -broom_length/2.0, 1.0),
-                FacingDirection::DownLeft  => Vec3::new(-broom_length/2.0, -broom_length/2.0, 1.0),
-            };
+    let Some((player_tf, stats)) = player_query.iter().next() else { return; };
+    let just_pressed = mouse_buttons.just_pressed(MouseButton::Right)
+        || gamepads.iter().any(|g| g.just_pressed(GamepadButton::West));
+    let pressed = mouse_buttons.pressed(MouseButton::Right)
+        || gamepads.iter().any(|g| g.pressed(GamepadButton::West));
+    combo.since_swing += time.delta_secs();
+
+    if just_pressed && broom_q.is_empty() {
+        combo.step = if combo.since_swing <= SWING_SECS + COMBO_GAP { (combo.step + 1) % 3 } else { 0 };
+        combo.since_swing = 0.0;
+        let broom_image: Handle<Image> = asset_server.load("Broom.png");
+        spawn_swing(&mut commands, broom_image, player_tf, SwingKind::Combo(combo.step), stats);
+    }
 
+    if pressed {
+        combo.held += time.delta_secs();
+    } else {
+        if combo.held >= stats.spin_charge_secs && broom_q.is_empty() {
             let broom_image: Handle<Image> = asset_server.load("Broom.png");
-
-            commands.spawn((
-                Sprite {
-                    image: broom_image,
-                    custom_size: Some(Vec2::new(broom_length, broom_width)),
-                    anchor: bevy::sprite::Anchor::CenterLeft,
-                    ..default()
-                },
-                Transform {
-                    translation: broom_pos,
-                    ..default()
-                },
-                Broom,
-                BroomSwing {
-                    timer: Timer::from_seconds(0.25, TimerMode::Once),
-                    active: true,
-                },
-                // Collider kept for bullet-deflect size query; Collidable intentionally
-                // omitted so the sweeping broom is NOT treated as a wall by collision systems.
-                Collider::from_size(Vec2::new(broom_length, broom_width)),
-                GameEntity,
-            ));
+            spawn_swing(&mut commands, broom_image, player_tf, SwingKind::Spin, stats);
+            // A spin ends the chain.
+            combo.step = 2;
+            combo.since_swing = f32::MAX;
         }
+        combo.held = 0.0;
     }
 }
 
@@ -121,13 +288,21 @@ fn broom_swing_system(
     if let Some((player_tf, facing)) = player_query.iter().next() {
         for (entity, mut broom_tf, mut swing) in &mut broom_query {
             swing.timer.tick(time.delta());
+            if let Some(parry) = swing.parry.as_mut() {
+                parry.tick(time.delta());
+            }
 
             if swing.active {
-                let broom_length = TILE_SIZE * 2.5;
-
-                let sweep = (-90.0_f32).to_radians()
-                    + (swing.timer.elapsed_secs() / swing.timer.duration().as_secs_f32()) 
-                    * (180.0_f32).to_radians();
+                let frac = swing.timer.elapsed_secs() / swing.timer.duration().as_secs_f32();
+                let sweep = match swing.kind {
+                    // Alternate hits sweep back the other way.
+                    SwingKind::Combo(step) => {
+                        let arc = COMBO_ARC_DEG[step].to_radians();
+                        let sweep = -arc / 2.0 + frac * arc;
+                        if step == 1 { -sweep } else { sweep }
+                    }
+                    SwingKind::Spin => frac * std::f32::consts::TAU,
+                };
 
                 let base_angle = match facing.0 {
                     FacingDirection::Up        => std::f32::consts::FRAC_PI_2,
@@ -142,7 +317,7 @@ fn broom_swing_system(
 
                 broom_tf.rotation = Quat::from_rotation_z(base_angle + sweep);
                 broom_tf.translation =
-                    player_tf.translation + broom_tf.rotation * Vec3::new(broom_length / 2.0, 0.0, 0.0);
+                    player_tf.translation + Vec3::Z + broom_tf.rotation * Vec3::new(BROOM_LENGTH / 2.0, 0.0, 0.0);
 
                 if swing.timer.finished() {
                     commands.entity(entity).despawn();
@@ -153,22 +328,37 @@ fn broom_swing_system(
 }
 
 
+/// Each swing hits an enemy once, knocking it away from the player. Later
+/// hits in the combo hit harder and shove further.
 pub fn broom_hit_enemies_system(
-    mut enemies: Query<(&mut Health, &Transform), (With<Enemy>, Without<Broom>)>,
-    broom_query: Query<(&Transform, &Collider), (With<Broom>, Without<Enemy>)>,
+    mut enemies: Query<
+        (Entity, &Transform, Option<&mut Velocity>),
+        (With<Enemy>, Without<Broom>, Without<Player>),
+    >,
+    mut broom_query: Query<(&Transform, &Collider, &mut BroomSwing), (With<Broom>, Without<Enemy>)>,
+    player_query: Query<&Transform, (With<Player>, Without<Broom>, Without<Enemy>)>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
     let enemy_half = Vec2::splat(crate::enemies::ENEMY_SIZE * 0.5);
-    if let Some((broom_tf, broom_col)) = broom_query.iter().next() {
-        for (mut health, enemy_tf) in enemies.iter_mut() {
-            if aabb_overlap(
-                broom_tf.translation.x,
-                broom_tf.translation.y,
-                broom_col.half_extents,
-                enemy_tf.translation.x,
-                enemy_tf.translation.y,
-                enemy_half,
-            ) {
-                health.0 -= 10.0;
+    let Ok(player_tf) = player_query.single() else { return; };
+    let player_pos = player_tf.translation.truncate();
+    if let Some((broom_tf, broom_col, mut swing)) = broom_query.iter_mut().next() {
+        for (entity, enemy_tf, vel) in enemies.iter_mut() {
+            let enemy_pos = enemy_tf.translation.truncate();
+            if swing.hit.contains(&entity)
+                || !swing_reaches(&swing, broom_tf, broom_col, player_pos, enemy_pos, enemy_half)
+            {
+                continue;
+            }
+            swing.hit.insert(entity);
+            damage_events.write(DamageEvent {
+                source: DamageSource::Player,
+                target: entity,
+                amount: swing.damage,
+                kind: DamageKind::Melee,
+            });
+            if let Some(mut vel) = vel {
+                vel.velocity += (enemy_pos - player_pos).normalize_or(Vec2::X) * swing.knockback;
             }
         }
     }
@@ -179,6 +369,7 @@ pub fn broom_hit_enemies_system(
 
 
 
+
 fn broom_push_tables_system(
     broom_query: Query<(&Transform, &Collider), With<Broom>>,
     player_query: Query<(&Transform, &Facing), With<Player>>,
@@ -246,4 +437,46 @@ pub fn broom_fix_window(
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swing(kind: SwingKind) -> BroomSwing {
+        BroomSwing {
+            timer: Timer::from_seconds(SWING_SECS, TimerMode::Once),
+            active: true,
+            kind,
+            parry: None,
+            damage: 0.0,
+            knockback: 0.0,
+            power: 1.0,
+            hit: HashSet::new(),
+        }
+    }
+
+    const HALF: Vec2 = Vec2::splat(5.0);
+
+    #[test]
+    fn combo_reaches_only_what_overlaps_the_broom() {
+        let broom_tf = Transform::from_xyz(BROOM_LENGTH * 0.5, 0.0, 0.0);
+        let col = Collider::from_size(Vec2::new(BROOM_LENGTH, BROOM_WIDTH));
+        let s = swing(SwingKind::Combo(0));
+        assert!(swing_reaches(&s, &broom_tf, &col, Vec2::ZERO, Vec2::new(BROOM_LENGTH * 0.9, 0.0), HALF));
+        // Just as close to the player, but behind them rather than under the broom.
+        assert!(!swing_reaches(&s, &broom_tf, &col, Vec2::ZERO, Vec2::new(-BROOM_LENGTH * 0.9, 0.0), HALF));
+    }
+
+    #[test]
+    fn spin_reaches_all_round_the_player() {
+        let broom_tf = Transform::from_xyz(BROOM_LENGTH * 0.5, 0.0, 0.0);
+        let col = Collider::from_size(Vec2::new(BROOM_LENGTH, BROOM_WIDTH));
+        let s = swing(SwingKind::Spin);
+        let player = Vec2::new(100.0, 100.0);
+        for dir in [Vec2::X, -Vec2::X, Vec2::Y, -Vec2::Y] {
+            assert!(swing_reaches(&s, &broom_tf, &col, player, player + dir * SPIN_RADIUS, HALF));
+            assert!(!swing_reaches(&s, &broom_tf, &col, player, player + dir * (SPIN_RADIUS + 20.0), HALF));
+        }
+    }
+}
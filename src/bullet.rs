@@ -1,12 +1,14 @@
 use crate::Player;
 
-use crate::player::{Health, MaxHealth, MoveSpeed, Shield};
+use crate::damage::{DamageEvent, DamageKind, DamageSource};
 use crate::room::{LevelState, RoomVec};
-use crate::weapons::{BulletDamage, BulletRes, BeamRifleRes, WeaponInventory, WeaponSounds, fire_weapon};
+use crate::weapons::BulletDamage;
+use crate::weapons::mods::{BulletHitEvent, SeekEnemies};
+use crate::impacts::WallImpactEvent;
 use crate::window;
 use crate::{GameState, TILE_SIZE};
 use crate::table;
-use bevy::{prelude::*, window::PrimaryWindow};
+use bevy::prelude::*;
 use std::collections::HashSet;
 
 #[derive(Component)]
@@ -41,13 +43,25 @@ pub struct HitEnemies(pub HashSet<Entity>);
 #[derive(Component, Deref, DerefMut)]
 pub struct Velocity(pub Vec2);
 
+/// Wall bounces left. Each one reflects the bullet off the face it hit
+/// instead of destroying it. Player and enemy shots alike.
+#[derive(Component)]
+pub struct Ricochet(pub u32);
+
+/// Where the bullet was before this frame's move. `bullet_collision` sweeps
+/// from here to the current position so fast shots can't skip past thin
+/// walls or small enemies at low frame rates.
+#[derive(Component)]
+pub struct PrevPosition(pub Vec2);
+
 impl Plugin for BulletPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, shoot_bullet_on_click.run_if(in_state(GameState::Playing)).run_if(not(resource_exists::<crate::pause::IsPaused>))) // Mouse shooting
-            .add_systems(Update, move_bullets.run_if(in_state(GameState::Playing)))
+        app.add_systems(Update, move_bullets.run_if(in_state(GameState::Playing)))
             .add_systems(
                 Update,
-                bullet_collision.run_if(in_state(GameState::Playing)),
+                bullet_collision
+                    .after(move_bullets)
+                    .run_if(in_state(GameState::Playing)),
             )
             .add_systems(
                 Last,
@@ -63,74 +77,39 @@ impl Plugin for BulletPlugin {
     }
 }
 
-fn cursor_to_world(cursor_pos: Vec2, camera: (&Camera, &GlobalTransform)) -> Option<Vec2> {
-    camera.0.viewport_to_world_2d(camera.1, cursor_pos).ok()
-}
-
-// Mouse shooting - uses weapon stats from player
-pub fn shoot_bullet_on_click(
-    mut commands: Commands,
-    buttons: Res<ButtonInput<MouseButton>>,
-    mut q_player: Query<(&Transform, &mut WeaponInventory), With<crate::player::Player>>,
-    q_window: Query<&Window, With<PrimaryWindow>>,
-    q_camera: Query<(&Camera, &GlobalTransform)>,
-    bullet_res: Res<BulletRes>,
-    beam_res: Res<BeamRifleRes>,
-    weapon_sounds: Res<WeaponSounds>,
-) {
-    let Ok((player_transform, mut inventory)) = q_player.single_mut() else {
-        return;
-    };
-
-    if buttons.pressed(MouseButton::Left) && inventory.current().can_shoot() {
-        let window = match q_window.single() {
-            Ok(win) => win,
-            Err(_) => return,
-        };
-
-        let Some(cursor_pos) = window.cursor_position() else {
-            return;
-        };
-
-        let (camera, cam_transform) = match q_camera.single() {
-            Ok(c) => c,
-            Err(_) => return,
-        };
-
-        let Some(world_pos) = cursor_to_world(cursor_pos, (camera, cam_transform)) else {
-            return;
-        };
-
-        let player_pos = player_transform.translation.truncate();
-
-        let dir_vec = (world_pos - player_pos).normalize_or_zero();
-        if dir_vec == Vec2::ZERO {
-            return;
-        }
-
-        let spawn_pos = player_pos + dir_vec * 16.0;
-
-        fire_weapon(
-            &mut commands,
-            &mut inventory,
-            &bullet_res,
-            &beam_res,
-            &weapon_sounds,
-            spawn_pos,
-            dir_vec,
-        );
-    }
-}
-
 pub fn move_bullets(
     mut commands: Commands,
     mut bullet_q: Query<
-        (Entity, &mut Transform, &Velocity),
+        (Entity, &mut Transform, &mut Velocity, Option<&SeekEnemies>, Option<&mut PrevPosition>),
         (With<Bullet>, Without<MarkedForDespawn>),
     >,
+    enemy_q: Query<&Transform, (With<crate::enemies::Enemy>, Without<Bullet>)>,
     time: Res<Time>,
 ) {
-    for (entity, mut transform, vel) in bullet_q.iter_mut() {
+    for (entity, mut transform, mut vel, seek, prev) in bullet_q.iter_mut() {
+        let start = transform.translation.truncate();
+        match prev {
+            Some(mut prev) => prev.0 = start,
+            None => { commands.entity(entity).try_insert(PrevPosition(start)); }
+        }
+
+        // Seeker rounds bend toward the closest enemy in range.
+        if let Some(seek) = seek {
+            let pos = transform.translation.truncate();
+            let target = enemy_q
+                .iter()
+                .map(|t| t.translation.truncate())
+                .filter(|p| p.distance(pos) <= seek.range)
+                .min_by(|a, b| a.distance(pos).total_cmp(&b.distance(pos)));
+            if let Some(target) = target {
+                let desired = (target - pos).normalize_or_zero();
+                let current = vel.0.normalize_or_zero();
+                let max_turn = seek.turn_rate * time.delta_secs();
+                let turn = current.angle_to(desired).clamp(-max_turn, max_turn);
+                vel.0 = Vec2::from_angle(turn).rotate(vel.0);
+            }
+        }
+
         transform.translation += (vel.0 * time.delta_secs()).extend(0.0);
 
         let p = transform.translation;
@@ -155,166 +134,271 @@ fn animate_bullet(
     }
 }
 
+/// What a bullet's path crossed this frame, in the order it is resolved
+/// when two things are hit at the same moment.
+#[derive(Clone, Copy)]
+enum SweepHit {
+    Enemy(Entity),
+    Player,
+    Table(Entity),
+    Window(Entity),
+    Wall,
+}
+
+impl SweepHit {
+    fn priority(self) -> u8 {
+        match self {
+            SweepHit::Enemy(_) => 0,
+            SweepHit::Player => 1,
+            SweepHit::Table(_) => 2,
+            SweepHit::Window(_) => 3,
+            SweepHit::Wall => 4,
+        }
+    }
+}
+
 pub fn bullet_collision(
     mut commands: Commands,
     mut bullet_query: Query<
-        (Entity, &Transform, &BulletOwner, &BulletDamage, Option<&mut Piercing>, Option<&mut HitEnemies>),
+        (
+            Entity, &mut Transform, &mut BulletOwner, &mut Velocity, &BulletDamage,
+            Option<&mut Piercing>, Option<&mut HitEnemies>, Option<&mut Ricochet>,
+            Option<&crate::collidable::Collider>, Option<&PrevPosition>,
+        ),
         (With<Bullet>, Without<MarkedForDespawn>),
     >,
-    mut enemy_query: Query<
-        (Entity, &Transform, &mut crate::enemies::Health),
-        (With<crate::enemies::Enemy>, Without<crate::enemies::Reaper>),
+    enemy_query: Query<
+        (Entity, &Transform, Option<&crate::collidable::Collider>, Option<&crate::enemies::Elite>),
+        (With<crate::enemies::Enemy>, Without<crate::enemies::Reaper>, Without<Bullet>),
     >,
-    mut player_query: Query<
-        (&Transform, &mut Health, &mut MaxHealth, &mut MoveSpeed, &mut crate::player::Armor, &mut Shield),
-        With<Player>,
+    player_query: Query<
+        (Entity, &Transform),
+        (With<Player>, Without<Bullet>),
     >,
     mut table_query: Query<
-        (&Transform, &mut table::Health, &table::TableState),
-        With<table::Table>,
+        (Entity, &Transform, &mut table::Health, &table::TableState),
+        (With<table::Table>, Without<Bullet>),
     >,
     mut window_query: Query<
-        (&Transform, &mut window::Health, &window::GlassState),
-        With<window::Window>,
+        (Entity, &Transform, &mut window::Health, &window::GlassState),
+        (With<window::Window>, Without<Bullet>),
     >,
     wall_grid: Res<crate::map::WallGrid>,
     lvlstate: Res<LevelState>,
     rooms: Res<RoomVec>,
+    mut hit_events: EventWriter<BulletHitEvent>,
+    mut impacts: EventWriter<WallImpactEvent>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
-    let bullet_half = Vec2::splat(8.0);
-
-    let Ok((player_tf, mut hp, _maxhp, _movspd, armor, mut shield)) = player_query.single_mut() else {
+    let Ok((player_entity, player_tf)) = player_query.single() else {
         return;
     };
 
-    let _final_room = matches!(*lvlstate, LevelState::InRoom(_, _, _)) && rooms.0.len() == 1;
+    let _final_room = matches!(*lvlstate, LevelState::InRoom(_, _)) && rooms.0.len() == 1;
+
+    let mut hits: Vec<(f32, Vec2, SweepHit)> = Vec::new();
+
+    'bullet_loop: for (
+        bullet_entity, mut bullet_tf, mut owner, mut vel, damage,
+        mut piercing, mut hit_enemies_opt, mut ricochet, collider, prev,
+    ) in &mut bullet_query {
+        let to = bullet_tf.translation.truncate();
+        let from = prev.map_or(to, |p| p.0);
+        let bullet_half = collider.map_or(Vec2::splat(8.0), |c| {
+            rotated_half_extents(c.half_extents, bullet_tf.rotation)
+        });
+        let player_owned = matches!(*owner, BulletOwner::Player);
+
+        // Gather everything the swept box touches this frame.
+        hits.clear();
+        if player_owned {
+            for (enemy_entity, enemy_tf, enemy_collider, _) in &enemy_query {
+                // Bosses carry a larger Collider; regular enemies use ENEMY_SIZE.
+                let enemy_half = enemy_collider
+                    .map(|c| c.half_extents)
+                    .unwrap_or(Vec2::splat(crate::enemies::ENEMY_SIZE * 0.5));
+                if let Some((t, n)) = sweep_aabb(from, to, bullet_half, enemy_tf.translation.truncate(), enemy_half) {
+                    hits.push((t, n, SweepHit::Enemy(enemy_entity)));
+                }
+            }
+            for (table_entity, table_tf, _, state) in &table_query {
+                if *state != table::TableState::Intact { continue; }
+                if let Some((t, n)) = sweep_aabb(from, to, bullet_half, table_tf.translation.truncate(), Vec2::splat(TILE_SIZE * 0.5)) {
+                    hits.push((t, n, SweepHit::Table(table_entity)));
+                }
+            }
+            for (window_entity, window_tf, _, state) in &window_query {
+                if *state != window::GlassState::Intact { continue; }
+                if let Some((t, n)) = sweep_aabb(from, to, bullet_half, window_tf.translation.truncate(), Vec2::splat(TILE_SIZE * 0.5)) {
+                    hits.push((t, n, SweepHit::Window(window_entity)));
+                }
+            }
+        } else if let Some((t, n)) = sweep_aabb(from, to, bullet_half, player_tf.translation.truncate(), Vec2::splat(TILE_SIZE)) {
+            hits.push((t, n, SweepHit::Player));
+        }
 
-    'bullet_loop: for (bullet_entity, bullet_tf, owner, damage, mut piercing, mut hit_enemies_opt) in &mut bullet_query {
-        let bullet_pos = bullet_tf.translation;
+        let mid = (from + to) * 0.5;
+        let reach = ((to - from).length() * 0.5 / wall_grid.cell_size).ceil() as i32 + 2;
+        for (wall_pos, wall_half) in wall_grid.nearby(mid, reach) {
+            if let Some((t, n)) = sweep_aabb(from, to, bullet_half, wall_pos, wall_half) {
+                hits.push((t, n, SweepHit::Wall));
+            }
+        }
 
-        // Bullet hits enemy
-        if matches!(owner, BulletOwner::Player) {
-            if let Some(ref mut hit_enemies) = hit_enemies_opt {
-            for (enemy_entity, enemy_tf, mut health) in &mut enemy_query {
-                if hit_enemies.0.contains(&enemy_entity) {
-                    continue;
-                }
-                let enemy_pos = enemy_tf.translation;
-                let enemy_half = Vec2::splat(crate::enemies::ENEMY_SIZE * 0.5);
-                if aabb_overlap(
-                    bullet_pos.x,
-                    bullet_pos.y,
-                    bullet_half,
-                    enemy_pos.x,
-                    enemy_pos.y,
-                    enemy_half,
-                ) {
+        hits.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.2.priority().cmp(&b.2.priority())));
+
+        for &(t, normal, hit) in &hits {
+            match hit {
+                SweepHit::Enemy(enemy_entity) => {
+                    let Some(ref mut hit_enemies) = hit_enemies_opt else { continue };
+                    if hit_enemies.0.contains(&enemy_entity) { continue; }
+                    let Ok((_, _, _, elite)) = enemy_query.get(enemy_entity) else { continue };
+
+                    // Reflective elites sometimes bat the shot straight back.
+                    if elite.is_some_and(|e| e.has(crate::enemies::EliteAffix::Reflective))
+                        && rand::random::<f32>() < crate::enemies::elite::REFLECT_CHANCE
+                    {
+                        *owner = BulletOwner::Enemy;
+                        vel.0 = -vel.0;
+                        set_pos(&mut bullet_tf, from.lerp(to, t));
+                        continue 'bullet_loop;
+                    }
                     hit_enemies.0.insert(enemy_entity);
-                    health.0 -= damage.0;
+                    damage_events.write(DamageEvent {
+                        source: DamageSource::Player,
+                        target: enemy_entity,
+                        amount: damage.0,
+                        kind: DamageKind::Bullet,
+                    });
+                    hit_events.write(BulletHitEvent {
+                        bullet: bullet_entity,
+                        enemy: enemy_entity,
+                        pos: from.lerp(to, t),
+                    });
                     match &mut piercing {
-                        None => {
-                            // Non-piercing bullet: stop on first hit
-                            commands.entity(bullet_entity).try_insert(MarkedForDespawn);
-                            continue 'bullet_loop;
+                        Some(p) if p.0 > 0 => {
+                            // Consume one pierce slot and keep going
+                            p.0 -= 1;
                         }
-                        Some(p) if p.0 == 0 => {
-                            // Used up all pierce slots: stop on this hit
+                        _ => {
+                            // Non-piercing, or out of pierce slots: stop on this hit
                             commands.entity(bullet_entity).try_insert(MarkedForDespawn);
                             continue 'bullet_loop;
                         }
-                        Some(p) => {
-                            // Consume one pierce slot and keep going
-                            p.0 -= 1;
-                        }
                     }
                 }
-            }
-            } // if let Some(hit_enemies)
-        }
-
-        // Bullet hits player
-        if matches!(owner, BulletOwner::Enemy) {
-            let player_pos = player_tf.translation;
-            let player_half = Vec2::splat(TILE_SIZE);
-            if aabb_overlap(
-                bullet_pos.x,
-                bullet_pos.y,
-                bullet_half,
-                player_pos.x,
-                player_pos.y,
-                player_half,
-            ) {
-                if shield.current >= 1.0 {
-                    shield.current -= 1.0;
-                } else {
-                    hp.0 -= damage.0 * crate::player::armor_factor(armor.0);
-                }
-                commands.entity(bullet_entity).try_insert(MarkedForDespawn);
-                continue 'bullet_loop;
-            }
-        }
-
-        // Bullet hits table
-        if matches!(owner, BulletOwner::Player) {
-            for (table_tf, mut table_health, state) in &mut table_query {
-                if *state != table::TableState::Intact {
-                    continue;
+                SweepHit::Player => {
+                    damage_events.write(DamageEvent {
+                        source: DamageSource::Enemy,
+                        target: player_entity,
+                        amount: damage.0,
+                        kind: DamageKind::Bullet,
+                    });
+                    commands.entity(bullet_entity).try_insert(MarkedForDespawn);
+                    continue 'bullet_loop;
                 }
-                let table_pos = table_tf.translation;
-                let table_half = Vec2::splat(TILE_SIZE * 0.5);
-                if aabb_overlap(
-                    bullet_pos.x,
-                    bullet_pos.y,
-                    bullet_half,
-                    table_pos.x,
-                    table_pos.y,
-                    table_half,
-                ) {
-                    table_health.0 -= damage.0; // Use bullet damage
+                SweepHit::Table(table_entity) => {
+                    if let Ok((_, _, mut table_health, _)) = table_query.get_mut(table_entity) {
+                        table_health.0 -= damage.0;
+                    }
                     commands.entity(bullet_entity).try_insert(MarkedForDespawn);
                     continue 'bullet_loop;
                 }
-            }
-        }
-
-        // Bullet hits window
-        if matches!(owner, BulletOwner::Player) {
-            for (window_tf, mut window_health, state) in &mut window_query {
-                if *state != window::GlassState::Intact {
-                    continue;
+                SweepHit::Window(window_entity) => {
+                    if let Ok((_, _, mut window_health, _)) = window_query.get_mut(window_entity) {
+                        window_health.0 -= damage.0;
+                    }
+                    commands.entity(bullet_entity).try_insert(MarkedForDespawn);
+                    continue 'bullet_loop;
                 }
-                let window_pos = window_tf.translation;
-                let window_half = Vec2::splat(TILE_SIZE * 0.5);
-                if aabb_overlap(
-                    bullet_pos.x,
-                    bullet_pos.y,
-                    bullet_half,
-                    window_pos.x,
-                    window_pos.y,
-                    window_half,
-                ) {
-                    window_health.0 -= damage.0; // Use bullet damage
+                SweepHit::Wall => {
+                    let contact = from.lerp(to, t);
+                    let heading_in = normal != Vec2::ZERO && vel.0.dot(normal) < 0.0;
+                    if heading_in {
+                        impacts.write(WallImpactEvent {
+                            pos: contact,
+                            normal,
+                            chip: if player_owned { damage.0 } else { 0.0 },
+                        });
+                    }
+                    // Ricochet rounds flip off the face they hit, restarting
+                    // from the contact point. A shot already heading away
+                    // from the face (still touching it after a bounce) just
+                    // carries on out.
+                    if let Some(ref mut bounces) = ricochet
+                        && bounces.0 > 0
+                    {
+                        if !heading_in {
+                            continue;
+                        }
+                        let v = vel.0;
+                        vel.0 = v - 2.0 * v.dot(normal) * normal;
+                        bounces.0 -= 1;
+                        set_pos(&mut bullet_tf, contact);
+                        // A bounced shot may hit the same enemies again.
+                        if let Some(ref mut hit_enemies) = hit_enemies_opt {
+                            hit_enemies.0.clear();
+                        }
+                        continue 'bullet_loop;
+                    }
                     commands.entity(bullet_entity).try_insert(MarkedForDespawn);
                     continue 'bullet_loop;
                 }
             }
         }
+    }
+}
+
+fn set_pos(tf: &mut Transform, pos: Vec2) {
+    tf.translation.x = pos.x;
+    tf.translation.y = pos.y;
+}
 
+/// Half extents of the axis-aligned box that encloses a rotated one.
+fn rotated_half_extents(half: Vec2, rotation: Quat) -> Vec2 {
+    let (axis, angle) = rotation.to_axis_angle();
+    let angle = angle * axis.z.signum();
+    let (s, c) = angle.sin_cos();
+    Vec2::new(
+        c.abs() * half.x + s.abs() * half.y,
+        s.abs() * half.x + c.abs() * half.y,
+    )
+}
 
-        for (wall_pos, wall_half) in wall_grid.nearby(bullet_pos.truncate(), 2) {
-            if aabb_overlap(
-                bullet_pos.x,
-                bullet_pos.y,
-                bullet_half,
-                wall_pos.x,
-                wall_pos.y,
-                wall_half,
-            ) {
-                commands.entity(bullet_entity).try_insert(MarkedForDespawn);
-                continue 'bullet_loop;
-            }
+/// Sweeps a box with half extents `a_half` from `from` to `to` against a
+/// static box. Returns the fraction of the move (0..=1) at first contact and
+/// the face normal it came through — zero if they already overlap at `from`.
+pub fn sweep_aabb(from: Vec2, to: Vec2, a_half: Vec2, b_pos: Vec2, b_half: Vec2) -> Option<(f32, Vec2)> {
+    let half = a_half + b_half;
+    let rel = from - b_pos;
+    if rel.x.abs() < half.x && rel.y.abs() < half.y {
+        return Some((0.0, Vec2::ZERO));
+    }
+
+    let delta = to - from;
+    let mut t_enter = 0.0_f32;
+    let mut t_exit = 1.0_f32;
+    let mut normal = Vec2::ZERO;
+
+    for axis in 0..2 {
+        let (p, d, h) = (rel[axis], delta[axis], half[axis]);
+        if d.abs() < f32::EPSILON {
+            // Not moving on this axis: must already be inside the slab.
+            if p.abs() >= h { return None; }
+            continue;
+        }
+        let t1 = (-h - p) / d;
+        let t2 = (h - p) / d;
+        let (near, far) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+        if near > t_enter {
+            t_enter = near;
+            normal = Vec2::ZERO;
+            normal[axis] = -d.signum();
         }
+        t_exit = t_exit.min(far);
+        if t_enter > t_exit { return None; }
     }
+    (t_enter <= 1.0).then_some((t_enter, normal))
 }
 
 fn cleanup_marked_bullets(world: &mut World) {
@@ -336,3 +420,171 @@ pub fn aabb_overlap(ax: f32, ay: f32, a_half: Vec2, bx: f32, by: f32, b_half: Ve
     (ax - bx).abs() < (a_half.x + b_half.x) && (ay - by).abs() < (a_half.y + b_half.y)
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collidable::Collider;
+    use crate::player::{Health, MaxHealth, MoveSpeed, Shield};
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    const FRAME: Duration = Duration::from_millis(50); // 20 FPS
+    /// The beam rifle's bullet speed — 90 px per frame at 20 FPS, nearly
+    /// three tiles.
+    const SPEED: f32 = 1800.0;
+    const TARGET_X: f32 = 200.0;
+
+    /// Bare-bones world running only `move_bullets`, `bullet_collision` and
+    /// `apply_damage` with a fixed 50 ms step.
+    fn harness(walls: &[Vec2]) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+            .insert_resource(crate::map::WallGrid::from_positions(walls.iter().copied(), TILE_SIZE, 0.0, 0.0))
+            .insert_resource(LevelState::NotRoom)
+            .insert_resource(RoomVec(Vec::new()))
+            .add_event::<BulletHitEvent>()
+            .add_event::<WallImpactEvent>()
+            .add_event::<DamageEvent>()
+            .add_event::<crate::damage::DeathEvent>()
+            .init_resource::<crate::damage::CombatLog>()
+            .add_systems(Update, (move_bullets, bullet_collision, crate::damage::apply_damage).chain());
+        app.world_mut().spawn((
+            Player,
+            Transform::from_xyz(-5000.0, 0.0, 0.0),
+            Health(100.0),
+            MaxHealth(100.0),
+            MoveSpeed(0.0),
+            crate::player::Armor(0.0),
+            Shield { current: 0.0, max: 0.0 },
+        ));
+        app
+    }
+
+    fn fire(app: &mut App, x: f32, half: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Transform::from_xyz(x, 0.0, 0.0),
+                Velocity(Vec2::new(SPEED, 0.0)),
+                Bullet,
+                BulletOwner::Player,
+                BulletDamage(10.0),
+                HitEnemies::default(),
+                Collider { half_extents: half },
+            ))
+            .id()
+    }
+
+    /// Steps until the bullet is stopped, returning how far it got, or
+    /// `None` if it flew clean past `TARGET_X`.
+    fn run_until_stopped(app: &mut App, bullet: Entity) -> Option<f32> {
+        for _ in 0..20 {
+            app.update();
+            let entity = app.world().entity(bullet);
+            if entity.contains::<MarkedForDespawn>() {
+                return Some(entity.get::<Transform>().unwrap().translation.x);
+            }
+            if entity.get::<Transform>().unwrap().translation.x > TARGET_X + 200.0 {
+                return None;
+            }
+        }
+        None
+    }
+
+    /// Every starting phase across one frame's travel, so some of them land
+    /// the discrete sample either side of the target.
+    fn start_offsets() -> impl Iterator<Item = f32> {
+        (0..45).map(|i| i as f32 * 2.0)
+    }
+
+    #[test]
+    fn fast_bullet_never_tunnels_through_one_tile_wall() {
+        for start in start_offsets() {
+            let mut app = harness(&[Vec2::new(TARGET_X, 0.0)]);
+            let bullet = fire(&mut app, start, Vec2::splat(5.0));
+            assert!(
+                run_until_stopped(&mut app, bullet).is_some(),
+                "bullet starting at x={start} passed through the wall",
+            );
+        }
+    }
+
+    #[test]
+    fn fast_bullet_never_tunnels_through_enemy() {
+        for start in start_offsets() {
+            let mut app = harness(&[]);
+            let enemy = app.world_mut().spawn((
+                crate::enemies::Enemy,
+                Transform::from_xyz(TARGET_X, 0.0, 0.0),
+                crate::enemies::Health(100.0),
+            )).id();
+            let bullet = fire(&mut app, start, Vec2::splat(5.0));
+
+            assert!(run_until_stopped(&mut app, bullet).is_some(), "bullet from x={start} missed the enemy");
+            let health = app.world().get::<crate::enemies::Health>(enemy).unwrap().0;
+            assert_eq!(health, 90.0, "enemy hit from x={start} took the wrong damage");
+        }
+    }
+
+    #[test]
+    fn fast_bullet_never_tunnels_through_table() {
+        for start in start_offsets() {
+            let mut app = harness(&[]);
+            let table = app.world_mut().spawn((
+                table::Table,
+                Transform::from_xyz(TARGET_X, 0.0, 0.0),
+                table::Health(100.0),
+                table::TableState::Intact,
+            )).id();
+            let bullet = fire(&mut app, start, Vec2::new(15.0, 2.0));
+
+            assert!(run_until_stopped(&mut app, bullet).is_some(), "bullet from x={start} passed through the table");
+            assert_eq!(app.world().get::<table::Health>(table).unwrap().0, 90.0);
+        }
+    }
+
+    #[test]
+    fn fast_bullet_never_tunnels_through_window() {
+        for start in start_offsets() {
+            let mut app = harness(&[]);
+            let glass = app.world_mut().spawn((
+                window::Window,
+                Transform::from_xyz(TARGET_X, 0.0, 0.0),
+                window::Health(100.0),
+                window::GlassState::Intact,
+            )).id();
+            let bullet = fire(&mut app, start, Vec2::splat(5.0));
+
+            assert!(run_until_stopped(&mut app, bullet).is_some(), "bullet from x={start} passed through the window");
+            assert_eq!(app.world().get::<window::Health>(glass).unwrap().0, 90.0);
+        }
+    }
+
+    #[test]
+    fn broken_window_lets_bullets_through() {
+        let mut app = harness(&[]);
+        app.world_mut().spawn((
+            window::Window,
+            Transform::from_xyz(TARGET_X, 0.0, 0.0),
+            window::Health(0.0),
+            window::GlassState::Broken,
+        ));
+        let bullet = fire(&mut app, 0.0, Vec2::splat(5.0));
+        assert_eq!(run_until_stopped(&mut app, bullet), None);
+    }
+
+    #[test]
+    fn wall_behind_enemy_does_not_block_the_hit() {
+        let mut app = harness(&[Vec2::new(TARGET_X + TILE_SIZE, 0.0)]);
+        let enemy = app.world_mut().spawn((
+            crate::enemies::Enemy,
+            Transform::from_xyz(TARGET_X, 0.0, 0.0),
+            crate::enemies::Health(100.0),
+        )).id();
+        let bullet = fire(&mut app, 60.0, Vec2::splat(5.0));
+
+        run_until_stopped(&mut app, bullet);
+        assert_eq!(app.world().get::<crate::enemies::Health>(enemy).unwrap().0, 90.0);
+    }
+}
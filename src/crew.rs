@@ -0,0 +1,390 @@
+use bevy::prelude::*;
+
+use crate::damage::{DamageKind, DamageSource, DeathEvent};
+use crate::items::{Consumables, ItemKind, SentryShot};
+use crate::player::Player;
+use crate::rewards::{RewardFont, spawn_popup};
+use crate::stats::{Stat, StatSheet};
+use crate::status::{ApplyStatusEvent, StatusKind};
+use crate::weapons::mods::BulletHitEvent;
+use crate::weapons::{Weapon, WeaponInventory, WeaponRegistry, beam_rifle, zapper};
+use crate::window::{self, GlassState, Window};
+use crate::{GameEntity, GameState, TILE_SIZE, Z_ENTITIES};
+
+/// Holding F this long next to a breach seals it at repair speed 1.
+const HAND_REPAIR_SECS: f32 = 4.0;
+const REPAIR_RANGE: f32 = TILE_SIZE * 2.0;
+const REPAIR_BAR_WIDTH: f32 = TILE_SIZE;
+const REPAIR_BAR_COLOR: Color = Color::srgb(0.75, 0.85, 0.95);
+
+/// Chance for each Security bullet to leave its target Vulnerable.
+const HOLLOW_POINT_CHANCE: f32 = 0.2;
+const HOLLOW_POINT_SECS: f32 = 2.0;
+
+/// Who the player is playing as. Chosen before each new run; decides the
+/// starting stats, loadout and passive.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum CrewRole {
+    /// Repairs breaches three times as fast and starts with hull patches.
+    Engineer,
+    /// Heavier and hits harder with the broom; broom kills drop extra scrap.
+    #[default]
+    Janitor,
+    /// Armored, carries the beam rifle; bullets can leave enemies Vulnerable.
+    Security,
+}
+
+impl CrewRole {
+    pub const ALL: [CrewRole; 3] = [CrewRole::Engineer, CrewRole::Janitor, CrewRole::Security];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            CrewRole::Engineer => "Engineer",
+            CrewRole::Janitor => "Janitor",
+            CrewRole::Security => "Security",
+        }
+    }
+
+    /// Starting kit, shown on the selection card.
+    fn kit(self) -> &'static str {
+        match self {
+            CrewRole::Engineer => "Zapper, 2 Hull Patches",
+            CrewRole::Janitor => "Zapper, +50% broom power, harder to pull into breaches",
+            CrewRole::Security => "Beam Rifle, +25 armor",
+        }
+    }
+
+    fn passive(self) -> (&'static str, &'static str) {
+        match self {
+            CrewRole::Engineer => ("Quick Fix", "Seals breaches by hand three times as fast."),
+            CrewRole::Janitor => ("Clean Sweep", "Enemies finished with the broom drop double scrap."),
+            CrewRole::Security => ("Hollow Points", "Bullets have a 20% chance to make enemies Vulnerable."),
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            CrewRole::Engineer => Color::srgb(1.0, 0.7, 0.2),
+            CrewRole::Janitor => Color::srgb(0.4, 0.85, 0.5),
+            CrewRole::Security => Color::srgb(0.4, 0.6, 1.0),
+        }
+    }
+
+    /// Everything a new run as this role starts with.
+    pub fn starting_buffs(self, registry: &WeaponRegistry) -> crate::SavedPlayerBuffs {
+        let stats = self.stat_sheet();
+        crate::SavedPlayerBuffs {
+            health: crate::player::Health(stats.get(Stat::MaxHealth)),
+            stats,
+            inventory: WeaponInventory::new(self.starting_weapon(registry)),
+            reward_stacks: default(),
+            scrap: default(),
+            consumables: self.starting_items(),
+        }
+    }
+
+    fn stat_sheet(self) -> StatSheet {
+        let mut sheet = StatSheet::default();
+        match self {
+            CrewRole::Engineer => {
+                sheet.set_base(Stat::RepairSpeed, 3.0);
+            }
+            CrewRole::Janitor => {
+                let power = sheet.base(Stat::BroomPower) * 1.5;
+                let mass = sheet.base(Stat::Mass) + 30.0;
+                sheet.set_base(Stat::BroomPower, power).set_base(Stat::Mass, mass);
+            }
+            CrewRole::Security => {
+                sheet.set_base(Stat::Armor, 25.0);
+            }
+        }
+        sheet
+    }
+
+    fn starting_weapon(self, registry: &WeaponRegistry) -> Weapon {
+        match self {
+            CrewRole::Security => registry.create(beam_rifle::ID).expect("beam rifle is always registered"),
+            CrewRole::Engineer | CrewRole::Janitor => {
+                let mut weapon = registry.create(zapper::ID).expect("zapper is always registered");
+                weapon.fire_rate = 0.5;
+                weapon.shoot_timer = Timer::from_seconds(0.5, TimerMode::Once);
+                weapon.damage = 25.0;
+                weapon
+            }
+        }
+    }
+
+    fn starting_items(self) -> Consumables {
+        let mut items = Consumables::default();
+        if self == CrewRole::Engineer {
+            items.add(ItemKind::HullPatch);
+            items.add(ItemKind::HullPatch);
+        }
+        items
+    }
+}
+
+/// The role picked on the selection screen. "Play again" and moving on to
+/// the next station keep it.
+#[derive(Resource, Default)]
+pub struct SelectedCrew(pub CrewRole);
+
+#[derive(Component)]
+struct CrewSelectUI;
+
+#[derive(Component)]
+struct CrewCard(CrewRole);
+
+/// The bar over a window that is being repaired by hand.
+#[derive(Component)]
+struct RepairBar;
+
+#[derive(Component)]
+struct RepairBarFill;
+
+pub struct CrewPlugin;
+
+impl Plugin for CrewPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SelectedCrew>()
+            .add_systems(OnEnter(GameState::CrewSelect), setup_crew_select)
+            .add_systems(Update, handle_crew_select.run_if(in_state(GameState::CrewSelect)))
+            .add_systems(OnExit(GameState::CrewSelect), cleanup_crew_select)
+            .add_systems(
+                Update,
+                (
+                    repair_by_hand.run_if(not(resource_exists::<crate::pause::IsPaused>)),
+                    clean_sweep
+                        .after(crate::damage::apply_damage)
+                        .before(crate::enemies::check_enemy_health),
+                    hollow_points.after(crate::bullet::bullet_collision),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn setup_crew_select(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(30.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.02, 0.02, 0.06)),
+            ZIndex(100),
+            CrewSelectUI,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new("Choose your crew role"),
+                TextFont { font_size: 40.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+
+            root.spawn(Node { column_gap: Val::Px(24.0), ..default() })
+                .with_children(|row| {
+                    for (i, role) in CrewRole::ALL.into_iter().enumerate() {
+                        let (passive, passive_desc) = role.passive();
+                        row.spawn((
+                            Button,
+                            CrewCard(role),
+                            Node {
+                                width: Val::Px(280.0),
+                                height: Val::Px(260.0),
+                                flex_direction: FlexDirection::Column,
+                                padding: UiRect::all(Val::Px(14.0)),
+                                row_gap: Val::Px(10.0),
+                                border: UiRect::all(Val::Px(2.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgba(0.1, 0.1, 0.2, 0.9)),
+                            BorderColor(role.color()),
+                            BorderRadius::all(Val::Px(6.0)),
+                        ))
+                        .with_children(|card| {
+                            card.spawn((
+                                Text::new(format!("{}  [{}]", role.name(), i + 1)),
+                                TextFont { font_size: 28.0, ..default() },
+                                TextColor(role.color()),
+                            ));
+                            card.spawn((
+                                Text::new(role.kit()),
+                                TextFont { font_size: 16.0, ..default() },
+                                TextColor(Color::WHITE),
+                            ));
+                            card.spawn((
+                                Text::new(format!("Passive: {passive}")),
+                                TextFont { font_size: 18.0, ..default() },
+                                TextColor(Color::srgb(1.0, 1.0, 0.5)),
+                            ));
+                            card.spawn((
+                                Text::new(passive_desc),
+                                TextFont { font_size: 16.0, ..default() },
+                                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                            ));
+                        });
+                    }
+                });
+
+            root.spawn((
+                Text::new("Esc — Back"),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::srgba(1.0, 1.0, 1.0, 0.6)),
+            ));
+        });
+}
+
+/// Click a card (or press 1/2/3) to start the run as that role.
+fn handle_crew_select(
+    keys: Res<ButtonInput<KeyCode>>,
+    interactions: Query<(&Interaction, &CrewCard), (Changed<Interaction>, With<Button>)>,
+    mut selected: ResMut<SelectedCrew>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keys.just_pressed(KeyCode::Escape) {
+        next_state.set(GameState::Menu);
+        return;
+    }
+    let picked = interactions
+        .iter()
+        .find(|(i, _)| **i == Interaction::Pressed)
+        .map(|(_, card)| card.0)
+        .or_else(|| {
+            [KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3]
+                .iter()
+                .position(|k| keys.just_pressed(*k))
+                .map(|i| CrewRole::ALL[i])
+        });
+    let Some(role) = picked else { return; };
+    selected.0 = role;
+    next_state.set(GameState::Loading);
+}
+
+fn cleanup_crew_select(mut commands: Commands, ui_q: Query<Entity, With<CrewSelectUI>>) {
+    for e in &ui_q {
+        commands.entity(e).despawn();
+    }
+}
+
+/// Hold F next to a breach to seal it. Anyone can; how long it takes is
+/// set by the RepairSpeed stat.
+fn repair_by_hand(
+    mut commands: Commands,
+    time: Res<Time>,
+    input: Res<ButtonInput<KeyCode>>,
+    font: Res<RewardFont>,
+    player_q: Query<(&Transform, &StatSheet), With<Player>>,
+    mut windows: Query<(Entity, &Transform, &mut crate::window::Health, &GlassState), With<Window>>,
+    bar_q: Query<Entity, With<RepairBar>>,
+    mut fill_q: Query<&mut Transform, (With<RepairBarFill>, Without<Window>, Without<Player>)>,
+    mut progress: Local<Option<(Entity, f32)>>,
+) {
+    let clear_bar = |commands: &mut Commands| {
+        for bar in &bar_q {
+            commands.entity(bar).despawn();
+        }
+    };
+    let Ok((player_tf, sheet)) = player_q.single() else { return; };
+    if !input.pressed(KeyCode::KeyF) {
+        if progress.take().is_some() {
+            clear_bar(&mut commands);
+        }
+        return;
+    }
+    let player_pos = player_tf.translation.truncate();
+
+    let nearest = windows
+        .iter_mut()
+        .filter(|(_, _, _, state)| **state == GlassState::Broken)
+        .map(|(e, tf, health, _)| (e, tf.translation.truncate(), health))
+        .filter(|(_, pos, _)| pos.distance(player_pos) < REPAIR_RANGE)
+        .min_by(|a, b| a.1.distance(player_pos).total_cmp(&b.1.distance(player_pos)));
+    let Some((window, window_pos, mut health)) = nearest else {
+        if progress.take().is_some() {
+            clear_bar(&mut commands);
+        }
+        return;
+    };
+
+    // Moving on to a different window starts over.
+    let elapsed = match *progress {
+        Some((e, secs)) if e == window => secs + time.delta_secs(),
+        _ => {
+            clear_bar(&mut commands);
+            commands
+                .spawn((
+                    Sprite::from_color(Color::srgba(0.0, 0.0, 0.0, 0.7), Vec2::new(REPAIR_BAR_WIDTH, 5.0)),
+                    Transform::from_translation((window_pos + Vec2::Y * TILE_SIZE * 0.8).extend(Z_ENTITIES + 5.0)),
+                    RepairBar,
+                    GameEntity,
+                ))
+                .with_children(|bar| {
+                    bar.spawn((
+                        Sprite::from_color(REPAIR_BAR_COLOR, Vec2::new(REPAIR_BAR_WIDTH, 5.0)),
+                        Transform::from_xyz(0.0, 0.0, 0.1).with_scale(Vec3::new(0.0, 1.0, 1.0)),
+                        RepairBarFill,
+                    ));
+                });
+            time.delta_secs()
+        }
+    };
+
+    let needed = HAND_REPAIR_SECS / sheet.get(Stat::RepairSpeed);
+    let fraction = (elapsed / needed).min(1.0);
+    for mut fill in &mut fill_q {
+        fill.scale.x = fraction;
+        fill.translation.x = -REPAIR_BAR_WIDTH * 0.5 * (1.0 - fraction);
+    }
+
+    if fraction >= 1.0 {
+        health.0 = window::MAX_HEALTH;
+        *progress = None;
+        clear_bar(&mut commands);
+        spawn_popup(&mut commands, &font, window_pos, "Sealed");
+    } else {
+        *progress = Some((window, elapsed));
+    }
+}
+
+/// Janitor passive: a broom kill drops a second helping of scrap.
+fn clean_sweep(
+    mut commands: Commands,
+    mut deaths: EventReader<DeathEvent>,
+    player_q: Query<&CrewRole, With<Player>>,
+    enemies: Query<(), With<crate::enemies::Enemy>>,
+) {
+    let is_janitor = player_q.single().is_ok_and(|r| *r == CrewRole::Janitor);
+    for death in deaths.read() {
+        if !is_janitor || death.killer != DamageSource::Player || death.kind != DamageKind::Melee { continue; }
+        if enemies.get(death.entity).is_err() { continue; }
+        crate::shop::drop_scrap(&mut commands, death.pos);
+    }
+}
+
+/// Security passive: hits from the player's own bullets sometimes leave the
+/// target Vulnerable. Sentry bolts don't count.
+fn hollow_points(
+    mut hits: EventReader<BulletHitEvent>,
+    player_q: Query<&CrewRole, With<Player>>,
+    sentry_shots: Query<(), With<SentryShot>>,
+    mut statuses: EventWriter<ApplyStatusEvent>,
+) {
+    let is_security = player_q.single().is_ok_and(|r| *r == CrewRole::Security);
+    for hit in hits.read() {
+        if !is_security || sentry_shots.contains(hit.bullet) { continue; }
+        if rand::random::<f32>() >= HOLLOW_POINT_CHANCE { continue; }
+        statuses.write(ApplyStatusEvent {
+            target: hit.enemy,
+            kind: StatusKind::Vulnerable,
+            stacks: 1,
+            secs: HOLLOW_POINT_SECS,
+        });
+    }
+}
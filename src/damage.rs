@@ -0,0 +1,329 @@
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
+use std::io::Write;
+
+use crate::enemies::Enemy;
+use crate::player::{Armor, Player, Shield, armor_factor};
+use crate::rewards::RewardFont;
+use crate::status::{StatusEffects, StatusKind, VULNERABLE_PER_STACK};
+use crate::{GameEntity, GameState, SavedPlayerBuffs};
+
+/// Where F9 writes the combat log.
+const COMBAT_LOG_PATH: &str = "combat_log.csv";
+/// Hits smaller than this (beam ticks) are summed per target until they
+/// add up to one readable number.
+const MIN_NUMBER: f32 = 5.0;
+const NUMBER_SECS: f32 = 0.7;
+/// The log keeps only this many of the most recent hits.
+const COMBAT_LOG_CAP: usize = 5000;
+
+/// What dealt a hit. Decides which defences get a say.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DamageKind {
+    Bullet,
+    Melee,
+    /// Thrown tables and other fast-moving debris.
+    Impact,
+    Explosion,
+    Beam,
+    /// Burning and poison ticks.
+    Status,
+    /// Suffocation, floor hazards and being blown out of the station.
+    Environment,
+}
+
+impl DamageKind {
+    /// Whether a shield charge can soak the hit and armor can reduce it.
+    /// Damage over time and the station itself go straight to health.
+    pub fn blockable(self) -> bool {
+        !matches!(self, DamageKind::Status | DamageKind::Environment)
+    }
+}
+
+/// Who gets the credit for a hit.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DamageSource {
+    Player,
+    Enemy,
+    /// Loose tables, hazards, vacuum and bad air.
+    Station,
+    Status(StatusKind),
+}
+
+/// Ask for `amount` damage to be dealt to `target`. Every hit on the player
+/// or an enemy goes through here; `apply_damage` works out how much lands.
+#[derive(Event, Clone, Copy)]
+pub struct DamageEvent {
+    pub source: DamageSource,
+    pub target: Entity,
+    pub amount: f32,
+    pub kind: DamageKind,
+}
+
+/// Sent once when a hit takes the player or an enemy to 0 HP.
+#[derive(Event, Clone, Copy)]
+pub struct DeathEvent {
+    pub entity: Entity,
+    pub pos: Vec2,
+    pub killer: DamageSource,
+    pub kind: DamageKind,
+}
+
+/// The part of the pipeline every target shares, whatever the damage kind:
+/// Vulnerable stacks raise the damage taken. Which kinds shields and armor
+/// stop is decided in `PlayerDefenseItem::take`.
+pub fn mitigate(amount: f32, statuses: Option<&StatusEffects>) -> f32 {
+    let stacks = statuses.map_or(0, |s| s.stacks(StatusKind::Vulnerable));
+    amount * (1.0 + VULNERABLE_PER_STACK * stacks as f32)
+}
+
+/// Everything on the player that decides how much of a hit lands.
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct PlayerDefense {
+    pub health: &'static mut crate::player::Health,
+    pub armor: &'static Armor,
+    pub shield: &'static mut Shield,
+    pub statuses: Option<&'static StatusEffects>,
+}
+
+impl PlayerDefenseItem<'_> {
+    /// Runs a hit through vulnerability, shield and armor and takes what is
+    /// left off health. Returns the HP actually lost.
+    pub fn take(&mut self, amount: f32, kind: DamageKind) -> f32 {
+        let mut amount = mitigate(amount, self.statuses);
+        if kind.blockable() {
+            if self.shield.current >= 1.0 {
+                self.shield.current -= 1.0;
+                return 0.0;
+            }
+            amount *= armor_factor(self.armor.0);
+        }
+        self.health.0 -= amount;
+        amount
+    }
+}
+
+/// Everything on an enemy that decides how much of a hit lands.
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct EnemyDefense {
+    pub health: &'static mut crate::enemies::Health,
+    pub statuses: Option<&'static StatusEffects>,
+}
+
+impl EnemyDefenseItem<'_> {
+    /// Takes a hit off the enemy's health. Returns the HP actually lost.
+    pub fn take(&mut self, amount: f32) -> f32 {
+        let amount = mitigate(amount, self.statuses);
+        self.health.0 -= amount;
+        amount
+    }
+}
+
+/// One resolved hit.
+#[derive(Clone, Debug)]
+pub struct CombatEntry {
+    /// Seconds of game time since the game started.
+    pub time: f32,
+    pub source: DamageSource,
+    pub target: Entity,
+    pub target_is_player: bool,
+    pub kind: DamageKind,
+    pub pos: Vec2,
+    /// What was asked for.
+    pub raw: f32,
+    /// What came off health after shields, armor and vulnerability.
+    pub landed: f32,
+    pub fatal: bool,
+}
+
+/// The most recent hits of the current run, for damage numbers and
+/// balancing. Press F9 to write them to `combat_log.csv`.
+#[derive(Resource, Default)]
+pub struct CombatLog {
+    pub entries: VecDeque<CombatEntry>,
+    /// Hits logged since the log was last cleared, including ones that have
+    /// since been dropped off the front.
+    pub total: usize,
+}
+
+impl CombatLog {
+    fn push(&mut self, entry: CombatEntry) {
+        if self.entries.len() == COMBAT_LOG_CAP {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+        self.total += 1;
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.total = 0;
+    }
+
+    pub fn write_csv(&self, path: &str) -> std::io::Result<()> {
+        let mut out = std::io::BufWriter::new(std::fs::File::create(path)?);
+        writeln!(out, "time,source,target,kind,raw,landed,fatal")?;
+        for e in &self.entries {
+            let target = if e.target_is_player { "player".to_string() } else { format!("enemy#{}", e.target.index()) };
+            writeln!(
+                out,
+                "{:.2},{:?},{},{:?},{:.2},{:.2},{}",
+                e.time, e.source, target, e.kind, e.raw, e.landed, e.fatal,
+            )?;
+        }
+        out.flush()
+    }
+}
+
+#[derive(Component)]
+struct DamageNumber(Timer);
+
+pub struct DamagePlugin;
+
+impl Plugin for DamagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<DamageEvent>()
+            .add_event::<DeathEvent>()
+            .init_resource::<CombatLog>()
+            .add_systems(OnEnter(GameState::Loading), reset_combat_log)
+            .add_systems(
+                Update,
+                (
+                    apply_damage,
+                    spawn_damage_numbers.after(apply_damage),
+                    tick_damage_numbers,
+                    dump_combat_log,
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// A new run starts a fresh log; continuing to the next station keeps it.
+fn reset_combat_log(mut log: ResMut<CombatLog>, saved: Option<Res<SavedPlayerBuffs>>) {
+    if saved.is_none() {
+        log.clear();
+    }
+}
+
+/// The health system: resolves every `DamageEvent` against the target's
+/// defences, logs it, and announces the hit that kills.
+pub(crate) fn apply_damage(
+    time: Res<Time>,
+    mut events: EventReader<DamageEvent>,
+    mut players: Query<(PlayerDefense, &Transform), With<Player>>,
+    mut enemies: Query<(EnemyDefense, &Transform), (With<Enemy>, Without<Player>)>,
+    mut log: ResMut<CombatLog>,
+    mut deaths: EventWriter<DeathEvent>,
+) {
+    for ev in events.read() {
+        let (landed, before, after, pos, target_is_player) = if let Ok((mut player, tf)) = players.get_mut(ev.target) {
+            let before = player.health.0;
+            let landed = player.take(ev.amount, ev.kind);
+            (landed, before, player.health.0, tf.translation.truncate(), true)
+        } else if let Ok((mut enemy, tf)) = enemies.get_mut(ev.target) {
+            let before = enemy.health.0;
+            let landed = enemy.take(ev.amount);
+            (landed, before, enemy.health.0, tf.translation.truncate(), false)
+        } else {
+            continue;
+        };
+
+        let fatal = before > 0.0 && after <= 0.0;
+        if fatal {
+            deaths.write(DeathEvent { entity: ev.target, pos, killer: ev.source, kind: ev.kind });
+        }
+        log.push(CombatEntry {
+            time: time.elapsed_secs(),
+            source: ev.source,
+            target: ev.target,
+            target_is_player,
+            kind: ev.kind,
+            pos,
+            raw: ev.amount,
+            landed,
+            fatal,
+        });
+    }
+}
+
+/// Floating numbers for the hits logged since last frame. Red for the
+/// player, white for enemies, blue when a shield soaked it. Partial totals
+/// are forgotten once their target dies.
+fn spawn_damage_numbers(
+    mut commands: Commands,
+    log: Res<CombatLog>,
+    font: Res<RewardFont>,
+    mut deaths: EventReader<DeathEvent>,
+    mut seen: Local<usize>,
+    mut pending: Local<HashMap<Entity, f32>>,
+) {
+    if *seen > log.total {
+        *seen = 0;
+        pending.clear();
+    }
+    let new = (log.total - *seen).min(log.entries.len());
+    for e in log.entries.iter().skip(log.entries.len() - new) {
+        let (text, color) = if e.landed <= 0.0 && e.target_is_player {
+            ("Blocked".to_string(), Color::srgb(0.5, 0.8, 1.0))
+        } else {
+            let total = pending.entry(e.target).or_default();
+            *total += e.landed;
+            if *total < MIN_NUMBER && !e.fatal { continue; }
+            let amount = std::mem::take(total);
+            let color = if e.target_is_player { Color::srgb(1.0, 0.3, 0.3) } else { Color::WHITE };
+            (format!("{amount:.0}"), color)
+        };
+        let jitter = Vec2::new(rand::random_range(-8.0..8.0), 0.0);
+        let pos = e.pos + jitter + Vec2::Y * crate::TILE_SIZE * 0.5;
+        commands.spawn((
+            Text2d::new(text),
+            TextFont { font: font.0.clone(), font_size: 16.0, ..default() },
+            TextColor(color),
+            Transform::from_xyz(pos.x, pos.y, 10.0),
+            DamageNumber(Timer::from_seconds(NUMBER_SECS, TimerMode::Once)),
+            GameEntity,
+        ));
+    }
+    *seen = log.total;
+    for death in deaths.read() {
+        pending.remove(&death.entity);
+    }
+}
+
+fn tick_damage_numbers(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut q: Query<(Entity, &mut Transform, &mut TextColor, &mut DamageNumber)>,
+) {
+    for (entity, mut tf, mut color, mut number) in &mut q {
+        number.0.tick(time.delta());
+        tf.translation.y += 50.0 * time.delta_secs();
+        color.0 = color.0.with_alpha(1.0 - number.0.fraction());
+        if number.0.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn dump_combat_log(keys: Res<ButtonInput<KeyCode>>, log: Res<CombatLog>) {
+    if !keys.just_pressed(KeyCode::F9) { return; }
+    match log.write_csv(COMBAT_LOG_PATH) {
+        Ok(()) => info!("Wrote {} combat log entries to {COMBAT_LOG_PATH}", log.entries.len()),
+        Err(e) => warn!("Couldn't write combat log: {e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mitigate_passes_damage_through_without_statuses() {
+        assert_eq!(mitigate(12.0, None), 12.0);
+        assert_eq!(mitigate(12.0, Some(&StatusEffects::default())), 12.0);
+    }
+}
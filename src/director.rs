@@ -0,0 +1,236 @@
+use bevy::prelude::*;
+
+use crate::player::{Health, MaxHealth, NumOfCleared, Player, Shield};
+use crate::room::{LevelState, RoomVec};
+use crate::{GameEntity, GameState, SavedPlayerBuffs, StationLevel, FONT_PATH};
+
+/// Adaptive difficulty director.
+///
+/// Scores the player after every cleared room (damage taken, clear time and
+/// the health/shield they finished with) and folds it into `intensity`:
+/// -1 means the player is struggling, +1 means they are cruising. Spawn
+/// budget, elite chance, enemy fire rate and reward quality all read from
+/// here, each clamped to a narrow band so the game never swings wildly.
+///
+/// F3 shows a debug readout, F4 turns the director off (everything falls
+/// back to the plain progress-based curve).
+#[derive(Resource)]
+pub struct Director {
+    pub enabled: bool,
+    pub show_debug: bool,
+    pub intensity: f32,
+    pub rooms_scored: u32,
+    pub last_score: f32,
+    tracking: Option<RoomTracking>,
+}
+
+struct RoomTracking {
+    room: usize,
+    elapsed: f32,
+    damage_taken: f32,
+    last_health: f32,
+    last_shield: f32,
+    start_enemies: usize,
+}
+
+impl Default for Director {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            show_debug: false,
+            intensity: 0.0,
+            rooms_scored: 0,
+            last_score: 0.0,
+            tracking: None,
+        }
+    }
+}
+
+// ── Tuning ─────────────────────────────────────────────────────────────────
+
+/// How strongly a single room's score moves `intensity`.
+const SCORE_BLEND: f32 = 0.35;
+/// Damage (as a fraction of max health) taken in a room that counts as "par".
+const PAR_DAMAGE_FRAC: f32 = 0.3;
+/// Seconds per starting enemy that counts as a par clear time.
+const PAR_SECS_PER_ENEMY: f32 = 4.0;
+/// A shield charge is worth this much health when counting damage.
+const SHIELD_CHARGE_HP: f32 = 10.0;
+
+impl Director {
+    fn effective_intensity(&self) -> f32 {
+        if self.enabled { self.intensity } else { 0.0 }
+    }
+
+    /// Multiplier on the number of enemies a room spawns (0.75 – 1.3).
+    pub fn spawn_budget_mult(&self) -> f32 {
+        (1.0 + self.effective_intensity() * 0.3).clamp(0.75, 1.3)
+    }
+
+    /// Added to the station-based elite chance (±8%).
+    pub fn elite_chance_bonus(&self) -> f32 {
+        self.effective_intensity() * 0.08
+    }
+
+    /// Enemy fire-rate multiplier. Rises with actual progress (rooms cleared
+    /// and stations completed), then nudged by intensity.
+    pub fn fire_rate_mult(&self, rooms_cleared: usize, station_level: u32) -> f32 {
+        let progress = 1.0 + rooms_cleared as f32 * 0.05 + station_level as f32 * 0.10;
+        (progress * (1.0 + self.effective_intensity() * 0.15)).clamp(0.8, 2.0)
+    }
+
+    /// Reward quality in 0..1 — higher for a struggling player.
+    pub fn reward_quality(&self) -> f32 {
+        (0.5 - self.effective_intensity() * 0.5).clamp(0.0, 1.0)
+    }
+
+    /// Struggling players get an extra heart when they clear a room.
+    pub fn bonus_heart(&self) -> bool {
+        self.reward_quality() >= 0.7
+    }
+}
+
+// ── Components ─────────────────────────────────────────────────────────────
+
+#[derive(Component)]
+struct DirectorDebugText;
+
+// ── Plugin ─────────────────────────────────────────────────────────────────
+
+pub struct DirectorPlugin;
+
+impl Plugin for DirectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Director>()
+            .add_systems(OnEnter(GameState::Loading), reset_director)
+            .add_systems(OnEnter(GameState::Playing), spawn_director_debug)
+            .add_systems(
+                Update,
+                (
+                    track_room_performance,
+                    toggle_director.run_if(not(resource_exists::<crate::pause::IsPaused>)),
+                    update_director_debug,
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// A brand-new run (no saved buffs) starts from neutral; continuing to the
+/// next station keeps what the director learned.
+fn reset_director(mut director: ResMut<Director>, saved: Option<Res<SavedPlayerBuffs>>) {
+    director.tracking = None;
+    if saved.is_none() {
+        director.intensity = 0.0;
+        director.rooms_scored = 0;
+        director.last_score = 0.0;
+    }
+}
+
+fn toggle_director(keys: Res<ButtonInput<KeyCode>>, mut director: ResMut<Director>) {
+    if keys.just_pressed(KeyCode::F3) {
+        director.show_debug = !director.show_debug;
+    }
+    if keys.just_pressed(KeyCode::F4) {
+        director.enabled = !director.enabled;
+        info!("Difficulty director {}", if director.enabled { "enabled" } else { "disabled" });
+    }
+}
+
+fn track_room_performance(
+    time: Res<Time>,
+    lvlstate: Res<LevelState>,
+    rooms: Res<RoomVec>,
+    player_q: Query<(&Health, &MaxHealth, &Shield), With<Player>>,
+    mut director: ResMut<Director>,
+) {
+    let Ok((health, max_health, shield)) = player_q.single() else { return };
+
+    // Start tracking as soon as the room locks.
+    if let LevelState::InRoom(index, _) = *lvlstate
+        && director.tracking.as_ref().is_none_or(|t| t.room != index)
+    {
+        director.tracking = Some(RoomTracking {
+            room: index,
+            elapsed: 0.0,
+            damage_taken: 0.0,
+            last_health: health.0,
+            last_shield: shield.current,
+            start_enemies: rooms.0[index].numofenemies.max(1),
+        });
+    }
+
+    let Some(tracking) = director.tracking.as_mut() else { return };
+    tracking.elapsed += time.delta_secs();
+    tracking.damage_taken += (tracking.last_health - health.0).max(0.0);
+    tracking.damage_taken += (tracking.last_shield - shield.current).max(0.0) * SHIELD_CHARGE_HP;
+    tracking.last_health = health.0;
+    tracking.last_shield = shield.current;
+
+    if !rooms.0[tracking.room].cleared {
+        return;
+    }
+
+    let damage_frac = tracking.damage_taken / max_health.0.max(1.0);
+    let par_time = tracking.start_enemies as f32 * PAR_SECS_PER_ENEMY;
+    let damage_score = ((PAR_DAMAGE_FRAC - damage_frac) / PAR_DAMAGE_FRAC).clamp(-1.0, 1.0);
+    let time_score = ((par_time - tracking.elapsed) / par_time).clamp(-1.0, 1.0);
+    let health_score = (health.0 / max_health.0.max(1.0) * 2.0 - 1.0).clamp(-1.0, 1.0);
+    let shield_score = if shield.max > 0.0 { shield.current / shield.max } else { 0.0 };
+
+    let score = (damage_score * 0.45 + time_score * 0.25 + health_score * 0.2 + shield_score * 0.1)
+        .clamp(-1.0, 1.0);
+
+    director.intensity = (director.intensity + (score - director.intensity) * SCORE_BLEND).clamp(-1.0, 1.0);
+    director.last_score = score;
+    director.rooms_scored += 1;
+    director.tracking = None;
+}
+
+fn spawn_director_debug(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font: asset_server.load(FONT_PATH),
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.6, 1.0, 0.6)),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(12.0),
+            bottom: Val::Px(12.0),
+            ..default()
+        },
+        Visibility::Hidden,
+        DirectorDebugText,
+        GameEntity,
+    ));
+}
+
+fn update_director_debug(
+    director: Res<Director>,
+    station_level: Res<StationLevel>,
+    cleared_q: Query<&NumOfCleared, With<Player>>,
+    mut text_q: Query<(&mut Text, &mut Visibility), With<DirectorDebugText>>,
+) {
+    let Ok((mut text, mut vis)) = text_q.single_mut() else { return };
+    if !director.show_debug {
+        *vis = Visibility::Hidden;
+        return;
+    }
+    *vis = Visibility::Visible;
+
+    let cleared = cleared_q.single().map(|c| c.0).unwrap_or(0);
+    text.0 = format!(
+        "Director: {}\nIntensity: {:+.2}  (last room {:+.2}, {} scored)\nSpawn x{:.2}  Elite {:+.0}%  Fire x{:.2}\nReward quality {:.2}",
+        if director.enabled { "ON" } else { "OFF (F4)" },
+        director.intensity,
+        director.last_score,
+        director.rooms_scored,
+        director.spawn_budget_mult(),
+        director.elite_chance_bonus() * 100.0,
+        director.fire_rate_mult(cleared, station_level.0),
+        director.reward_quality(),
+    );
+}
@@ -0,0 +1,595 @@
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+use crate::collidable::Collider;
+use crate::enemies::patterns::{BulletPattern, BulletStyle, fire_pattern, player_target};
+use crate::enemies::{Enemy, EnemyRes, Health, MaxHealth, Velocity, ENEMY_ACCEL, ENEMY_SIZE};
+use crate::player::Player;
+use crate::room::{LevelState, RoomVec};
+use crate::weapons::{EnemyBulletRes, WeaponSounds};
+use crate::window;
+use crate::{GameEntity, GameState, StationLevel, FONT_PATH, Z_ENTITIES};
+
+// ── Components & resources ─────────────────────────────────────────────────
+
+/// The station boss. Lives in the room furthest from the airlock and must be
+/// killed before the station counts as cleared.
+#[derive(Component)]
+pub struct Boss;
+
+/// Chasers summoned by the boss during its second phase.
+#[derive(Component)]
+pub struct BossMinion;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BossPhase {
+    /// Above 66% HP: stays near the centre of the room and fills it with spirals and rings.
+    Barrage,
+    /// 33–66% HP: keeps its distance, fires aimed spreads and summons chasers.
+    Summoner,
+    /// Below 33% HP: charges the player and shatters windows to drag them around.
+    Breaker,
+}
+
+impl BossPhase {
+    fn from_health_ratio(ratio: f32) -> Self {
+        if ratio > 0.66 {
+            BossPhase::Barrage
+        } else if ratio > 0.33 {
+            BossPhase::Summoner
+        } else {
+            BossPhase::Breaker
+        }
+    }
+
+    fn banner(self) -> &'static str {
+        match self {
+            BossPhase::Barrage => "The Overseer awakens!",
+            BossPhase::Summoner => "The Overseer calls for help!",
+            BossPhase::Breaker => "The Overseer is tearing the hull open!",
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct BossAI {
+    pub phase: BossPhase,
+    /// Fast timer driving the rotating spiral stream.
+    pub stream_timer: Timer,
+    /// Slow timer driving rings / aimed spreads.
+    pub volley_timer: Timer,
+    pub summon_timer: Timer,
+    pub shatter_timer: Timer,
+    pub spiral_angle: f32,
+    /// Centre of the boss room — the boss drifts back here during the barrage phase.
+    pub home: Vec2,
+}
+
+impl BossAI {
+    fn new(home: Vec2) -> Self {
+        Self {
+            phase: BossPhase::Barrage,
+            stream_timer: Timer::from_seconds(0.12, TimerMode::Repeating),
+            volley_timer: Timer::from_seconds(1.6, TimerMode::Repeating),
+            summon_timer: Timer::from_seconds(4.0, TimerMode::Repeating),
+            shatter_timer: Timer::from_seconds(6.0, TimerMode::Repeating),
+            spiral_angle: 0.0,
+            home,
+        }
+    }
+}
+
+/// Per-level boss bookkeeping, reset every time a station is loaded.
+#[derive(Resource, Default)]
+pub struct BossState {
+    pub spawned: bool,
+    pub defeated: bool,
+}
+
+#[derive(Resource)]
+pub struct BossRes {
+    pub image: Handle<Image>,
+}
+
+#[derive(Event)]
+pub struct BossShootEvent {
+    pub shooter: Entity,
+    pub origin: Vec3,
+    pub direction: Vec2,
+    pub pattern: BulletPattern,
+}
+
+#[derive(Component)]
+struct BossHealthBarRoot;
+
+#[derive(Component)]
+struct BossHealthBarFill;
+
+#[derive(Component)]
+struct BossBanner {
+    timer: Timer,
+}
+
+// ── Tuning ─────────────────────────────────────────────────────────────────
+
+const BOSS_BASE_HP: f32 = 1500.0;
+const BOSS_SIZE: f32 = ENEMY_SIZE * 2.5;
+const BOSS_SPEED: f32 = 140.0;
+const BOSS_BULLET_DAMAGE: f32 = 14.0;
+const BOSS_BULLET_SCALE: f32 = 0.3;
+const BOSS_BULLET_SPEED: f32 = 380.0;
+const MAX_MINIONS: usize = 4;
+
+// ── Plugin ─────────────────────────────────────────────────────────────────
+
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BossState>()
+            .add_event::<BossShootEvent>()
+            .add_systems(Startup, load_boss_assets)
+            .add_systems(OnEnter(GameState::Loading), reset_boss_state)
+            .add_systems(
+                Update,
+                (
+                    // Counted into the room before it can be seen as cleared.
+                    spawn_boss_on_room_entry
+                        .after(crate::room::entered_room)
+                        .before(crate::room::playing_room),
+                    boss_phase_system,
+                    boss_ai.after(boss_phase_system),
+                    spawn_boss_bullets.after(boss_ai),
+                    move_boss.after(boss_ai),
+                    boss_summon_minions.after(boss_phase_system),
+                    boss_shatter_windows.after(boss_phase_system),
+                    update_boss_health_bar,
+                    boss_banner_lifecycle,
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+// ── Asset loading ──────────────────────────────────────────────────────────
+
+fn load_boss_assets(mut commands: Commands, assets: Res<AssetServer>) {
+    commands.insert_resource(BossRes { image: assets.load("reaper/reaper1.png") });
+}
+
+fn reset_boss_state(mut commands: Commands) {
+    commands.insert_resource(BossState::default());
+}
+
+// ── Spawn ──────────────────────────────────────────────────────────────────
+
+/// Spawns the boss the first time the player locks themselves into the boss room.
+/// The boss counts as one of the room's enemies, so the doors stay shut until it dies.
+fn spawn_boss_on_room_entry(
+    mut commands: Commands,
+    lvlstate: Res<LevelState>,
+    mut rooms: ResMut<RoomVec>,
+    mut state: ResMut<BossState>,
+    res: Res<BossRes>,
+    station_level: Res<StationLevel>,
+    wall_grid: Res<crate::map::WallGrid>,
+    grid: Res<crate::map::MapGridMeta>,
+    asset_server: Res<AssetServer>,
+) {
+    if state.spawned { return; }
+    let LevelState::InRoom(idx, _) = *lvlstate else { return };
+    let Some(room) = rooms.0.get_mut(idx) else { return };
+    if !room.is_boss_room { return; }
+
+    let centre = (room.top_left_corner + room.bot_right_corner) * 0.5;
+    let home = crate::room::nearest_floor_pos(centre, &wall_grid, &grid);
+    let hp = BOSS_BASE_HP * (1.0 + station_level.0 as f32 * 0.5);
+
+    commands.spawn((
+        Sprite {
+            image: res.image.clone(),
+            custom_size: Some(Vec2::splat(BOSS_SIZE)),
+            color: Color::srgb(1.0, 0.55, 0.55),
+            ..default()
+        },
+        Transform::from_xyz(home.x, home.y, Z_ENTITIES),
+        Enemy,
+        Boss,
+        BossAI::new(home),
+        Velocity::new(),
+        Health::new(hp),
+        MaxHealth(hp),
+        Collider { half_extents: Vec2::splat(BOSS_SIZE * 0.4) },
+        crate::fluiddynamics::PulledByFluid { mass: 200.0 },
+        crate::status::StatusResist::unstoppable()
+            .with(crate::status::StatusKind::Burning, 0.5)
+            .with(crate::status::StatusKind::Poisoned, 0.5),
+        GameEntity,
+    ));
+    room.numofenemies += 1;
+    state.spawned = true;
+
+    spawn_boss_health_bar(&mut commands, &asset_server);
+    spawn_boss_banner(&mut commands, &asset_server, BossPhase::Barrage.banner());
+}
+
+fn spawn_boss_health_bar(commands: &mut Commands, assets: &AssetServer) {
+    let font: Handle<Font> = assets.load(FONT_PATH);
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                top: Val::Px(12.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            ZIndex(15),
+            BossHealthBarRoot,
+            GameEntity,
+        ))
+        .with_children(|col| {
+            col.spawn((
+                Text::new("STATION OVERSEER"),
+                TextFont { font, font_size: 22.0, ..default() },
+                TextColor(Color::srgb(1.0, 0.35, 0.35)),
+            ));
+            col.spawn((
+                Node {
+                    width: Val::Px(520.0),
+                    height: Val::Px(16.0),
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.2, 0.0, 0.0, 0.85)),
+                BorderRadius::all(Val::Px(3.0)),
+            ))
+            .with_children(|bg| {
+                bg.spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.85, 0.1, 0.1)),
+                    BorderRadius::all(Val::Px(3.0)),
+                    BossHealthBarFill,
+                ));
+            });
+        });
+}
+
+/// Centre-screen banner announcing a phase change; despawns itself after 2.5 s.
+fn spawn_boss_banner(commands: &mut Commands, assets: &AssetServer, text: &str) {
+    let font: Handle<Font> = assets.load(FONT_PATH);
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            ZIndex(200),
+            BossBanner { timer: Timer::from_seconds(2.5, TimerMode::Once) },
+            GameEntity,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new(text),
+                TextFont { font, font_size: 40.0, ..default() },
+                TextColor(Color::srgb(1.0, 0.25, 0.1)),
+            ));
+        });
+}
+
+// ── Systems ────────────────────────────────────────────────────────────────
+
+/// Moves the boss to the next phase once its health crosses a threshold.
+fn boss_phase_system(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    mut boss_q: Query<(&Health, &MaxHealth, &mut BossAI, &mut Sprite), With<Boss>>,
+) {
+    for (health, max_health, mut ai, mut sprite) in &mut boss_q {
+        let ratio = (health.0 / max_health.0).clamp(0.0, 1.0);
+        let phase = BossPhase::from_health_ratio(ratio);
+        if phase == ai.phase { continue; }
+
+        ai.phase = phase;
+        ai.volley_timer.reset();
+        ai.summon_timer.reset();
+        ai.shatter_timer.reset();
+        match phase {
+            BossPhase::Barrage => {}
+            BossPhase::Summoner => {
+                ai.volley_timer.set_duration(std::time::Duration::from_secs_f32(1.0));
+                sprite.color = Color::srgb(1.0, 0.4, 0.8);
+            }
+            BossPhase::Breaker => {
+                ai.stream_timer.set_duration(std::time::Duration::from_secs_f32(0.09));
+                ai.volley_timer.set_duration(std::time::Duration::from_secs_f32(0.8));
+                sprite.color = Color::srgb(1.0, 0.2, 0.1);
+            }
+        }
+        spawn_boss_banner(&mut commands, &assets, phase.banner());
+    }
+}
+
+fn boss_ai(
+    time: Res<Time>,
+    player_q: Query<&Transform, (With<Player>, Without<Boss>)>,
+    mut boss_q: Query<(Entity, &Transform, &mut Velocity, &mut BossAI), With<Boss>>,
+    mut shoot_writer: EventWriter<BossShootEvent>,
+) {
+    let Ok(player_tf) = player_q.single() else { return };
+    let player_pos = player_tf.translation.truncate();
+    let dt = time.delta_secs();
+
+    for (shooter, tf, mut vel, mut ai) in &mut boss_q {
+        let pos = tf.translation.truncate();
+        let to_player = (player_pos - pos).normalize_or_zero();
+        let accel = ENEMY_ACCEL * 0.5 * dt;
+
+        // Movement: each phase has its own preferred spot.
+        let target = match ai.phase {
+            BossPhase::Barrage => ai.home,
+            BossPhase::Summoner => player_pos - to_player * 320.0,
+            BossPhase::Breaker => player_pos,
+        };
+        let move_dir = if pos.distance(target) > 24.0 {
+            (target - pos).normalize_or_zero()
+        } else {
+            Vec2::ZERO
+        };
+        let max_speed = if ai.phase == BossPhase::Breaker { BOSS_SPEED * 1.6 } else { BOSS_SPEED };
+        vel.velocity = if move_dir == Vec2::ZERO {
+            vel.velocity * 0.9
+        } else {
+            (vel.velocity + move_dir * accel).clamp_length_max(max_speed)
+        };
+
+        ai.stream_timer.tick(time.delta());
+        ai.volley_timer.tick(time.delta());
+
+        let mut fire = |dir: Vec2, speed: f32, pattern: BulletPattern| {
+            shoot_writer.write(BossShootEvent { shooter, origin: tf.translation, direction: dir, pattern: pattern.speed(speed) });
+        };
+
+        // Spiral stream — three arms in the barrage phase, two while breaking.
+        if ai.phase != BossPhase::Summoner && ai.stream_timer.just_finished() {
+            let arms = if ai.phase == BossPhase::Barrage { 3 } else { 2 };
+            fire(Vec2::from_angle(ai.spiral_angle), BOSS_BULLET_SPEED, BulletPattern::ring(arms));
+            ai.spiral_angle = (ai.spiral_angle + 0.23) % TAU;
+        }
+
+        if ai.volley_timer.just_finished() {
+            match ai.phase {
+                // Three quick six-arm rings, each turned a little, so the gaps sweep.
+                BossPhase::Barrage => {
+                    let offset = ai.spiral_angle * 0.5;
+                    fire(
+                        Vec2::from_angle(offset),
+                        BOSS_BULLET_SPEED * 0.8,
+                        BulletPattern::spiral(6, 0.35).burst(3, 0.1),
+                    );
+                }
+                // 5-way aimed spread, plus a telegraphed homing shot.
+                BossPhase::Summoner => {
+                    fire(to_player, BOSS_BULLET_SPEED * 1.2, BulletPattern::spread(5, 0.9).aimed());
+                    fire(
+                        to_player,
+                        BOSS_BULLET_SPEED * 0.7,
+                        BulletPattern::single().aimed().delayed(0.4).homing(2.0),
+                    );
+                }
+                // Fast 3-way bursts that lead the player.
+                BossPhase::Breaker => {
+                    fire(
+                        to_player,
+                        BOSS_BULLET_SPEED * 1.5,
+                        BulletPattern::spread(3, 0.5).aimed_with_lead().burst(3, 0.12),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Axis-separated movement with wall sliding, same approach as `move_enemy`.
+fn move_boss(
+    time: Res<Time>,
+    mut boss_q: Query<(&mut Transform, &mut Velocity, &Collider), With<Boss>>,
+    wall_grid: Res<crate::map::WallGrid>,
+) {
+    let dt = time.delta_secs();
+    for (mut tf, mut vel, col) in &mut boss_q {
+        let half = col.half_extents;
+        let change = vel.velocity * dt;
+        let mut pos = tf.translation;
+
+        if change.x != 0.0 {
+            let mut nx = pos.x + change.x;
+            for (wall_pos, wall_half) in wall_grid.nearby(Vec2::new(nx, pos.y), 4) {
+                if crate::player::aabb_overlap(nx, pos.y, half, wall_pos.x, wall_pos.y, wall_half) {
+                    nx = if change.x > 0.0 {
+                        wall_pos.x - (half.x + wall_half.x)
+                    } else {
+                        wall_pos.x + (half.x + wall_half.x)
+                    };
+                    vel.velocity.x = 0.0;
+                }
+            }
+            pos.x = nx;
+        }
+
+        if change.y != 0.0 {
+            let mut ny = pos.y + change.y;
+            for (wall_pos, wall_half) in wall_grid.nearby(Vec2::new(pos.x, ny), 4) {
+                if crate::player::aabb_overlap(pos.x, ny, half, wall_pos.x, wall_pos.y, wall_half) {
+                    ny = if change.y > 0.0 {
+                        wall_pos.y - (half.y + wall_half.y)
+                    } else {
+                        wall_pos.y + (half.y + wall_half.y)
+                    };
+                    vel.velocity.y = 0.0;
+                }
+            }
+            pos.y = ny;
+        }
+
+        tf.translation = pos;
+    }
+}
+
+/// Summons chasers around the boss during the Summoner phase.
+/// Each minion is added to the room's enemy count so the doors stay locked.
+fn boss_summon_minions(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut boss_q: Query<(&Transform, &mut BossAI), With<Boss>>,
+    minion_q: Query<(), With<BossMinion>>,
+    enemy_res: Res<EnemyRes>,
+    lvlstate: Res<LevelState>,
+    mut rooms: ResMut<RoomVec>,
+    station_level: Res<StationLevel>,
+) {
+    let LevelState::InRoom(idx, _) = *lvlstate else { return };
+
+    for (tf, mut ai) in &mut boss_q {
+        if ai.phase != BossPhase::Summoner { continue; }
+        ai.summon_timer.tick(time.delta());
+        if !ai.summon_timer.just_finished() { continue; }
+
+        let alive = minion_q.iter().count();
+        let to_spawn = MAX_MINIONS.saturating_sub(alive).min(2);
+        let health_multiplier = 1.0 + station_level.0 as f32 * 0.5;
+        for i in 0..to_spawn {
+            let a = ai.spiral_angle + std::f32::consts::PI * i as f32;
+            let at = tf.translation + (Vec2::from_angle(a) * BOSS_SIZE).extend(0.0);
+            let minion = crate::enemies::spawn_enemy_at(&mut commands, &enemy_res, at, true, health_multiplier, 0.0);
+            commands.entity(minion).insert(BossMinion);
+            rooms.0[idx].numofenemies += 1;
+        }
+    }
+}
+
+/// In the Breaker phase the boss periodically smashes the intact window closest
+/// to the player, so the resulting breach drags them across the room.
+fn boss_shatter_windows(
+    time: Res<Time>,
+    mut boss_q: Query<&mut BossAI, With<Boss>>,
+    player_q: Query<&Transform, With<Player>>,
+    mut window_q: Query<(&Transform, &mut window::Health, &window::GlassState), With<window::Window>>,
+    lvlstate: Res<LevelState>,
+    rooms: Res<RoomVec>,
+) {
+    let LevelState::InRoom(idx, _) = *lvlstate else { return };
+    let Some(room) = rooms.0.get(idx) else { return };
+    let Ok(player_tf) = player_q.single() else { return };
+    let player_pos = player_tf.translation.truncate();
+
+    for mut ai in &mut boss_q {
+        if ai.phase != BossPhase::Breaker { continue; }
+        ai.shatter_timer.tick(time.delta());
+        if !ai.shatter_timer.just_finished() { continue; }
+
+        // Windows sit on the room border, so search slightly outside its bounds
+        // (same margin `track_window_breaches` uses).
+        let tlc = room.top_left_corner + Vec2::new(-64.0, 64.0);
+        let brc = room.bot_right_corner + Vec2::new(64.0, -64.0);
+        let target = window_q
+            .iter_mut()
+            .filter(|(tf, _, state)| {
+                let p = tf.translation.truncate();
+                **state == window::GlassState::Intact
+                    && p.x >= tlc.x && p.x <= brc.x && p.y <= tlc.y && p.y >= brc.y
+            })
+            .min_by(|(a, _, _), (b, _, _)| {
+                let da = a.translation.truncate().distance_squared(player_pos);
+                let db = b.translation.truncate().distance_squared(player_pos);
+                da.total_cmp(&db)
+            });
+
+        if let Some((_, mut health, _)) = target {
+            health.0 = 0.0;
+        }
+    }
+}
+
+fn spawn_boss_bullets(
+    mut commands: Commands,
+    mut events: EventReader<BossShootEvent>,
+    player_q: Query<(&Transform, &crate::bullet::Velocity), With<Player>>,
+    bullet_res: Res<EnemyBulletRes>,
+    weapon_sounds: Res<WeaponSounds>,
+) {
+    let target = player_target(&player_q);
+    let mut fired = false;
+    for ev in events.read() {
+        let style = BulletStyle {
+            damage: BOSS_BULLET_DAMAGE,
+            scale: BOSS_BULLET_SCALE,
+            muzzle: BOSS_SIZE * 0.4,
+            anim_secs: 0.15,
+            bounces: 0,
+        };
+        fired |= fire_pattern(
+            &mut commands, &bullet_res, ev.shooter, ev.origin.truncate(), ev.direction,
+            &ev.pattern, &style, target,
+        );
+    }
+
+    // One sound per frame no matter how many bullets went out.
+    if fired {
+        commands.spawn((
+            AudioPlayer::new(weapon_sounds.laser.clone()),
+            PlaybackSettings::DESPAWN,
+        ));
+    }
+}
+
+fn update_boss_health_bar(
+    mut commands: Commands,
+    mut state: ResMut<BossState>,
+    boss_q: Query<(&Health, &MaxHealth), With<Boss>>,
+    mut fill_q: Query<&mut Node, With<BossHealthBarFill>>,
+    root_q: Query<Entity, With<BossHealthBarRoot>>,
+) {
+    match boss_q.single() {
+        Ok((health, max_health)) => {
+            if let Ok(mut node) = fill_q.single_mut() {
+                let ratio = (health.0 / max_health.0).clamp(0.0, 1.0);
+                node.width = Val::Percent(ratio * 100.0);
+            }
+        }
+        Err(_) => {
+            // Boss is gone — it can only leave the world by dying.
+            if state.spawned && !state.defeated {
+                state.defeated = true;
+                for e in &root_q {
+                    commands.entity(e).despawn();
+                }
+            }
+        }
+    }
+}
+
+fn boss_banner_lifecycle(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut q: Query<(Entity, &mut BossBanner)>,
+) {
+    for (entity, mut banner) in &mut q {
+        banner.timer.tick(time.delta());
+        if banner.timer.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
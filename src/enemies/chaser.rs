@@ -55,7 +55,7 @@ pub fn spawn_at(
     active: bool,
     health_multiplier: f32,
     speed_bonus: f32,
-) {
+) -> Entity {
     let hp = 50.0 * health_multiplier;
     let mut e = commands.spawn((
         Sprite::from_image(res.frames[0].clone()),
@@ -76,6 +76,7 @@ pub fn spawn_at(
     if active {
         e.insert(ActiveEnemy);
     }
+    e.id()
 }
 
 // ── Systems ────────────────────────────────────────────────────────────────
@@ -106,7 +107,7 @@ pub fn animate_hit(
     mut commands: Commands,
     mut enemies: Query<
         (Entity, &mut Sprite, &mut HitAnimation),
-        (Without<super::ranger::RangedEnemy>, Without<crate::enemies::Reaper>, Without<super::turret::TurretEnemy>),
+        (Without<super::ranger::RangedEnemy>, Without<crate::enemies::Reaper>, Without<super::turret::TurretEnemy>, Without<super::boss::Boss>),
     >,
     enemy_res: Res<EnemyRes>,
 ) {
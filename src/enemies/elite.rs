@@ -0,0 +1,203 @@
+use bevy::prelude::*;
+
+use crate::fluiddynamics::PulledByFluid;
+use crate::explosion::ExplosionEvent;
+use crate::room::{LevelState, RoomVec};
+use crate::FONT_PATH;
+use super::{EnemyMoveSpeed, EnemyRes, Health, MaxHealth, BAR_Y_OFFSET, spawn_enemy_at};
+
+// ── Affixes ────────────────────────────────────────────────────────────────
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EliteAffix {
+    /// Heals whenever it lands a contact hit on the player.
+    Vampiric,
+    /// Blows up when killed, hurting the player if they are close.
+    Explosive,
+    /// Moves much faster than normal.
+    Hasted,
+    /// Has a chance to send player bullets straight back.
+    Reflective,
+    /// Anchored to the floor — hull breaches don't pull it around.
+    VacuumProof,
+    /// Splits into two weaker chasers when killed.
+    Splitter,
+}
+
+const ALL_AFFIXES: [EliteAffix; 6] = [
+    EliteAffix::Vampiric,
+    EliteAffix::Explosive,
+    EliteAffix::Hasted,
+    EliteAffix::Reflective,
+    EliteAffix::VacuumProof,
+    EliteAffix::Splitter,
+];
+
+impl EliteAffix {
+    pub fn name(self) -> &'static str {
+        match self {
+            EliteAffix::Vampiric => "Vampiric",
+            EliteAffix::Explosive => "Volatile",
+            EliteAffix::Hasted => "Hasted",
+            EliteAffix::Reflective => "Reflective",
+            EliteAffix::VacuumProof => "Anchored",
+            EliteAffix::Splitter => "Splitter",
+        }
+    }
+
+    fn tint(self) -> Color {
+        match self {
+            EliteAffix::Vampiric => Color::srgb(0.9, 0.3, 0.45),
+            EliteAffix::Explosive => Color::srgb(1.0, 0.55, 0.2),
+            EliteAffix::Hasted => Color::srgb(0.45, 0.9, 1.0),
+            EliteAffix::Reflective => Color::srgb(0.85, 0.85, 1.0),
+            EliteAffix::VacuumProof => Color::srgb(0.6, 0.6, 0.6),
+            EliteAffix::Splitter => Color::srgb(0.55, 1.0, 0.45),
+        }
+    }
+}
+
+// ── Components ─────────────────────────────────────────────────────────────
+
+#[derive(Component)]
+pub struct Elite {
+    pub affixes: Vec<EliteAffix>,
+}
+
+impl Elite {
+    pub fn has(&self, affix: EliteAffix) -> bool {
+        self.affixes.contains(&affix)
+    }
+}
+
+/// Name tag child drawn above the health bar.
+#[derive(Component)]
+pub struct EliteNameTag;
+
+// ── Tuning ─────────────────────────────────────────────────────────────────
+
+const ELITE_HEALTH_MULT: f32 = 1.6;
+const HASTED_SPEED_MULT: f32 = 1.5;
+pub const VAMPIRIC_HEAL: f32 = 25.0;
+pub const REFLECT_CHANCE: f32 = 0.35;
+const BLAST_RADIUS: f32 = 110.0;
+const BLAST_DAMAGE: f32 = 20.0;
+const BLAST_KNOCKBACK: f32 = 600.0;
+const SPLIT_COUNT: usize = 2;
+
+/// Chance that a room enemy spawns as an elite. Starts low on the first
+/// station and climbs by 5% per station, capped at 40%.
+pub fn elite_chance(station_level: u32) -> f32 {
+    (0.06 + station_level as f32 * 0.05).min(0.4)
+}
+
+/// One affix, plus a second from station 2 onward half of the time.
+pub fn roll_affixes(station_level: u32) -> Vec<EliteAffix> {
+    let first = ALL_AFFIXES[rand::random_range(0..ALL_AFFIXES.len())];
+    let mut affixes = vec![first];
+    if station_level >= 2 && rand::random::<f32>() < 0.5 {
+        let second = ALL_AFFIXES[rand::random_range(0..ALL_AFFIXES.len())];
+        if second != first {
+            affixes.push(second);
+        }
+    }
+    affixes
+}
+
+/// Turn a freshly spawned enemy into an elite. Stat changes, tint and the
+/// name tag are applied by `setup_elites` once the enemy exists.
+pub fn make_elite(commands: &mut Commands, entity: Entity, affixes: Vec<EliteAffix>) {
+    commands.entity(entity).insert(Elite { affixes });
+}
+
+// ── Systems ────────────────────────────────────────────────────────────────
+
+/// Applies the stat changes, tint and name tag to newly made elites.
+pub fn setup_elites(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    mut elites: Query<
+        (Entity, &Elite, &mut Sprite, &mut Health, &mut MaxHealth, Option<&mut EnemyMoveSpeed>),
+        Added<Elite>,
+    >,
+) {
+    for (entity, elite, mut sprite, mut health, mut max_health, speed) in &mut elites {
+        max_health.0 *= ELITE_HEALTH_MULT;
+        health.0 = max_health.0;
+
+        if elite.has(EliteAffix::Hasted)
+            && let Some(mut speed) = speed
+        {
+            speed.0 *= HASTED_SPEED_MULT;
+        }
+        if elite.has(EliteAffix::VacuumProof) {
+            commands.entity(entity).remove::<PulledByFluid>();
+        }
+
+        sprite.color = elite.affixes[0].tint();
+
+        let label = elite.affixes.iter().map(|a| a.name()).collect::<Vec<_>>().join(" ");
+        let font: Handle<Font> = assets.load(FONT_PATH);
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                Text2d::new(label),
+                TextFont { font, font_size: 11.0, ..default() },
+                TextColor(Color::srgb(1.0, 0.85, 0.3)),
+                Transform::from_xyz(0.0, BAR_Y_OFFSET + 10.0, 3.0),
+                EliteNameTag,
+            ));
+        });
+    }
+}
+
+/// Death effects for elites. Runs before `check_enemy_health`, which still
+/// despawns the enemy and updates the room count.
+pub fn elite_death_effects(
+    mut commands: Commands,
+    mut deaths: EventReader<crate::damage::DeathEvent>,
+    elites: Query<(&Transform, &Elite)>,
+    mut explosions: EventWriter<ExplosionEvent>,
+    mut rooms: ResMut<RoomVec>,
+    lvlstate: Res<LevelState>,
+    enemy_res: Res<EnemyRes>,
+    rewards: Res<crate::rewards::RewardRegistry>,
+    stacks: Query<(&crate::rewards::RewardStacks, &crate::stats::StatSheet), With<crate::Player>>,
+    director: Res<crate::director::Director>,
+    wall_grid: Res<crate::map::WallGrid>,
+    grid: Res<crate::map::MapGridMeta>,
+) {
+    for death in deaths.read() {
+        let Ok((tf, elite)) = elites.get(death.entity) else { continue; };
+        let pos = tf.translation;
+
+        // Guaranteed extra reward for every elite kill (more with Greed).
+        if let Ok((stacks, sheet)) = stacks.single() {
+            for i in 0..sheet.get(crate::stats::Stat::RewardDrops) as u32 {
+                let offset = Vec2::new(i as f32 * crate::TILE_SIZE, 0.0);
+                let drop = crate::room::nearest_floor_pos(pos.truncate() + offset, &wall_grid, &grid);
+                crate::rewards::spawn_reward(&mut commands, drop.extend(crate::Z_ENTITIES), &rewards, stacks, director.reward_quality());
+            }
+        }
+
+        if elite.has(EliteAffix::Explosive) {
+            explosions.write(ExplosionEvent {
+                pos: pos.truncate(),
+                radius: BLAST_RADIUS,
+                damage: BLAST_DAMAGE,
+                knockback: BLAST_KNOCKBACK,
+                hurts_player: true,
+            });
+        }
+
+        if elite.has(EliteAffix::Splitter) {
+            for i in 0..SPLIT_COUNT {
+                let side = if i == 0 { -1.0 } else { 1.0 };
+                let at = pos + Vec3::new(side * 20.0, 0.0, 0.0);
+                spawn_enemy_at(&mut commands, &enemy_res, at, true, 0.5, 0.0);
+            }
+            if let LevelState::InRoom(index, _) = *lvlstate {
+                rooms.0[index].numofenemies += SPLIT_COUNT;
+            }
+        }
+    }
+}
@@ -1,4 +1,7 @@
+pub mod boss;
 pub mod chaser;
+pub mod elite;
+pub mod patterns;
 pub mod ranger;
 pub mod reaper;
 pub mod turret;
@@ -13,6 +16,8 @@ pub use ranger::{
     RangedAnimationTimer, RangedEnemy, RangedEnemyAI, RangedEnemyFrames,
     RangedEnemyRes, RangerShootEvent, spawn_ranged_enemy_at,
 };
+pub use boss::Boss;
+pub use elite::{Elite, EliteAffix};
 pub use reaper::Reaper;
 pub use turret::{TurretEnemy, TurretRes, TurretShootEvent, spawn_turret_enemy_at};
 
@@ -23,6 +28,7 @@ use crate::{GameState, TILE_SIZE};
 use crate::collidable::{Collider, Collidable};
 use crate::player::Player;
 use crate::room::{LevelState, RoomVec};
+use crate::damage::{DamageEvent, DamageKind, DamageSource, DeathEvent};
 use crate::table;
 
 // Shared constants
@@ -74,7 +80,7 @@ pub struct EnemyHealthBarFg;
 
 const BAR_WIDTH: f32 = 34.0;
 const BAR_HEIGHT: f32 = 4.0;
-const BAR_Y_OFFSET: f32 = 62.0;
+pub(crate) const BAR_Y_OFFSET: f32 = 62.0;
 
 /// Spawns the two bar sprites (background + fill) as children of an enemy entity.
 pub fn spawn_health_bar_children(parent: &mut ChildSpawnerCommands) {
@@ -183,6 +189,8 @@ impl Plugin for EnemyPlugin {
                     turret::ai.after(compute_enemy_paths),
                     ranger::spawn_ranger_bullets.after(ranger::ai),
                     turret::spawn_turret_bullets.after(turret::ai),
+                    patterns::tick_pending_patterns,
+                    patterns::steer_homing_bullets.before(crate::bullet::move_bullets),
                     move_enemy.after(ranger::ai).after(turret::ai),
                     move_reaper_freely.after(ranger::ai),
                     collide_enemies_with_enemies.after(move_enemy),
@@ -192,7 +200,15 @@ impl Plugin for EnemyPlugin {
                     .run_if(in_state(GameState::Playing)),
             )
             .add_systems(Update, kill_enemies_outside_station.run_if(in_state(GameState::Playing)))
-            .add_systems(Update, check_enemy_health.run_if(in_state(GameState::Playing)))
+            .add_systems(
+                Update,
+                (
+                    elite::setup_elites,
+                    elite::elite_death_effects.after(crate::damage::apply_damage).before(check_enemy_health),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(Update, check_enemy_health.after(crate::damage::apply_damage).run_if(in_state(GameState::Playing)))
         .add_systems(Update, update_enemy_health_bars.run_if(in_state(GameState::Playing)))
             .add_systems(Update, chaser::animate_hit)
             .add_systems(Update, table_hits_enemy)
@@ -227,7 +243,8 @@ fn update_enemy_health_bars(
 
 fn kill_enemies_outside_station(
     grid_meta: Res<crate::map::MapGridMeta>,
-    mut enemy_query: Query<(&Transform, &mut Health), (With<Enemy>, Without<Reaper>)>,
+    enemy_query: Query<(Entity, &Transform, &Health), (With<Enemy>, Without<Reaper>)>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
     let tile = crate::TILE_SIZE;
     let x_min = grid_meta.x0 - tile * 0.5;
@@ -235,10 +252,15 @@ fn kill_enemies_outside_station(
     let y_min = grid_meta.y0 - tile * 0.5;
     let y_max = grid_meta.y0 + grid_meta.rows as f32 * tile - tile * 0.5;
 
-    for (tf, mut hp) in &mut enemy_query {
+    for (entity, tf, hp) in &enemy_query {
         let p = tf.translation;
-        if p.x < x_min || p.x > x_max || p.y < y_min || p.y > y_max {
-            hp.0 = 0.0;
+        if hp.0 > 0.0 && (p.x < x_min || p.x > x_max || p.y < y_min || p.y > y_max) {
+            damage_events.write(DamageEvent {
+                source: DamageSource::Station,
+                target: entity,
+                amount: hp.0,
+                kind: DamageKind::Environment,
+            });
         }
     }
 }
@@ -405,30 +427,24 @@ fn compute_enemy_paths(
     }
 }
 
-fn check_enemy_health(
+/// Removes every enemy a `DeathEvent` names, counts it off the room and
+/// drops its scrap where it fell.
+pub(crate) fn check_enemy_health(
     mut commands: Commands,
-    enemy_query: Query<(Entity, &Health, &Transform), With<Enemy>>,
-    key_holder_q: Query<(), With<crate::key_chest::KeyHolder>>,
+    mut deaths: EventReader<DeathEvent>,
+    enemy_query: Query<(), With<Enemy>>,
     mut rooms: ResMut<RoomVec>,
     lvlstate: Res<LevelState>,
     mut last_kill_pos: ResMut<LastKillPos>,
-    key_res: Option<Res<crate::key_chest::KeyChestRes>>,
 ) {
-    for (entity, health, transform) in enemy_query.iter() {
-        if health.0 <= 0.0 {
-            if let LevelState::InRoom(index, _, _) = *lvlstate {
-                rooms.0[index].numofenemies -= 1;
-            }
-            last_kill_pos.0 = transform.translation.truncate();
-
-            if key_holder_q.get(entity).is_ok() {
-                if let Some(ref kr) = key_res {
-                    crate::key_chest::drop_key(&mut commands, kr, transform.translation);
-                }
-            }
-
-            commands.entity(entity).despawn();
+    for death in deaths.read() {
+        if enemy_query.get(death.entity).is_err() { continue; }
+        if let LevelState::InRoom(index, _) = *lvlstate {
+            rooms.0[index].numofenemies -= 1;
         }
+        last_kill_pos.0 = death.pos;
+        crate::shop::drop_scrap(&mut commands, death.pos);
+        commands.entity(death.entity).despawn();
     }
 }
 
@@ -645,20 +661,18 @@ fn collide_enemies_with_enemies(
 }
 
 fn table_hits_enemy(
-    mut enemy_query: Query<
-        (&Transform, &mut Health),
-        (With<Enemy>, Without<Reaper>),
-    >,
+    enemy_query: Query<(Entity, &Transform), (With<Enemy>, Without<Reaper>)>,
     table_query: Query<
         (&Transform, &Collider, Option<&Velocity>, &table::TableRoom),
         With<table::Table>,
     >,
     active_room: Res<table::ActiveRoom>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
     let Some(active) = active_room.0 else { return; };
     let enemy_half = Vec2::splat(ENEMY_SIZE * 0.5);
 
-    for (enemy_tf, mut health) in &mut enemy_query {
+    for (enemy, enemy_tf) in &enemy_query {
         let enemy_pos = enemy_tf.translation.truncate();
         for (table_tf, table_col, vel_opt, room) in &table_query {
             if room.0 != active { continue; }
@@ -671,7 +685,12 @@ fn table_hits_enemy(
             ) {
                 let speed = vel_opt.map(|v| v.velocity.length()).unwrap_or(0.0);
                 if speed > 5.0 {
-                    health.0 -= speed * 0.02;
+                    damage_events.write(DamageEvent {
+                        source: DamageSource::Station,
+                        target: enemy,
+                        amount: speed * 0.02,
+                        kind: DamageKind::Impact,
+                    });
                 }
             }
         }
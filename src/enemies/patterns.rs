@@ -0,0 +1,427 @@
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+use crate::GameEntity;
+use crate::bullet::{Bullet, BulletOwner, AnimationTimer, AnimationFrameCount};
+use crate::collidable::Collider;
+use crate::player::Player;
+use crate::weapons::{BulletDamage, EnemyBulletRes, WeaponSounds};
+
+// ── Pattern description ────────────────────────────────────────────────────
+//
+// A `BulletPattern` is plain data that an enemy shoot event carries around.
+// The shape decides how many bullets leave per shot and in which directions,
+// the aim decides what "forward" means, and the optional burst / delay /
+// homing settings compose on top of any shape:
+//
+//     BulletPattern::spread(5, 0.9).aimed_with_lead().burst(3, 0.12)
+//     BulletPattern::ring(12).speed(300.0).delayed(0.6).homing(1.5)
+//
+// Every projectile is still a regular `Bullet` with `BulletOwner::Enemy`, so
+// `bullet_collision` handles it exactly like a single straight shot.
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PatternShape {
+    /// One bullet straight along the aim direction.
+    Single,
+    /// `count` bullets fanned evenly across `arc` radians, centred on the aim.
+    Spread { count: u32, arc: f32 },
+    /// `count` bullets evenly spaced around a full circle, starting at the aim.
+    Ring { count: u32 },
+    /// Like a ring with `arms` bullets, but every shot of a burst rotates the
+    /// whole thing by `step` radians.
+    Spiral { arms: u32, step: f32 },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PatternAim {
+    /// Use the direction on the shoot event as-is.
+    Fixed,
+    /// Point at the player at the moment each shot leaves.
+    Player,
+    /// Point at where the player will be if they keep moving the same way.
+    PlayerWithLead,
+}
+
+/// Bullet speed for patterns that don't set one.
+const DEFAULT_SPEED: f32 = 400.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BulletPattern {
+    pub shape: PatternShape,
+    pub aim: PatternAim,
+    /// How fast every bullet of the pattern travels.
+    pub speed: f32,
+    /// Number of shots fired back-to-back (1 = a single volley).
+    pub burst_count: u32,
+    /// Seconds between shots of a burst.
+    pub burst_interval: f32,
+    /// Seconds to wait before the first shot leaves.
+    pub delay: f32,
+    /// Turn rate in radians/second for bullets that curve toward the player.
+    pub homing: Option<f32>,
+}
+
+impl Default for BulletPattern {
+    fn default() -> Self {
+        Self {
+            shape: PatternShape::Single,
+            aim: PatternAim::Fixed,
+            speed: DEFAULT_SPEED,
+            burst_count: 1,
+            burst_interval: 0.0,
+            delay: 0.0,
+            homing: None,
+        }
+    }
+}
+
+impl BulletPattern {
+    pub fn single() -> Self {
+        Self::default()
+    }
+
+    pub fn spread(count: u32, arc: f32) -> Self {
+        Self { shape: PatternShape::Spread { count, arc }, ..default() }
+    }
+
+    pub fn ring(count: u32) -> Self {
+        Self { shape: PatternShape::Ring { count }, ..default() }
+    }
+
+    pub fn spiral(arms: u32, step: f32) -> Self {
+        Self { shape: PatternShape::Spiral { arms, step }, ..default() }
+    }
+
+    pub fn aimed(mut self) -> Self {
+        self.aim = PatternAim::Player;
+        self
+    }
+
+    pub fn aimed_with_lead(mut self) -> Self {
+        self.aim = PatternAim::PlayerWithLead;
+        self
+    }
+
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    pub fn burst(mut self, count: u32, interval: f32) -> Self {
+        self.burst_count = count.max(1);
+        self.burst_interval = interval;
+        self
+    }
+
+    pub fn delayed(mut self, secs: f32) -> Self {
+        self.delay = secs;
+        self
+    }
+
+    pub fn homing(mut self, turn_rate: f32) -> Self {
+        self.homing = Some(turn_rate);
+        self
+    }
+
+    /// Directions for shot number `shot` of the pattern, given the resolved aim.
+    pub fn directions(&self, aim: Vec2, shot: u32) -> Vec<Vec2> {
+        let base = aim.to_angle();
+        match self.shape {
+            PatternShape::Single => vec![aim],
+            PatternShape::Spread { count, arc } => {
+                if count <= 1 {
+                    return vec![aim];
+                }
+                (0..count)
+                    .map(|i| {
+                        let t = i as f32 / (count - 1) as f32 - 0.5;
+                        Vec2::from_angle(base + t * arc)
+                    })
+                    .collect()
+            }
+            PatternShape::Ring { count } => ring_dirs(base, count),
+            PatternShape::Spiral { arms, step } => ring_dirs(base + step * shot as f32, arms),
+        }
+    }
+}
+
+fn ring_dirs(base: f32, count: u32) -> Vec<Vec2> {
+    let count = count.max(1);
+    (0..count)
+        .map(|i| Vec2::from_angle(base + TAU * i as f32 / count as f32))
+        .collect()
+}
+
+/// How a given enemy's bullets look and hurt; the pattern decides where they go.
+#[derive(Clone, Copy, Debug)]
+pub struct BulletStyle {
+    pub damage: f32,
+    pub scale: f32,
+    /// Distance from the shooter's centre at which bullets appear.
+    pub muzzle: f32,
+    pub anim_secs: f32,
+    /// Wall bounces before the bullet is spent.
+    pub bounces: u32,
+}
+
+/// Where the player is and how fast they are going, for aimed patterns.
+#[derive(Clone, Copy, Debug)]
+pub struct PatternTarget {
+    pub pos: Vec2,
+    pub vel: Vec2,
+}
+
+/// Solve for the direction a bullet of `speed` needs to leave `from` in to
+/// meet a target moving at constant velocity. Falls back to aiming straight
+/// at the target when it is out-running the bullet.
+pub fn lead_direction(from: Vec2, speed: f32, target: PatternTarget) -> Vec2 {
+    let rel = target.pos - from;
+    let a = target.vel.length_squared() - speed * speed;
+    let b = 2.0 * rel.dot(target.vel);
+    let c = rel.length_squared();
+
+    let t = if a.abs() < 1e-3 {
+        if b.abs() < 1e-3 { 0.0 } else { -c / b }
+    } else {
+        let disc = b * b - 4.0 * a * c;
+        if disc < 0.0 {
+            0.0
+        } else {
+            let sq = disc.sqrt();
+            let t1 = (-b - sq) / (2.0 * a);
+            let t2 = (-b + sq) / (2.0 * a);
+            match (t1 > 0.0, t2 > 0.0) {
+                (true, true) => t1.min(t2),
+                (true, false) => t1,
+                (false, true) => t2,
+                (false, false) => 0.0,
+            }
+        }
+    };
+
+    (rel + target.vel * t).normalize_or_zero()
+}
+
+// ── Components ─────────────────────────────────────────────────────────────
+
+/// Curves a bullet toward the player for a limited time so it stays dodgeable.
+#[derive(Component)]
+pub struct HomingBullet {
+    pub turn_rate: f32,
+    pub timer: Timer,
+}
+
+const HOMING_SECS: f32 = 1.5;
+
+/// A pattern still waiting on its delay or with burst shots left to fire.
+/// Dropped if `shooter` dies first.
+#[derive(Component)]
+pub struct PendingPattern {
+    shooter: Entity,
+    pattern: BulletPattern,
+    style: BulletStyle,
+    origin: Vec2,
+    direction: Vec2,
+    next_shot: u32,
+    timer: Timer,
+}
+
+// ── Firing ─────────────────────────────────────────────────────────────────
+
+/// Fire `pattern` from `origin`. Anything that has to happen later (a delay
+/// or the rest of a burst) is handed to a `PendingPattern` entity.
+/// Returns true if bullets left this frame, so callers can play a sound.
+pub fn fire_pattern(
+    commands: &mut Commands,
+    bullet_res: &EnemyBulletRes,
+    shooter: Entity,
+    origin: Vec2,
+    direction: Vec2,
+    pattern: &BulletPattern,
+    style: &BulletStyle,
+    target: Option<PatternTarget>,
+) -> bool {
+    if pattern.delay > 0.0 || pattern.burst_count > 1 {
+        let first_wait = if pattern.delay > 0.0 { pattern.delay } else { 0.0 };
+        let fires_now = first_wait == 0.0;
+        if fires_now {
+            emit_shot(commands, bullet_res, origin, direction, pattern, style, target, 0);
+        }
+        if !fires_now || pattern.burst_count > 1 {
+            let wait = if fires_now { pattern.burst_interval } else { first_wait };
+            commands.spawn((
+                PendingPattern {
+                    shooter,
+                    pattern: *pattern,
+                    style: *style,
+                    origin,
+                    direction,
+                    next_shot: if fires_now { 1 } else { 0 },
+                    timer: Timer::from_seconds(wait.max(0.001), TimerMode::Once),
+                },
+                GameEntity,
+            ));
+        }
+        return fires_now;
+    }
+
+    emit_shot(commands, bullet_res, origin, direction, pattern, style, target, 0)
+}
+
+fn emit_shot(
+    commands: &mut Commands,
+    bullet_res: &EnemyBulletRes,
+    origin: Vec2,
+    direction: Vec2,
+    pattern: &BulletPattern,
+    style: &BulletStyle,
+    target: Option<PatternTarget>,
+    shot: u32,
+) -> bool {
+    let aim = match (pattern.aim, target) {
+        (PatternAim::Player, Some(t)) => (t.pos - origin).normalize_or_zero(),
+        (PatternAim::PlayerWithLead, Some(t)) => lead_direction(origin, pattern.speed, t),
+        _ => direction.normalize_or_zero(),
+    };
+    if aim == Vec2::ZERO {
+        return false;
+    }
+
+    for dir in pattern.directions(aim, shot) {
+        spawn_enemy_bullet(commands, bullet_res, origin, dir, pattern, style);
+    }
+    true
+}
+
+/// Spawn one enemy projectile. Every pattern ends up here.
+pub fn spawn_enemy_bullet(
+    commands: &mut Commands,
+    bullet_res: &EnemyBulletRes,
+    origin: Vec2,
+    dir: Vec2,
+    pattern: &BulletPattern,
+    style: &BulletStyle,
+) {
+    let spawn_pos = origin + dir * style.muzzle;
+    let mut e = commands.spawn((
+        Sprite::from_atlas_image(
+            bullet_res.0.clone(),
+            TextureAtlas { layout: bullet_res.1.clone(), index: 0 },
+        ),
+        Transform {
+            translation: Vec3::new(spawn_pos.x, spawn_pos.y, 5.0),
+            scale: Vec3::splat(style.scale),
+            ..Default::default()
+        },
+        crate::bullet::Velocity(dir * pattern.speed),
+        Bullet,
+        BulletOwner::Enemy,
+        Collider { half_extents: Vec2::splat(5.0) },
+        BulletDamage(style.damage),
+        AnimationTimer(Timer::from_seconds(style.anim_secs, TimerMode::Repeating)),
+        AnimationFrameCount(3),
+        GameEntity,
+    ));
+    if style.bounces > 0 {
+        e.insert(crate::bullet::Ricochet(style.bounces));
+    }
+    if let Some(turn_rate) = pattern.homing {
+        e.insert(HomingBullet {
+            turn_rate,
+            timer: Timer::from_seconds(HOMING_SECS, TimerMode::Once),
+        });
+    }
+}
+
+/// Reads the player's position and velocity for aimed patterns.
+pub fn player_target(
+    player_q: &Query<(&Transform, &crate::bullet::Velocity), With<Player>>,
+) -> Option<PatternTarget> {
+    player_q.single().ok().map(|(tf, vel)| PatternTarget {
+        pos: tf.translation.truncate(),
+        vel: vel.0,
+    })
+}
+
+// ── Systems ────────────────────────────────────────────────────────────────
+
+/// Fires delayed patterns and the remaining shots of bursts, and drops the
+/// ones whose shooter is gone.
+pub fn tick_pending_patterns(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut pending_q: Query<(Entity, &mut PendingPattern)>,
+    shooters: Query<()>,
+    player_q: Query<(&Transform, &crate::bullet::Velocity), With<Player>>,
+    bullet_res: Res<EnemyBulletRes>,
+    weapon_sounds: Res<WeaponSounds>,
+) {
+    let target = player_target(&player_q);
+    let mut fired = false;
+
+    for (entity, mut pending) in &mut pending_q {
+        if !shooters.contains(pending.shooter) {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        pending.timer.tick(time.delta());
+        if !pending.timer.finished() {
+            continue;
+        }
+
+        let p = &*pending;
+        fired |= emit_shot(
+            &mut commands, &bullet_res, p.origin, p.direction,
+            &p.pattern, &p.style, target, p.next_shot,
+        );
+
+        pending.next_shot += 1;
+        if pending.next_shot >= pending.pattern.burst_count {
+            commands.entity(entity).despawn();
+        } else {
+            let interval = pending.pattern.burst_interval.max(0.001);
+            pending.timer = Timer::from_seconds(interval, TimerMode::Once);
+        }
+    }
+
+    if fired {
+        commands.spawn((
+            AudioPlayer::new(weapon_sounds.laser.clone()),
+            PlaybackSettings::DESPAWN,
+        ));
+    }
+}
+
+/// Turns homing bullets toward the player, keeping their speed.
+pub fn steer_homing_bullets(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut bullets: Query<(Entity, &Transform, &mut crate::bullet::Velocity, &mut HomingBullet)>,
+    player_q: Query<&Transform, With<Player>>,
+) {
+    let Ok(player_tf) = player_q.single() else { return };
+    let player_pos = player_tf.translation.truncate();
+    let dt = time.delta_secs();
+
+    for (entity, tf, mut vel, mut homing) in &mut bullets {
+        homing.timer.tick(time.delta());
+        if homing.timer.finished() {
+            commands.entity(entity).remove::<HomingBullet>();
+            continue;
+        }
+
+        let speed = vel.0.length();
+        if speed == 0.0 { continue; }
+        let to_player = player_pos - tf.translation.truncate();
+        if to_player == Vec2::ZERO { continue; }
+
+        let current = vel.0.to_angle();
+        let wanted = to_player.to_angle();
+        let mut delta = (wanted - current).rem_euclid(TAU);
+        if delta > std::f32::consts::PI { delta -= TAU; }
+        let max_turn = homing.turn_rate * dt;
+        let turned = current + delta.clamp(-max_turn, max_turn);
+        vel.0 = Vec2::from_angle(turned) * speed;
+    }
+}
@@ -3,10 +3,9 @@ use std::time::Duration;
 use crate::GameEntity;
 use crate::fluiddynamics::PulledByFluid;
 use crate::player::Player;
-use crate::room::LevelState;
-use crate::collidable::Collider;
-use crate::bullet::{Bullet, BulletOwner, AnimationTimer, AnimationFrameCount};
-use crate::weapons::{BulletDamage, EnemyBulletRes, WeaponSounds};
+use crate::weapons::{EnemyBulletRes, WeaponSounds};
+use crate::status::StatusEffects;
+use super::patterns::{BulletPattern, BulletStyle, fire_pattern, player_target};
 use super::{Enemy, Velocity, ActiveEnemy, Health, MaxHealth, ENEMY_ACCEL, ENEMY_SPEED, ANIM_TIME, spawn_health_bar_children, Reaper};
 
 // ── Components ─────────────────────────────────────────────────────────────
@@ -44,9 +43,10 @@ pub struct RangedEnemyRes {
 
 #[derive(Event)]
 pub struct RangerShootEvent {
+    pub shooter: Entity,
     pub origin: Vec3,
     pub direction: Vec2,
-    pub speed: f32,
+    pub pattern: BulletPattern,
 }
 
 // ── Asset loading ──────────────────────────────────────────────────────────
@@ -76,7 +76,7 @@ pub fn spawn_at(
     active: bool,
     health_multiplier: f32,
     speed_bonus: f32,
-) {
+) -> Entity {
     let hp = 40.0 * health_multiplier;
     let mut e = commands.spawn((
         Sprite::from_image(res.right_frames[0].clone()),
@@ -107,6 +107,7 @@ pub fn spawn_at(
     if active {
         e.insert(ActiveEnemy);
     }
+    e.id()
 }
 
 // ── Systems ────────────────────────────────────────────────────────────────
@@ -143,23 +144,25 @@ pub fn ai(
     time: Res<Time>,
     player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
     mut enemies: Query<
-        (&Transform, &mut Velocity, &mut RangedEnemyAI, Option<&super::EnemyMoveSpeed>, Option<&super::EnemyPathfinder>),
+        (Entity, &Transform, &mut Velocity, &mut RangedEnemyAI, Option<&super::EnemyMoveSpeed>, Option<&super::EnemyPathfinder>, Option<&StatusEffects>),
         (With<RangedEnemy>, Without<Reaper>),
     >,
     mut shoot_writer: EventWriter<RangerShootEvent>,
-    lvlstate: Res<LevelState>,
+    director: Res<crate::director::Director>,
+    station_level: Res<crate::StationLevel>,
+    cleared_q: Query<&crate::player::NumOfCleared, With<Player>>,
 ) {
     let Ok(player_tf) = player_query.single() else { return };
     let player_pos = player_tf.translation.truncate();
 
-    let difficulty_mult: f32 = match *lvlstate {
-        LevelState::InRoom(idx, _, _) | LevelState::EnteredRoom(idx) => 1.0 + (idx as f32 * 0.10),
-        LevelState::NotRoom => 1.0,
-    };
+    let rooms_cleared = cleared_q.single().map(|c| c.0).unwrap_or(0);
+    let difficulty_mult = director.fire_rate_mult(rooms_cleared, station_level.0);
 
-    for (enemy_tf, mut vel, mut enemy_ai, spd_opt, pathfinder_opt) in &mut enemies {
+    for (shooter, enemy_tf, mut vel, mut enemy_ai, spd_opt, pathfinder_opt, statuses) in &mut enemies {
         let max_speed = spd_opt.map_or(ENEMY_SPEED, |s| s.0);
-        let scaled_dt = time.delta_secs() * difficulty_mult;
+        // Stunned or frozen rangers hold their fire.
+        let stunned = statuses.is_some_and(StatusEffects::incapacitated);
+        let scaled_dt = if stunned { 0.0 } else { time.delta_secs() * difficulty_mult };
         enemy_ai.fire_cooldown.tick(Duration::from_secs_f32(scaled_dt));
 
         let enemy_pos = enemy_tf.translation.truncate();
@@ -184,11 +187,12 @@ pub fn ai(
             let move_dir = if delta > 20.0 { to_player } else if delta < -20.0 { -to_player } else { Vec2::ZERO };
             vel.velocity = (vel.velocity + move_dir * accel).clamp_length_max(max_speed);
 
-            if enemy_ai.fire_cooldown.finished() && dist <= enemy_ai.range {
+            if !stunned && enemy_ai.fire_cooldown.finished() && dist <= enemy_ai.range {
                 shoot_writer.write(RangerShootEvent {
+                    shooter,
                     origin: enemy_tf.translation,
                     direction: to_player,
-                    speed: enemy_ai.projectile_speed,
+                    pattern: BulletPattern::single().speed(enemy_ai.projectile_speed),
                 });
                 enemy_ai.fire_cooldown.reset();
             }
@@ -207,33 +211,24 @@ const RANGER_BULLET_SCALE: f32 = 0.25;
 pub fn spawn_ranger_bullets(
     mut commands: Commands,
     mut events: EventReader<RangerShootEvent>,
+    player_q: Query<(&Transform, &crate::bullet::Velocity), With<Player>>,
     bullet_res: Res<EnemyBulletRes>,
     weapon_sounds: Res<WeaponSounds>,
 ) {
+    let target = player_target(&player_q);
     for ev in events.read() {
-        let dir = ev.direction.normalize_or_zero();
-        if dir == Vec2::ZERO { continue; }
-        let spawn_pos = ev.origin.truncate() + dir * 16.0;
-
-        commands.spawn((
-            Sprite::from_atlas_image(
-                bullet_res.0.clone(),
-                TextureAtlas { layout: bullet_res.1.clone(), index: 0 },
-            ),
-            Transform {
-                translation: Vec3::new(spawn_pos.x, spawn_pos.y, 5.0),
-                scale: Vec3::splat(RANGER_BULLET_SCALE),
-                ..Default::default()
-            },
-            crate::bullet::Velocity(dir * ev.speed),
-            Bullet,
-            BulletOwner::Enemy,
-            Collider { half_extents: Vec2::splat(5.0) },
-            BulletDamage(RANGER_BULLET_DAMAGE),
-            AnimationTimer(Timer::from_seconds(0.2, TimerMode::Repeating)),
-            AnimationFrameCount(3),
-            GameEntity,
-        ));
+        let style = BulletStyle {
+            damage: RANGER_BULLET_DAMAGE,
+            scale: RANGER_BULLET_SCALE,
+            muzzle: 16.0,
+            anim_secs: 0.2,
+            bounces: 0,
+        };
+        let fired = fire_pattern(
+            &mut commands, &bullet_res, ev.shooter, ev.origin.truncate(), ev.direction,
+            &ev.pattern, &style, target,
+        );
+        if !fired { continue; }
 
         commands.spawn((
             AudioPlayer::new(weapon_sounds.laser.clone()),
@@ -1,17 +1,24 @@
 use bevy::prelude::*;
 
-use crate::bullet::{Bullet, BulletOwner, AnimationTimer, AnimationFrameCount};
+use crate::bullet::{Bullet, BulletOwner};
 use crate::collidable::{Collidable, Collider};
+use crate::damage::{DamageEvent, DamageKind, DamageSource};
 use crate::enemies::{ActiveEnemy, Enemy, Health, MaxHealth, RangedEnemy, RangedEnemyAI, Velocity, spawn_health_bar_children};
+use crate::enemies::patterns::{BulletPattern, BulletStyle, fire_pattern, player_target};
 use crate::player::Player;
 use crate::room::{LevelState, RoomVec};
+use crate::stats::{Stat, StatSheet};
+use crate::status::StatusResist;
 use crate::table;
-use crate::weapons::{BulletDamage, EnemyBulletRes, WeaponSounds};
+use crate::weapons::{EnemyBulletRes, WeaponSounds};
 use crate::{GameState, TILE_SIZE, Z_ENTITIES};
 use crate::GameEntity;
 
 // ── Components & resources ─────────────────────────────────────────────────
 
+/// How long the player gets in a room before the reaper turns up.
+const REAPER_DELAY_SECS: f32 = 7.0;
+
 #[derive(Component)]
 pub struct Reaper;
 
@@ -28,7 +35,7 @@ pub struct ReaperState {
 impl Default for ReaperState {
     fn default() -> Self {
         Self {
-            timer: Timer::from_seconds(7.0, TimerMode::Once),
+            timer: Timer::from_seconds(REAPER_DELAY_SECS, TimerMode::Once),
             current_room: None,
             spawned_in_room: None,
             spawned_in_final_room: false,
@@ -48,9 +55,10 @@ const REAPER_BULLET_SCALE: f32 = 0.35;
 
 #[derive(Event)]
 pub struct ReaperShootEvent {
+    pub shooter: Entity,
     pub origin: Vec3,
     pub direction: Vec2,
-    pub speed: f32,
+    pub pattern: BulletPattern,
 }
 
 /// Marker on the UI root node for the on-screen warning banner.
@@ -111,6 +119,7 @@ fn spawn_reaper(commands: &mut Commands, at: Vec3, res: &ReaperRes) {
         Collider { half_extents: Vec2::splat(TILE_SIZE * 0.5) },
         Collidable,
         crate::fluiddynamics::PulledByFluid { mass: 20.0 },
+        StatusResist::unstoppable(),
         GameEntity,
     )).with_children(|parent| spawn_health_bar_children(parent));
 }
@@ -153,12 +162,12 @@ fn reaper_room_timer(
     lvlstate: Res<LevelState>,
     rooms: Res<RoomVec>,
     mut commands: Commands,
-    player_q: Query<&Transform, With<Player>>,
+    player_q: Query<(&Transform, &StatSheet), With<Player>>,
     reaper_res: Res<ReaperRes>,
     assets: Res<AssetServer>,
 ) {
     let current_idx_opt = match *lvlstate {
-        LevelState::InRoom(idx, _, _) => Some(idx),
+        LevelState::InRoom(idx, _) => Some(idx),
         _ => None,
     };
 
@@ -167,15 +176,18 @@ fn reaper_room_timer(
             if state.current_room != Some(idx) {
                 state.current_room = Some(idx);
                 state.spawned_in_room = None;
-                state.timer.reset();
+                // Cursed upgrades can bring the reaper in sooner.
+                let mult = player_q.single().map_or(1.0, |(_, s)| s.get(Stat::ReaperDelay));
+                state.timer = Timer::from_seconds(REAPER_DELAY_SECS * mult, TimerMode::Once);
             }
-            if state.spawned_in_room == Some(idx) {
+            // The boss fight is pressure enough — no reaper in the boss room.
+            if state.spawned_in_room == Some(idx) || rooms.0[idx].is_boss_room {
                 return;
             }
 
             state.timer.tick(time.delta());
             if state.timer.finished() {
-                if let Ok(player_tf) = player_q.single() {
+                if let Ok((player_tf, _)) = player_q.single() {
                     let p = player_tf.translation;
                     let spawn_pos = p + Vec3::new(120.0, 0.0, Z_ENTITIES);
                     // Mark whether this is the last uncleared room so cleanup
@@ -214,8 +226,9 @@ fn reaper_warning_lifecycle(
 fn bullet_hits_reaper(
     mut commands: Commands,
     bullet_query: Query<(&Transform, Entity, &BulletOwner), With<Bullet>>,
-    mut reaper_query: Query<(&Transform, &mut Health), With<Reaper>>,
+    reaper_query: Query<(Entity, &Transform), With<Reaper>>,
     state: Res<ReaperState>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
     if !state.spawned_in_final_room {
         return;
@@ -229,13 +242,18 @@ fn bullet_hits_reaper(
             continue;
         }
         let bullet_pos = bullet_tf.translation;
-        for (reaper_tf, mut health) in &mut reaper_query {
+        for (reaper, reaper_tf) in &reaper_query {
             let reaper_pos = reaper_tf.translation;
             if crate::bullet::aabb_overlap(
                 bullet_pos.x, bullet_pos.y, bullet_half,
                 reaper_pos.x, reaper_pos.y, reaper_half,
             ) {
-                health.0 -= 25.0;
+                damage_events.write(DamageEvent {
+                    source: DamageSource::Player,
+                    target: reaper,
+                    amount: 25.0,
+                    kind: DamageKind::Bullet,
+                });
                 if let Ok(mut entity) = commands.get_entity(bullet_entity) {
                     entity.despawn();
                 }
@@ -245,12 +263,13 @@ fn bullet_hits_reaper(
 }
 
 fn table_hits_reaper(
-    mut reaper_query: Query<(&Transform, &mut Health), With<Reaper>>,
+    reaper_query: Query<(Entity, &Transform), With<Reaper>>,
     table_query: Query<
         (&Transform, &Collider, Option<&crate::enemies::Velocity>),
         With<table::Table>,
     >,
     state: Res<ReaperState>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
     if !state.spawned_in_final_room {
         return;
@@ -258,7 +277,7 @@ fn table_hits_reaper(
 
     let reaper_half = Vec2::splat(TILE_SIZE * 0.5);
 
-    for (reaper_tf, mut health) in &mut reaper_query {
+    for (reaper, reaper_tf) in &reaper_query {
         let reaper_pos = reaper_tf.translation.truncate();
         for (table_tf, table_col, vel_opt) in &table_query {
             let table_pos = table_tf.translation.truncate();
@@ -269,7 +288,12 @@ fn table_hits_reaper(
             ) {
                 let speed = vel_opt.map(|v| v.velocity.length()).unwrap_or(0.0);
                 if speed > 5.0 {
-                    health.0 -= speed * 0.02;
+                    damage_events.write(DamageEvent {
+                        source: DamageSource::Station,
+                        target: reaper,
+                        amount: speed * 0.02,
+                        kind: DamageKind::Impact,
+                    });
                 }
             }
         }
@@ -283,7 +307,7 @@ fn reaper_cleanup_system(
     mut state: ResMut<ReaperState>,
     reaper_q: Query<(Entity, &Health), With<Reaper>>,
 ) {
-    let current_idx = if let LevelState::InRoom(idx, _, _) = *lvlstate {
+    let current_idx = if let LevelState::InRoom(idx, _) = *lvlstate {
         Some(idx)
     } else {
         None
@@ -325,13 +349,13 @@ fn reaper_cleanup_system(
 fn reaper_ai(
     time: Res<Time>,
     player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
-    mut reapers: Query<(&Transform, &mut Velocity, &mut RangedEnemyAI), With<Reaper>>,
+    mut reapers: Query<(Entity, &Transform, &mut Velocity, &mut RangedEnemyAI), With<Reaper>>,
     mut shoot_writer: EventWriter<ReaperShootEvent>,
 ) {
     let Ok(player_tf) = player_query.single() else { return };
     let player_pos = player_tf.translation.truncate();
 
-    for (tf, mut vel, mut ai) in &mut reapers {
+    for (shooter, tf, mut vel, mut ai) in &mut reapers {
         ai.fire_cooldown.tick(time.delta());
 
         let pos = tf.translation.truncate();
@@ -349,9 +373,10 @@ fn reaper_ai(
 
         if ai.fire_cooldown.finished() && dist <= ai.range {
             shoot_writer.write(ReaperShootEvent {
+                shooter,
                 origin: tf.translation,
                 direction: dir,
-                speed: ai.projectile_speed,
+                pattern: BulletPattern::single().speed(ai.projectile_speed),
             });
             ai.fire_cooldown.reset();
         }
@@ -361,33 +386,24 @@ fn reaper_ai(
 fn spawn_reaper_bullets(
     mut commands: Commands,
     mut events: EventReader<ReaperShootEvent>,
+    player_q: Query<(&Transform, &crate::bullet::Velocity), With<Player>>,
     bullet_res: Res<EnemyBulletRes>,
     weapon_sounds: Res<WeaponSounds>,
 ) {
+    let target = player_target(&player_q);
     for ev in events.read() {
-        let dir = ev.direction.normalize_or_zero();
-        if dir == Vec2::ZERO { continue; }
-        let spawn_pos = ev.origin.truncate() + dir * 16.0;
-
-        commands.spawn((
-            Sprite::from_atlas_image(
-                bullet_res.0.clone(),
-                TextureAtlas { layout: bullet_res.1.clone(), index: 0 },
-            ),
-            Transform {
-                translation: Vec3::new(spawn_pos.x, spawn_pos.y, 5.0),
-                scale: Vec3::splat(REAPER_BULLET_SCALE),
-                ..Default::default()
-            },
-            crate::bullet::Velocity(dir * ev.speed),
-            Bullet,
-            BulletOwner::Enemy,
-            Collider { half_extents: Vec2::splat(5.0) },
-            BulletDamage(REAPER_BULLET_DAMAGE),
-            AnimationTimer(Timer::from_seconds(0.15, TimerMode::Repeating)),
-            AnimationFrameCount(3),
-            GameEntity,
-        ));
+        let style = BulletStyle {
+            damage: REAPER_BULLET_DAMAGE,
+            scale: REAPER_BULLET_SCALE,
+            muzzle: 16.0,
+            anim_secs: 0.15,
+            bounces: 0,
+        };
+        let fired = fire_pattern(
+            &mut commands, &bullet_res, ev.shooter, ev.origin.truncate(), ev.direction,
+            &ev.pattern, &style, target,
+        );
+        if !fired { continue; }
 
         commands.spawn((
             AudioPlayer::new(weapon_sounds.laser.clone()),
@@ -3,10 +3,9 @@ use std::time::Duration;
 use crate::GameEntity;
 use crate::fluiddynamics::PulledByFluid;
 use crate::player::Player;
-use crate::room::LevelState;
-use crate::collidable::Collider;
-use crate::bullet::{Bullet, BulletOwner, AnimationTimer, AnimationFrameCount};
-use crate::weapons::{BulletDamage, EnemyBulletRes, WeaponSounds};
+use crate::weapons::{EnemyBulletRes, WeaponSounds};
+use crate::status::StatusEffects;
+use super::patterns::{BulletPattern, BulletStyle, fire_pattern};
 use super::{Enemy, Velocity, ActiveEnemy, Health, MaxHealth, ENEMY_ACCEL, ENEMY_SPEED, ANIM_TIME, spawn_health_bar_children};
 
 // ── Components ─────────────────────────────────────────────────────────────
@@ -41,10 +40,11 @@ pub struct TurretRes {
 
 #[derive(Event)]
 pub struct TurretShootEvent {
+    pub shooter: Entity,
     pub origin: Vec3,
-    pub speed: f32,
     /// Current animation frame index (0-7). Odd = 45-degree rotated frame.
     pub frame_index: usize,
+    pub pattern: BulletPattern,
 }
 
 // ── Asset loading ──────────────────────────────────────────────────────────
@@ -75,7 +75,7 @@ pub fn spawn_at(
     active: bool,
     health_multiplier: f32,
     speed_bonus: f32,
-) {
+) -> Entity {
     let hp = 60.0 * health_multiplier;
     let mut e = commands.spawn((
         Sprite::from_image(res.frames[0].clone()),
@@ -101,6 +101,7 @@ pub fn spawn_at(
     if active {
         e.insert(ActiveEnemy);
     }
+    e.id()
 }
 
 pub use spawn_at as spawn_turret_enemy_at;
@@ -128,29 +129,33 @@ pub fn ai(
     player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
     mut enemies: Query<
         (
+            Entity,
             &Transform,
             &mut Velocity,
             &mut TurretAI,
             &TurretFrames,
             Option<&super::EnemyMoveSpeed>,
             Option<&super::EnemyPathfinder>,
+            Option<&StatusEffects>,
         ),
         With<TurretEnemy>,
     >,
     mut shoot_writer: EventWriter<TurretShootEvent>,
-    lvlstate: Res<LevelState>,
+    director: Res<crate::director::Director>,
+    station_level: Res<crate::StationLevel>,
+    cleared_q: Query<&crate::player::NumOfCleared, With<Player>>,
 ) {
     let Ok(player_tf) = player_query.single() else { return };
     let player_pos = player_tf.translation.truncate();
 
-    let difficulty_mult: f32 = match *lvlstate {
-        LevelState::InRoom(idx, _, _) | LevelState::EnteredRoom(idx) => 1.0 + (idx as f32 * 0.10),
-        LevelState::NotRoom => 1.0,
-    };
+    let rooms_cleared = cleared_q.single().map(|c| c.0).unwrap_or(0);
+    let difficulty_mult = director.fire_rate_mult(rooms_cleared, station_level.0);
 
-    for (enemy_tf, mut vel, mut ai, frames, spd_opt, pathfinder_opt) in &mut enemies {
+    for (shooter, enemy_tf, mut vel, mut ai, frames, spd_opt, pathfinder_opt, statuses) in &mut enemies {
         let max_speed = spd_opt.map_or(TURRET_SPEED, |s| s.0);
-        let scaled_dt = time.delta_secs() * difficulty_mult;
+        // Stunned or frozen turrets hold their fire.
+        let stunned = statuses.is_some_and(StatusEffects::incapacitated);
+        let scaled_dt = if stunned { 0.0 } else { time.delta_secs() * difficulty_mult };
         ai.fire_cooldown.tick(Duration::from_secs_f32(scaled_dt));
 
         let enemy_pos = enemy_tf.translation.truncate();
@@ -179,11 +184,12 @@ pub fn ai(
             };
             vel.velocity = (vel.velocity + move_dir * accel).clamp_length_max(max_speed);
 
-            if ai.fire_cooldown.finished() && dist <= ai.range {
+            if !stunned && ai.fire_cooldown.finished() && dist <= ai.range {
                 shoot_writer.write(TurretShootEvent {
+                    shooter,
                     origin: enemy_tf.translation,
-                    speed: ai.projectile_speed,
                     frame_index: frames.index,
+                    pattern: BulletPattern::ring(4).speed(ai.projectile_speed),
                 });
                 ai.fire_cooldown.reset();
             }
@@ -195,6 +201,8 @@ pub fn ai(
 
 const TURRET_BULLET_DAMAGE: f32 = 12.0;
 const TURRET_BULLET_SCALE: f32 = 0.3;
+/// Turret rounds skip off one wall before they're spent.
+const TURRET_BULLET_BOUNCES: u32 = 1;
 
 pub fn spawn_turret_bullets(
     mut commands: Commands,
@@ -204,34 +212,23 @@ pub fn spawn_turret_bullets(
 ) {
     for ev in events.read() {
         // Odd frame indices (files 2,4,6,8) are 45-degree rotated — fire diagonally.
-        let dirs: [Vec2; 4] = if ev.frame_index % 2 == 1 {
-            let d = std::f32::consts::FRAC_1_SQRT_2;
-            [Vec2::new(d, d), Vec2::new(-d, d), Vec2::new(d, -d), Vec2::new(-d, -d)]
+        let base = if ev.frame_index % 2 == 1 {
+            Vec2::from_angle(std::f32::consts::FRAC_PI_4)
         } else {
-            [Vec2::Y, Vec2::NEG_Y, Vec2::X, Vec2::NEG_X]
+            Vec2::Y
         };
-        for dir in dirs {
-            let spawn_pos = ev.origin.truncate() + dir * 16.0;
-            commands.spawn((
-                Sprite::from_atlas_image(
-                    bullet_res.0.clone(),
-                    TextureAtlas { layout: bullet_res.1.clone(), index: 0 },
-                ),
-                Transform {
-                    translation: Vec3::new(spawn_pos.x, spawn_pos.y, 5.0),
-                    scale: Vec3::splat(TURRET_BULLET_SCALE),
-                    ..Default::default()
-                },
-                crate::bullet::Velocity(dir * ev.speed),
-                Bullet,
-                BulletOwner::Enemy,
-                Collider { half_extents: Vec2::splat(5.0) },
-                BulletDamage(TURRET_BULLET_DAMAGE),
-                AnimationTimer(Timer::from_seconds(0.2, TimerMode::Repeating)),
-                AnimationFrameCount(3),
-                GameEntity,
-            ));
-        }
+        let style = BulletStyle {
+            damage: TURRET_BULLET_DAMAGE,
+            scale: TURRET_BULLET_SCALE,
+            muzzle: 16.0,
+            anim_secs: 0.2,
+            bounces: TURRET_BULLET_BOUNCES,
+        };
+        let fired = fire_pattern(
+            &mut commands, &bullet_res, ev.shooter, ev.origin.truncate(), base,
+            &ev.pattern, &style, None,
+        );
+        if !fired { continue; }
 
         commands.spawn((
             AudioPlayer::new(weapon_sounds.laser.clone()),
@@ -0,0 +1,186 @@
+use bevy::prelude::*;
+
+use crate::enemies::{Enemy, Velocity};
+use crate::fluiddynamics::PulledByFluid;
+use crate::damage::{DamageEvent, DamageKind, DamageSource};
+use crate::player::{Player, Shield};
+use crate::status::{ApplyStatusEvent, StatusKind};
+use crate::{GameEntity, GameState, table, window};
+
+/// A blast at `pos`. Damage and knockback fall off to half at the edge of
+/// `radius`. Player-owned blasts never hurt the player — they only push them,
+/// which is what makes rocket jumping work. Enemy blasts (`hurts_player`)
+/// shove other enemies but don't damage them.
+#[derive(Event, Clone, Copy)]
+pub struct ExplosionEvent {
+    pub pos: Vec2,
+    pub radius: f32,
+    pub damage: f32,
+    pub knockback: f32,
+    pub hurts_player: bool,
+}
+
+/// Set on the player by an explosion. While it runs, `move_player` lets the
+/// player keep speed above the normal cap instead of clamping it away.
+#[derive(Component)]
+pub struct RocketBoost(pub Timer);
+
+#[derive(Component)]
+struct ExplosionFlash {
+    timer: Timer,
+    radius: f32,
+}
+
+const BOOST_SECS: f32 = 0.6;
+const BURN_SECS: f32 = 2.0;
+/// Knockback is divided by mass relative to this, so heavy things barely move.
+const REFERENCE_MASS: f32 = 10.0;
+
+pub struct ExplosionPlugin;
+
+impl Plugin for ExplosionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ExplosionEvent>()
+            .add_systems(
+                Update,
+                (apply_explosions, animate_explosion_flashes, tick_rocket_boost)
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn falloff(dist: f32, radius: f32) -> f32 {
+    1.0 - 0.5 * (dist / radius).clamp(0.0, 1.0)
+}
+
+fn apply_explosions(
+    mut commands: Commands,
+    mut events: EventReader<ExplosionEvent>,
+    mut enemies: Query<
+        (Entity, &Transform, &mut Velocity, Option<&PulledByFluid>),
+        (With<Enemy>, Without<table::Table>),
+    >,
+    mut tables: Query<
+        (&Transform, &mut table::Health, &mut Velocity, Option<&PulledByFluid>),
+        (With<table::Table>, Without<Enemy>),
+    >,
+    mut windows: Query<(&Transform, &mut window::Health, &window::GlassState), With<window::Window>>,
+    mut player_q: Query<
+        (Entity, &Transform, &mut crate::bullet::Velocity, &Shield),
+        With<Player>,
+    >,
+    mut statuses: EventWriter<ApplyStatusEvent>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    for ev in events.read() {
+        spawn_flash(&mut commands, ev.pos, ev.radius);
+
+        for (entity, tf, mut vel, pull) in &mut enemies {
+            let offset = tf.translation.truncate() - ev.pos;
+            let dist = offset.length();
+            if dist > ev.radius { continue; }
+            let f = falloff(dist, ev.radius);
+            if !ev.hurts_player {
+                damage_events.write(DamageEvent {
+                    source: DamageSource::Player,
+                    target: entity,
+                    amount: ev.damage * f,
+                    kind: DamageKind::Explosion,
+                });
+                ignite(&mut statuses, entity);
+            }
+            let mass = pull.map_or(REFERENCE_MASS, |p| p.mass);
+            vel.velocity += offset.normalize_or(Vec2::X) * ev.knockback * f * (REFERENCE_MASS / mass).min(1.5);
+        }
+
+        for (tf, mut health, mut vel, pull) in &mut tables {
+            let offset = tf.translation.truncate() - ev.pos;
+            let dist = offset.length();
+            if dist > ev.radius { continue; }
+            let f = falloff(dist, ev.radius);
+            health.0 -= ev.damage * f;
+            let mass = pull.map_or(REFERENCE_MASS, |p| p.mass);
+            vel.velocity += offset.normalize_or(Vec2::X) * ev.knockback * f * (REFERENCE_MASS / mass).min(1.5);
+        }
+
+        // Glass can't take a blast at all.
+        for (tf, mut health, state) in &mut windows {
+            if *state != window::GlassState::Intact { continue; }
+            if tf.translation.truncate().distance(ev.pos) <= ev.radius {
+                health.0 = 0.0;
+            }
+        }
+
+        if let Ok((entity, tf, mut vel, shield)) = player_q.single_mut() {
+            let offset = tf.translation.truncate() - ev.pos;
+            let dist = offset.length();
+            if dist <= ev.radius {
+                let f = falloff(dist, ev.radius);
+                if ev.hurts_player {
+                    damage_events.write(DamageEvent {
+                        source: DamageSource::Enemy,
+                        target: entity,
+                        amount: ev.damage * f,
+                        kind: DamageKind::Explosion,
+                    });
+                    // A shield charge soaks the blast, flames included.
+                    if shield.current < 1.0 {
+                        ignite(&mut statuses, entity);
+                    }
+                }
+                // Blasts with no push (e.g. Volatile Rounds) shouldn't grant a boost.
+                if ev.knockback > 0.0 {
+                    vel.0 += offset.normalize_or(Vec2::Y) * ev.knockback * f;
+                    commands.entity(entity).insert(RocketBoost(Timer::from_seconds(BOOST_SECS, TimerMode::Once)));
+                }
+            }
+        }
+    }
+}
+
+/// Anything a blast actually hurts is left burning.
+fn ignite(statuses: &mut EventWriter<ApplyStatusEvent>, target: Entity) {
+    statuses.write(ApplyStatusEvent { target, kind: StatusKind::Burning, stacks: 1, secs: BURN_SECS });
+}
+
+fn spawn_flash(commands: &mut Commands, pos: Vec2, radius: f32) {
+    commands.spawn((
+        Sprite {
+            color: Color::srgba(1.0, 0.55, 0.1, 0.7),
+            custom_size: Some(Vec2::splat(radius)),
+            ..default()
+        },
+        Transform::from_xyz(pos.x, pos.y, 6.0),
+        ExplosionFlash { timer: Timer::from_seconds(0.3, TimerMode::Once), radius },
+        GameEntity,
+    ));
+}
+
+fn animate_explosion_flashes(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut flashes: Query<(Entity, &mut Sprite, &mut ExplosionFlash)>,
+) {
+    for (entity, mut sprite, mut flash) in &mut flashes {
+        flash.timer.tick(time.delta());
+        let t = flash.timer.fraction();
+        sprite.custom_size = Some(Vec2::splat(flash.radius * (1.0 + t)));
+        sprite.color.set_alpha(0.7 * (1.0 - t));
+        if flash.timer.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn tick_rocket_boost(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut boosted: Query<(Entity, &mut RocketBoost)>,
+) {
+    for (entity, mut boost) in &mut boosted {
+        boost.0.tick(time.delta());
+        if boost.0.finished() {
+            commands.entity(entity).remove::<RocketBoost>();
+        }
+    }
+}
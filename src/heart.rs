@@ -1,10 +1,18 @@
 use bevy::prelude::*;
 use crate::player::{MaxHealth, Player};
+use crate::weapons::WeaponInventory;
 use crate::{TILE_SIZE, GameEntity};
 
 #[derive(Component)]
 pub struct Heart;
 
+/// Spare ammo: refills magazine reserves and tops up the battery.
+#[derive(Component)]
+pub struct AmmoPickup;
+
+const AMMO_MAGS: u32 = 2;
+const AMMO_BATTERY: f32 = 40.0;
+
 #[derive(Resource)]
 pub struct HeartRes {
     pub image: Handle<Image>,
@@ -16,7 +24,7 @@ impl Plugin for HeartPlugin {
     fn build(&self, app: &mut App) {
         app
             .add_systems(Startup, load_heart)
-            .add_systems(Update, collect_heart.run_if(in_state(crate::GameState::Playing)));
+            .add_systems(Update, (collect_heart, collect_ammo).run_if(in_state(crate::GameState::Playing)));
     }
 }
 
@@ -42,6 +50,19 @@ pub fn spawn_heart(
     ));
 }
 
+pub fn spawn_ammo(commands: &mut Commands, position: Vec2) {
+    commands.spawn((
+        Sprite {
+            color: Color::srgb(1.0, 0.85, 0.3),
+            custom_size: Some(Vec2::new(14.0, 10.0)),
+            ..default()
+        },
+        Transform::from_xyz(position.x, position.y, crate::Z_ENTITIES),
+        AmmoPickup,
+        GameEntity,
+    ));
+}
+
 fn collect_heart(
     mut commands: Commands,
     mut player_query: Query<(&Transform, &mut crate::player::Health, &MaxHealth), With<Player>>,
@@ -64,4 +85,29 @@ fn collect_heart(
             debug!("Heart collected! Health: {}", health.0);
         }
     }
-}
\ No newline at end of file
+}
+
+fn collect_ammo(
+    mut commands: Commands,
+    mut player_query: Query<(&Transform, &mut WeaponInventory), With<Player>>,
+    ammo_query: Query<(Entity, &Transform), With<AmmoPickup>>,
+) {
+    let Ok((player_tf, mut inventory)) = player_query.single_mut() else {
+        return;
+    };
+
+    let player_pos = player_tf.translation.truncate();
+    let collect_radius = TILE_SIZE * 1.5;
+
+    for (ammo_entity, ammo_tf) in &ammo_query {
+        if player_pos.distance(ammo_tf.translation.truncate()) >= collect_radius {
+            continue;
+        }
+        for weapon in &mut inventory.weapons {
+            weapon.ammo.refill(AMMO_MAGS);
+        }
+        let battery = &mut inventory.battery;
+        battery.current = (battery.current + AMMO_BATTERY).min(battery.max);
+        commands.entity(ammo_entity).despawn();
+    }
+}
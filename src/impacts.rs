@@ -0,0 +1,178 @@
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+use crate::map::{WallGrid, WallTile};
+use crate::{GameEntity, GameState, Z_FLOOR};
+
+/// A bullet struck (or bounced off) a wall tile. `chip` is the damage it
+/// deals to destructible walls — zero for enemy shots.
+#[derive(Event, Clone, Copy)]
+pub struct WallImpactEvent {
+    pub pos: Vec2,
+    pub normal: Vec2,
+    pub chip: f32,
+}
+
+/// Interior partitions with floor on both sides. Enough hits knock them out.
+#[derive(Component)]
+pub struct DestructibleWall {
+    pub health: f32,
+}
+
+pub const DESTRUCTIBLE_WALL_HEALTH: f32 = 250.0;
+
+#[derive(Component)]
+struct Debris {
+    velocity: Vec2,
+    timer: Timer,
+}
+
+#[derive(Component)]
+struct Scorch(Timer);
+
+/// Oldest-first list of live scorch marks so the count stays bounded.
+#[derive(Resource, Default)]
+struct ScorchMarks(VecDeque<Entity>);
+
+const MAX_SCORCHES: usize = 120;
+const SCORCH_SECS: f32 = 8.0;
+const DEBRIS_PER_IMPACT: usize = 4;
+const DEBRIS_PER_BREAK: usize = 14;
+const DEBRIS_SECS: f32 = 0.45;
+const DEBRIS_DRAG: f32 = 6.0;
+
+pub struct ImpactPlugin;
+
+impl Plugin for ImpactPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<WallImpactEvent>()
+            .init_resource::<ScorchMarks>()
+            .add_systems(OnExit(GameState::Playing), clear_scorch_marks)
+            .add_systems(
+                Update,
+                (
+                    (spawn_impact_effects, chip_walls).after(crate::bullet::bullet_collision),
+                    update_debris,
+                    fade_scorches,
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn clear_scorch_marks(mut marks: ResMut<ScorchMarks>) {
+    marks.0.clear();
+}
+
+fn spawn_debris(commands: &mut Commands, pos: Vec2, normal: Vec2, count: usize, color: Color) {
+    for _ in 0..count {
+        let dir = if normal == Vec2::ZERO {
+            Vec2::from_angle(rand::random_range(0.0..std::f32::consts::TAU))
+        } else {
+            Vec2::from_angle(rand::random_range(-1.0..1.0)).rotate(normal)
+        };
+        let size = rand::random_range(2.0..4.5);
+        commands.spawn((
+            Sprite { color, custom_size: Some(Vec2::splat(size)), ..default() },
+            Transform::from_xyz(pos.x, pos.y, 906.0),
+            Debris {
+                velocity: dir * rand::random_range(90.0..220.0),
+                timer: Timer::from_seconds(DEBRIS_SECS, TimerMode::Once),
+            },
+            GameEntity,
+        ));
+    }
+}
+
+fn spawn_impact_effects(
+    mut commands: Commands,
+    mut impacts: EventReader<WallImpactEvent>,
+    mut marks: ResMut<ScorchMarks>,
+) {
+    for impact in impacts.read() {
+        spawn_debris(&mut commands, impact.pos, impact.normal, DEBRIS_PER_IMPACT, Color::srgb(0.55, 0.55, 0.6));
+
+        let scorch = commands.spawn((
+            Sprite {
+                color: Color::srgba(0.05, 0.04, 0.03, 0.55),
+                custom_size: Some(Vec2::splat(rand::random_range(6.0..10.0))),
+                ..default()
+            },
+            Transform::from_xyz(impact.pos.x, impact.pos.y, Z_FLOOR + 3.0)
+                .with_rotation(Quat::from_rotation_z(rand::random_range(0.0..std::f32::consts::TAU))),
+            Scorch(Timer::from_seconds(SCORCH_SECS, TimerMode::Once)),
+            GameEntity,
+        )).id();
+        marks.0.push_back(scorch);
+        if marks.0.len() > MAX_SCORCHES
+            && let Some(old) = marks.0.pop_front()
+        {
+            commands.entity(old).try_despawn();
+        }
+    }
+}
+
+/// Player shots wear down destructible walls; at zero health the tile is
+/// removed from the `WallGrid` and bursts into rubble.
+fn chip_walls(
+    mut commands: Commands,
+    mut impacts: EventReader<WallImpactEvent>,
+    mut wall_grid: ResMut<WallGrid>,
+    mut walls: Query<(Entity, &Transform, &mut Sprite, &mut DestructibleWall), With<WallTile>>,
+) {
+    for impact in impacts.read() {
+        if impact.chip <= 0.0 { continue; }
+        // The contact point sits on the face; step back along the normal to land inside the tile.
+        let inside = impact.pos - impact.normal * wall_grid.cell_size * 0.25;
+        let tile = wall_grid.world_to_tile(inside);
+
+        for (entity, tf, mut sprite, mut wall) in &mut walls {
+            if wall_grid.world_to_tile(tf.translation.truncate()) != tile { continue; }
+            wall.health -= impact.chip;
+            let wear = (wall.health / DESTRUCTIBLE_WALL_HEALTH).clamp(0.0, 1.0);
+            sprite.color = Color::srgb(0.5 + 0.5 * wear, 0.5 + 0.5 * wear, 0.5 + 0.5 * wear);
+            if wall.health <= 0.0 {
+                let pos = tf.translation.truncate();
+                wall_grid.remove(pos);
+                spawn_debris(&mut commands, pos, Vec2::ZERO, DEBRIS_PER_BREAK, Color::srgb(0.45, 0.45, 0.5));
+                commands.entity(entity).despawn();
+            }
+            break;
+        }
+    }
+}
+
+fn update_debris(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut debris: Query<(Entity, &mut Transform, &mut Sprite, &mut Debris)>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut tf, mut sprite, mut d) in &mut debris {
+        d.timer.tick(time.delta());
+        tf.translation += (d.velocity * dt).extend(0.0);
+        d.velocity *= (1.0 - DEBRIS_DRAG * dt).max(0.0);
+        sprite.color.set_alpha(1.0 - d.timer.fraction());
+        if d.timer.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn fade_scorches(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut marks: ResMut<ScorchMarks>,
+    mut scorches: Query<(Entity, &mut Sprite, &mut Scorch)>,
+) {
+    for (entity, mut sprite, mut scorch) in &mut scorches {
+        scorch.0.tick(time.delta());
+        // Hold, then fade over the last quarter.
+        let fade = ((1.0 - scorch.0.fraction()) * 4.0).min(1.0);
+        sprite.color.set_alpha(0.55 * fade);
+        if scorch.0.finished() {
+            commands.entity(entity).despawn();
+            marks.0.retain(|&e| e != entity);
+        }
+    }
+}
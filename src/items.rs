@@ -0,0 +1,502 @@
+use bevy::prelude::*;
+use rand::seq::IndexedRandom;
+
+use crate::enemies::{ActiveEnemy, Enemy};
+use crate::explosion::ExplosionEvent;
+use crate::player::{AirTank, Health, Player};
+use crate::rewards::{RewardFont, spawn_popup};
+use crate::bullet::{AnimationFrameCount, AnimationTimer, Bullet, BulletOwner, HitEnemies, Velocity};
+use crate::collidable::Collider;
+use crate::stats::{Stat, StatSheet};
+use crate::weapons::BulletDamage;
+use crate::window::{self, GlassState, Window};
+use crate::{GameEntity, GameState, TILE_SIZE, Z_ENTITIES};
+
+/// How many different items the player can carry at once.
+pub const HOTBAR_SLOTS: usize = 5;
+const HOTBAR_KEYS: [KeyCode; HOTBAR_SLOTS] =
+    [KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4, KeyCode::Digit5];
+
+const MED_KIT_HEAL: f32 = 35.0;
+/// A hull patch seals a broken window within this distance.
+const PATCH_RANGE: f32 = TILE_SIZE * 2.0;
+
+/// Mines wait this long before they can go off, so dropping one at your
+/// feet mid-fight doesn't blow it straight away.
+const MINE_ARM_SECS: f32 = 1.0;
+const MINE_TRIGGER_RADIUS: f32 = TILE_SIZE * 1.5;
+const MINE_BLAST_RADIUS: f32 = 120.0;
+const MINE_DAMAGE: f32 = 70.0;
+const MINE_KNOCKBACK: f32 = 800.0;
+
+const TURRET_SECS: f32 = 12.0;
+const TURRET_RANGE: f32 = 380.0;
+const TURRET_DAMAGE: f32 = 12.0;
+const TURRET_FIRE_SECS: f32 = 0.35;
+const TURRET_BOLT_SPEED: f32 = 700.0;
+
+const PICKUP_RADIUS: f32 = TILE_SIZE * 0.8;
+
+/// Everything that can sit on the hotbar.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ItemKind {
+    MedKit,
+    O2Canister,
+    HullPatch,
+    ProximityMine,
+    Turret,
+}
+
+impl ItemKind {
+    pub const ALL: [ItemKind; 5] = [
+        ItemKind::MedKit,
+        ItemKind::O2Canister,
+        ItemKind::HullPatch,
+        ItemKind::ProximityMine,
+        ItemKind::Turret,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ItemKind::MedKit => "Med-kit",
+            ItemKind::O2Canister => "O2 Canister",
+            ItemKind::HullPatch => "Hull Patch",
+            ItemKind::ProximityMine => "Proximity Mine",
+            ItemKind::Turret => "Sentry Turret",
+        }
+    }
+
+    /// How many fit in one hotbar slot.
+    pub fn max_stack(self) -> u32 {
+        match self {
+            ItemKind::MedKit | ItemKind::O2Canister | ItemKind::HullPatch => 3,
+            ItemKind::ProximityMine => 5,
+            ItemKind::Turret => 1,
+        }
+    }
+
+    /// What the vendor asks before station scaling.
+    pub fn base_price(self) -> f32 {
+        match self {
+            ItemKind::MedKit => 25.0,
+            ItemKind::O2Canister => 20.0,
+            ItemKind::HullPatch => 25.0,
+            ItemKind::ProximityMine => 30.0,
+            ItemKind::Turret => 50.0,
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            ItemKind::MedKit => Color::srgb(0.95, 0.3, 0.35),
+            ItemKind::O2Canister => Color::srgb(0.3, 0.85, 1.0),
+            ItemKind::HullPatch => Color::srgb(0.75, 0.85, 0.95),
+            ItemKind::ProximityMine => Color::srgb(1.0, 0.75, 0.2),
+            ItemKind::Turret => Color::srgb(0.55, 0.9, 0.45),
+        }
+    }
+
+    pub fn random() -> Self {
+        *Self::ALL.choose(&mut rand::rng()).unwrap()
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ItemStack {
+    pub kind: ItemKind,
+    pub count: u32,
+}
+
+/// The player's hotbar. Each slot holds a stack of one kind; 1-5 uses
+/// whatever is in that slot. Kept between stations.
+#[derive(Component, Clone, Default)]
+pub struct Consumables {
+    pub slots: [Option<ItemStack>; HOTBAR_SLOTS],
+}
+
+impl Consumables {
+    /// Tops up the stack of `kind` if there is one with room, otherwise
+    /// takes the first empty slot. False when there's nowhere to put it.
+    pub fn add(&mut self, kind: ItemKind) -> bool {
+        if let Some(stack) = self.slots.iter_mut().flatten()
+            .find(|s| s.kind == kind && s.count < kind.max_stack())
+        {
+            stack.count += 1;
+            return true;
+        }
+        match self.slots.iter_mut().find(|s| s.is_none()) {
+            Some(slot) => {
+                *slot = Some(ItemStack { kind, count: 1 });
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Uses up one item from `slot`, clearing it when the stack runs out.
+    fn spend(&mut self, slot: usize) {
+        let Some(stack) = self.slots[slot].as_mut() else { return; };
+        stack.count -= 1;
+        if stack.count == 0 {
+            self.slots[slot] = None;
+        }
+    }
+}
+
+/// An item lying on the floor. Walking over it puts it on the hotbar.
+#[derive(Component)]
+pub struct ItemPickup(pub ItemKind);
+
+/// A placed proximity mine.
+#[derive(Component)]
+struct ProximityMine {
+    arm: Timer,
+}
+
+/// A placed sentry. Shoots zapper bolts at the nearest active enemy until
+/// its timer runs out.
+#[derive(Component)]
+struct Sentry {
+    fire: Timer,
+    life: Timer,
+}
+
+/// A bolt fired by a sentry rather than the player's weapon, so weapon mods
+/// and crew passives that key off the player's shots leave it alone.
+#[derive(Component)]
+pub struct SentryShot;
+
+#[derive(Resource)]
+struct SentryRes {
+    bolt: Handle<Image>,
+    layout: Handle<TextureAtlasLayout>,
+}
+
+#[derive(Component)]
+struct HotbarSwatch(usize);
+
+#[derive(Component)]
+struct HotbarCount(usize);
+
+pub struct ItemsPlugin;
+
+impl Plugin for ItemsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, load_sentry_assets)
+            .add_systems(OnEnter(GameState::Playing), spawn_hotbar)
+            .add_systems(
+                Update,
+                (
+                    use_hotbar_item
+                        .before(crate::rewards::choice::handle_upgrade_choice)
+                        .run_if(not(resource_exists::<crate::pause::IsPaused>)),
+                    collect_item_pickups,
+                    trigger_mines,
+                    run_sentries.run_if(not(resource_exists::<crate::pause::IsPaused>)),
+                    update_hotbar,
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn load_sentry_assets(
+    mut commands: Commands,
+    assets: Res<AssetServer>,
+    mut atlases: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    commands.insert_resource(SentryRes {
+        bolt: assets.load("bullet_animation.png"),
+        layout: atlases.add(TextureAtlasLayout::from_grid(UVec2::splat(100), 3, 1, None, None)),
+    });
+}
+
+/// Drops `kind` on the floor at `pos`. Chests call this.
+pub fn spawn_item_pickup(commands: &mut Commands, pos: Vec2, kind: ItemKind) {
+    commands.spawn((
+        Sprite::from_color(kind.color(), Vec2::splat(TILE_SIZE * 0.45)),
+        Transform::from_translation(pos.extend(Z_ENTITIES))
+            .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+        ItemPickup(kind),
+        GameEntity,
+    ));
+}
+
+/// 1-5 uses the item in that slot. An item that can't do anything right now
+/// (a hull patch with no breach in reach, a med-kit at full health) is kept.
+fn use_hotbar_item(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    font: Res<RewardFont>,
+    mut player_q: Query<(&Transform, &mut Consumables, &mut Health, &mut AirTank, &StatSheet), With<Player>>,
+    mut windows: Query<(&Transform, &mut crate::window::Health, &GlassState), With<Window>>,
+) {
+    let Some(slot) = HOTBAR_KEYS.iter().position(|k| input.just_pressed(*k)) else { return; };
+    let Ok((player_tf, mut items, mut health, mut tank, sheet)) = player_q.single_mut() else { return; };
+    let Some(stack) = items.slots[slot] else { return; };
+    let player_pos = player_tf.translation.truncate();
+
+    let used = match stack.kind {
+        ItemKind::MedKit => {
+            let max = sheet.get(Stat::MaxHealth);
+            let full = health.0 >= max;
+            if !full {
+                health.0 = (health.0 + MED_KIT_HEAL).min(max);
+            }
+            !full
+        }
+        ItemKind::O2Canister => {
+            let full = tank.current >= tank.max_capacity;
+            tank.current = tank.max_capacity;
+            !full
+        }
+        ItemKind::HullPatch => {
+            let nearest = windows
+                .iter_mut()
+                .filter(|(_, _, state)| **state == GlassState::Broken)
+                .map(|(tf, health, _)| (tf.translation.truncate().distance(player_pos), health))
+                .filter(|(d, _)| *d < PATCH_RANGE)
+                .min_by(|a, b| a.0.total_cmp(&b.0));
+            match nearest {
+                Some((_, mut health)) => {
+                    health.0 = window::MAX_HEALTH;
+                    true
+                }
+                None => {
+                    spawn_popup(&mut commands, &font, player_pos, "No breach nearby");
+                    false
+                }
+            }
+        }
+        ItemKind::ProximityMine => {
+            commands.spawn((
+                Sprite::from_color(ItemKind::ProximityMine.color(), Vec2::splat(TILE_SIZE * 0.35)),
+                Transform::from_translation(player_pos.extend(Z_ENTITIES - 1.0)),
+                ProximityMine { arm: Timer::from_seconds(MINE_ARM_SECS, TimerMode::Once) },
+                GameEntity,
+            ));
+            true
+        }
+        ItemKind::Turret => {
+            commands.spawn((
+                Sprite::from_color(ItemKind::Turret.color(), Vec2::splat(TILE_SIZE * 0.6)),
+                Transform::from_translation(player_pos.extend(Z_ENTITIES)),
+                Sentry {
+                    fire: Timer::from_seconds(TURRET_FIRE_SECS, TimerMode::Once),
+                    life: Timer::from_seconds(TURRET_SECS, TimerMode::Once),
+                },
+                GameEntity,
+            ));
+            true
+        }
+    };
+
+    if used {
+        items.spend(slot);
+        spawn_popup(&mut commands, &font, player_pos, stack.kind.name());
+    }
+}
+
+/// Items on the floor are picked up on contact, if the hotbar has room.
+fn collect_item_pickups(
+    mut commands: Commands,
+    mut player_q: Query<(&Transform, &mut Consumables), With<Player>>,
+    pickups: Query<(Entity, &Transform, &ItemPickup)>,
+) {
+    let Ok((player_tf, mut items)) = player_q.single_mut() else { return; };
+    let player_pos = player_tf.translation.truncate();
+
+    for (entity, tf, pickup) in &pickups {
+        if tf.translation.truncate().distance(player_pos) > PICKUP_RADIUS { continue; }
+        if items.add(pickup.0) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Armed mines blow up as soon as an enemy steps close.
+fn trigger_mines(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut mines: Query<(Entity, &Transform, &mut ProximityMine)>,
+    enemies: Query<&Transform, With<Enemy>>,
+    mut explosions: EventWriter<ExplosionEvent>,
+) {
+    for (entity, tf, mut mine) in &mut mines {
+        mine.arm.tick(time.delta());
+        if !mine.arm.finished() { continue; }
+
+        let pos = tf.translation.truncate();
+        if enemies.iter().any(|e| e.translation.truncate().distance(pos) < MINE_TRIGGER_RADIUS) {
+            explosions.write(ExplosionEvent {
+                pos,
+                radius: MINE_BLAST_RADIUS,
+                damage: MINE_DAMAGE,
+                knockback: MINE_KNOCKBACK,
+                hurts_player: false,
+            });
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn run_sentries(
+    mut commands: Commands,
+    time: Res<Time>,
+    res: Res<SentryRes>,
+    mut sentries: Query<(Entity, &Transform, &mut Sentry)>,
+    enemies: Query<&Transform, (With<Enemy>, With<ActiveEnemy>)>,
+) {
+    for (entity, tf, mut sentry) in &mut sentries {
+        sentry.life.tick(time.delta());
+        if sentry.life.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        sentry.fire.tick(time.delta());
+        if !sentry.fire.finished() { continue; }
+
+        let pos = tf.translation.truncate();
+        let target = enemies
+            .iter()
+            .map(|e| e.translation.truncate())
+            .filter(|e| e.distance(pos) < TURRET_RANGE)
+            .min_by(|a, b| a.distance(pos).total_cmp(&b.distance(pos)));
+        let Some(target) = target else { continue; };
+
+        commands.spawn((
+            Sprite::from_atlas_image(res.bolt.clone(), TextureAtlas { layout: res.layout.clone(), index: 0 }),
+            Transform::from_translation(pos.extend(910.0)).with_scale(Vec3::splat(0.25)),
+            AnimationTimer(Timer::from_seconds(0.2, TimerMode::Repeating)),
+            AnimationFrameCount(3),
+            Velocity((target - pos).normalize_or_zero() * TURRET_BOLT_SPEED),
+            Bullet,
+            BulletOwner::Player,
+            Collider { half_extents: Vec2::splat(5.0) },
+            BulletDamage(TURRET_DAMAGE),
+            HitEnemies::default(),
+            SentryShot,
+            GameEntity,
+        ));
+        sentry.fire.reset();
+    }
+}
+
+/// A row of slots along the bottom of the screen.
+fn spawn_hotbar(mut commands: Commands, font: Res<RewardFont>) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(12.0),
+                width: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(6.0),
+                ..default()
+            },
+            GameEntity,
+        ))
+        .with_children(|bar| {
+            for i in 0..HOTBAR_SLOTS {
+                bar.spawn((
+                    Node {
+                        width: Val::Px(44.0),
+                        height: Val::Px(44.0),
+                        border: UiRect::all(Val::Px(2.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BorderColor(Color::srgba(1.0, 1.0, 1.0, 0.4)),
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+                ))
+                .with_children(|slot| {
+                    slot.spawn((
+                        Node { width: Val::Px(22.0), height: Val::Px(22.0), ..default() },
+                        BackgroundColor(Color::NONE),
+                        HotbarSwatch(i),
+                    ));
+                    slot.spawn((
+                        Text::new(format!("{}", i + 1)),
+                        TextFont { font: font.0.clone(), font_size: 11.0, ..default() },
+                        TextColor(Color::srgba(1.0, 1.0, 1.0, 0.6)),
+                        Node { position_type: PositionType::Absolute, top: Val::Px(1.0), left: Val::Px(3.0), ..default() },
+                    ));
+                    slot.spawn((
+                        Text::new(""),
+                        TextFont { font: font.0.clone(), font_size: 13.0, ..default() },
+                        TextColor(Color::WHITE),
+                        Node { position_type: PositionType::Absolute, bottom: Val::Px(0.0), right: Val::Px(3.0), ..default() },
+                        HotbarCount(i),
+                    ));
+                });
+            }
+        });
+}
+
+fn update_hotbar(
+    player_q: Query<&Consumables, (With<Player>, Changed<Consumables>)>,
+    mut swatches: Query<(&mut BackgroundColor, &HotbarSwatch)>,
+    mut counts: Query<(&mut Text, &HotbarCount)>,
+) {
+    let Ok(items) = player_q.single() else { return; };
+    for (mut color, swatch) in &mut swatches {
+        color.0 = items.slots[swatch.0].map_or(Color::NONE, |s| s.kind.color());
+    }
+    for (mut text, count) in &mut counts {
+        text.0 = match items.slots[count.0] {
+            Some(s) if s.count > 1 => s.count.to_string(),
+            _ => String::new(),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_tops_up_an_existing_stack_first() {
+        let mut items = Consumables::default();
+        assert!(items.add(ItemKind::MedKit));
+        assert!(items.add(ItemKind::O2Canister));
+        assert!(items.add(ItemKind::MedKit));
+        assert_eq!(items.slots[0].map(|s| s.count), Some(2));
+        assert_eq!(items.slots[1].map(|s| s.kind), Some(ItemKind::O2Canister));
+        assert!(items.slots[2].is_none());
+    }
+
+    #[test]
+    fn full_stack_spills_into_a_new_slot() {
+        let mut items = Consumables::default();
+        for _ in 0..ItemKind::Turret.max_stack() + 1 {
+            assert!(items.add(ItemKind::Turret));
+        }
+        assert_eq!(items.slots[0].map(|s| s.count), Some(ItemKind::Turret.max_stack()));
+        assert_eq!(items.slots[1].map(|s| s.count), Some(1));
+    }
+
+    #[test]
+    fn add_fails_when_every_slot_is_full() {
+        let mut items = Consumables::default();
+        for _ in 0..HOTBAR_SLOTS {
+            assert!(items.add(ItemKind::Turret));
+        }
+        assert!(!items.add(ItemKind::Turret));
+        assert!(!items.add(ItemKind::MedKit));
+    }
+
+    #[test]
+    fn spend_clears_the_slot_when_the_stack_runs_out() {
+        let mut items = Consumables::default();
+        items.add(ItemKind::MedKit);
+        items.add(ItemKind::MedKit);
+        items.spend(0);
+        assert_eq!(items.slots[0].map(|s| s.count), Some(1));
+        items.spend(0);
+        assert!(items.slots[0].is_none());
+        // Spending an empty slot does nothing.
+        items.spend(0);
+        assert!(items.slots[0].is_none());
+    }
+}
@@ -1,14 +1,17 @@
 use bevy::prelude::*;
 use rand::random_range;
+use rand::seq::IndexedRandom;
 use crate::collidable::{Collidable, Collider};
+use crate::damage::DeathEvent;
 use crate::{GameEntity, GameState, TILE_SIZE, Z_ENTITIES};
 use crate::player::{Player, aabb_overlap};
 use crate::enemies::Enemy;
-use crate::room::LevelState;
+use crate::room::{LevelState, RoomVec};
+use crate::weapons::mods;
 
 // ─── Components ──────────────────────────────────────────────────────────────
 
-/// Marker added to one random enemy per level; their death spawns the KeyPickup.
+/// Marker added to one random enemy per level; their death drops the KeyPickup.
 #[derive(Component)]
 pub struct KeyHolder;
 
@@ -34,24 +37,35 @@ pub struct KeyChestRes {
 
 #[derive(Resource)]
 pub struct LevelKeyState {
-    /// Index (0..6, non-airlock) of the room whose enemies include the KeyHolder.
+    /// Index of the room whose enemies include the KeyHolder. Never the
+    /// airlock or the shop, which have no enemies.
     pub key_holder_room: usize,
     /// True once `KeyHolder` has been inserted on an enemy entity.
     pub key_assigned: bool,
     /// True once the player has collected the key off the floor.
     pub has_key: bool,
-    /// Index (0..6, non-airlock) of the room that contains the chest.
+    /// Index of the room that contains the chest. Never the key holder's room.
     pub chest_room: usize,
     /// True once the chest entity has been spawned.
     pub chest_spawned: bool,
 }
 
 impl LevelKeyState {
-    fn new() -> Self {
-        let key_room = random_range(0..6usize);
+    fn new(rooms: &RoomVec) -> Self {
+        let combat_rooms: Vec<usize> = rooms.0.iter()
+            .enumerate()
+            .filter(|(_, r)| !r.is_airlock && !r.is_shop)
+            .map(|(i, _)| i)
+            .collect();
         // Ensure chest is always in a different room than the key holder.
-        let r = random_range(0..5usize);
-        let chest_room = if r >= key_room { r + 1 } else { r };
+        let picked: Vec<usize> = combat_rooms.choose_multiple(&mut rand::rng(), 2).copied().collect();
+        let (key_room, chest_room) = match *picked.as_slice() {
+            [a, b] => (a, b),
+            // Only one room to fight in: the key and chest have to share it.
+            [a] => (a, a),
+            // No combat rooms at all, so no room index will ever match.
+            _ => (usize::MAX, usize::MAX),
+        };
         Self {
             key_holder_room: key_room,
             key_assigned: false,
@@ -72,7 +86,7 @@ impl Plugin for KeyChestPlugin {
             .add_systems(Startup, load_assets)
             .add_systems(
                 OnEnter(GameState::Loading),
-                (init_level_key_state, setup_key_hud),
+                (init_level_key_state.after(crate::procgen::ProcgenSet::BuildFullLevel), setup_key_hud),
             )
             .add_systems(
                 Update,
@@ -82,6 +96,9 @@ impl Plugin for KeyChestPlugin {
                     pickup_key,
                     interact_with_chest,
                     update_key_hud,
+                    drop_key_on_death
+                        .after(crate::damage::apply_damage)
+                        .before(crate::enemies::check_enemy_health),
                 )
                     .run_if(in_state(GameState::Playing)),
             );
@@ -97,8 +114,8 @@ fn load_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
     });
 }
 
-fn init_level_key_state(mut commands: Commands) {
-    commands.insert_resource(LevelKeyState::new());
+fn init_level_key_state(mut commands: Commands, rooms: Res<RoomVec>) {
+    commands.insert_resource(LevelKeyState::new(&rooms));
 }
 
 fn setup_key_hud(mut commands: Commands, res: Res<KeyChestRes>) {
@@ -129,7 +146,7 @@ fn assign_key_holder(
     enemy_q: Query<Entity, With<Enemy>>,
 ) {
     if key_state.key_assigned { return; }
-    let LevelState::InRoom(idx, _, _) = *lvl_state else { return };
+    let LevelState::InRoom(idx, _) = *lvl_state else { return };
     if idx != key_state.key_holder_room { return; }
 
     let enemies: Vec<Entity> = enemy_q.iter().collect();
@@ -150,7 +167,7 @@ fn spawn_chest_on_room_entry(
     res: Res<KeyChestRes>,
 ) {
     if key_state.chest_spawned { return; }
-    let LevelState::InRoom(idx, _, chest_pos) = *lvl_state else { return };
+    let LevelState::InRoom(idx, chest_pos) = *lvl_state else { return };
     if idx != key_state.chest_room { return; }
 
     commands.spawn((
@@ -184,7 +201,8 @@ fn pickup_key(
     }
 }
 
-/// Press E near a chest while holding the key to open it.
+/// Press E near a chest while holding the key to open it. Leaves a hotbar
+/// item behind where the chest stood.
 fn interact_with_chest(
     mut commands: Commands,
     input: Res<ButtonInput<KeyCode>>,
@@ -192,6 +210,8 @@ fn interact_with_chest(
     player_q: Query<&Transform, With<Player>>,
     chest_q: Query<(Entity, &Transform), With<Chest>>,
     mut inventory_q: Query<&mut crate::weapons::WeaponInventory, With<Player>>,
+    weapon_registry: Res<crate::weapons::WeaponRegistry>,
+    font: Res<crate::rewards::RewardFont>,
 ) {
     if !input.just_pressed(KeyCode::KeyE) { return; }
     if !key_state.has_key { return; }
@@ -206,9 +226,28 @@ fn interact_with_chest(
         if aabb_overlap(pp.x, pp.y, interact_half, cp.x, cp.y, chest_half) {
             commands.entity(entity).despawn();
             key_state.has_key = false;
-            if let Ok(mut inv) = inventory_q.single_mut() {
-                inv.weapons.push(crate::weapons::Weapon::new(crate::weapons::WeaponType::BeamRifle));
-            }
+            let Ok(mut inv) = inventory_q.single_mut() else { break };
+            // Half the time hand out a weapon the player doesn't carry yet
+            // (when there is one); otherwise a mod for what they're holding.
+            let unowned: Vec<_> = weapon_registry.ids()
+                .filter(|id| !inv.weapons.iter().any(|w| w.id == *id))
+                .collect();
+            let new_weapon = if rand::random::<bool>() { unowned.choose(&mut rand::rng()) } else { None };
+            let label = match new_weapon.and_then(|&id| weapon_registry.create(id)) {
+                Some(weapon) => {
+                    let name = weapon_registry.name(weapon.id);
+                    inv.weapons.push(weapon);
+                    name.to_string()
+                }
+                None => {
+                    let m = *mods::ALL_MODS.choose(&mut rand::rng()).unwrap();
+                    let slot = mods::install_mod(&mut inv, m);
+                    format!("{} mod -> {}", m.name(), weapon_registry.name(inv.weapons[slot].id))
+                }
+            };
+            crate::rewards::spawn_popup(&mut commands, &font, cp.truncate(), label);
+            // Every chest also holds something for the hotbar.
+            crate::items::spawn_item_pickup(&mut commands, cp.truncate(), crate::items::ItemKind::random());
             break;
         }
     }
@@ -223,15 +262,20 @@ fn update_key_hud(
     *vis = if key_state.has_key { Visibility::Visible } else { Visibility::Hidden };
 }
 
-// ─── Public helpers (called from other modules) ───────────────────────────────
-
-/// Spawns a key pickup at the given world position.
-/// Called by `enemies::check_enemy_health` when a `KeyHolder` enemy is killed.
-pub fn drop_key(commands: &mut Commands, res: &KeyChestRes, pos: Vec3) {
-    commands.spawn((
-        Sprite::from_image(res.key_img.clone()),
-        Transform::from_xyz(pos.x, pos.y, Z_ENTITIES),
-        KeyPickup,
-        GameEntity,
-    ));
+/// Drops the key where the `KeyHolder` enemy died.
+fn drop_key_on_death(
+    mut commands: Commands,
+    mut deaths: EventReader<DeathEvent>,
+    holders: Query<(), With<KeyHolder>>,
+    res: Res<KeyChestRes>,
+) {
+    for death in deaths.read() {
+        if holders.get(death.entity).is_err() { continue; }
+        commands.spawn((
+            Sprite::from_image(res.key_img.clone()),
+            Transform::from_xyz(death.pos.x, death.pos.y, Z_ENTITIES),
+            KeyPickup,
+            GameEntity,
+        ));
+    }
 }
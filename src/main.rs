@@ -1,6 +1,7 @@
 use crate::collidable::{Collidable, Collider};
 use crate::player::{Health, Player};
 use bevy::{prelude::*, window::{PresentMode, WindowMode}};
+use bevy::ecs::query::QueryData;
 use bevy::audio::Volume;
 use crate::air::{init_air_grid, spawn_pressure_labels, update_pressure_labels, update_air_on_window_break};
 use crate::room::RoomVec;
@@ -23,11 +24,22 @@ pub mod bullet;
 pub mod broom;
 pub mod rewards;
 pub mod heart;
+pub mod impacts;
 pub mod weapons;
 pub mod minimap;
 pub mod pause;
 pub mod settings;
 pub mod key_chest;
+pub mod director;
+pub mod waves;
+pub mod explosion;
+pub mod aim;
+pub mod shop;
+pub mod stats;
+pub mod status;
+pub mod damage;
+pub mod items;
+pub mod crew;
 
 pub const FONT_PATH: &str = "fonts/BitcountSingleInk-VariableFont_CRSV,ELSH,ELXP,SZP1,SZP2,XPN1,XPN2,YPN1,YPN2,slnt,wght.ttf";
 
@@ -94,7 +106,7 @@ pub enum EndScreenButtons{
 #[derive(Component)]
 pub struct GameEntity;
 
-/// Set when all station rooms are cleared and the reaper is dead.
+/// Set when all station rooms are cleared and the reaper and boss are dead.
 /// The player must physically return to the airlock before the win screen appears.
 #[derive(Resource)]
 pub struct LevelComplete;
@@ -112,24 +124,42 @@ impl Default for StationLevel {
     fn default() -> Self { Self(0) }
 }
 
-/// Saved player buffs carried between stations on "Continue".
-#[derive(Resource, Clone)]
+/// Everything about the player that carries between stations on "Continue".
+/// Every stat, upgrade and curse lives on the stat sheet; the rest is what
+/// the player is holding. Saved as one snapshot of the player's components
+/// and spawned back as one bundle; a new run starts from the crew role's.
+#[derive(Resource, Bundle, Clone)]
 pub struct SavedPlayerBuffs {
-    pub max_health: f32,
-    pub health: f32,
-    pub move_speed: f32,
-    pub fire_rate: f32,
-    pub num_cleared: usize,
-    pub armor: f32,
-    pub air_tank_max: f32,
-    pub air_tank_drain_rate: f32,
-    pub weapon_damage: f32,
-    pub piercing_pickups: u32,
-    pub regen_rate: f32,
-    pub shield_max: f32,
-    pub vacuum_mass: f32,
-    /// Extra weapons beyond the base Zapper (e.g. BeamRifle picked up from chest).
-    pub extra_weapons: Vec<weapons::WeaponType>,
+    pub stats: stats::StatSheet,
+    pub health: Health,
+    pub inventory: weapons::WeaponInventory,
+    pub reward_stacks: rewards::RewardStacks,
+    pub scrap: shop::Scrap,
+    pub consumables: items::Consumables,
+}
+
+/// The player components `SavedPlayerBuffs` is a snapshot of.
+#[derive(QueryData)]
+pub struct CarriedState {
+    stats: &'static stats::StatSheet,
+    health: &'static Health,
+    inventory: &'static weapons::WeaponInventory,
+    reward_stacks: &'static rewards::RewardStacks,
+    scrap: &'static shop::Scrap,
+    consumables: &'static items::Consumables,
+}
+
+impl CarriedStateItem<'_> {
+    pub fn snapshot(&self) -> SavedPlayerBuffs {
+        SavedPlayerBuffs {
+            stats: self.stats.clone(),
+            health: self.health.clone(),
+            inventory: self.inventory.clone(),
+            reward_stacks: self.reward_stacks.clone(),
+            scrap: *self.scrap,
+            consumables: self.consumables.clone(),
+        }
+    }
 }
 
 #[derive(Component)]
@@ -146,6 +176,8 @@ pub struct StationLevelDisplay;
 enum GameState {
     #[default]
     Menu,
+    /// Picking a crew role before a new run.
+    CrewSelect,
     Loading,
     Playing,
     GameOver,
@@ -184,6 +216,13 @@ fn main() {
             table::TablePlugin,
             fluiddynamics::FluidSimPlugin,
             window::WindowPlugin,
+            explosion::ExplosionPlugin,
+            impacts::ImpactPlugin,
+            aim::AimPlugin,
+            shop::ShopPlugin,
+            stats::StatsPlugin,
+            status::StatusPlugin,
+            damage::DamagePlugin,
         ))
         .add_plugins((
             menu::MenuPlugin,
@@ -193,12 +232,16 @@ fn main() {
             rewards::RewardPlugin,
             heart::HeartPlugin,
             enemies::reaper::ReaperPlugin,
+            enemies::boss::BossPlugin,
             weapons::WeaponPlugin,
             minimap::MinimapPlugin,
             pause::PausePlugin,
             settings::SettingsPlugin,
             key_chest::KeyChestPlugin,
+            director::DirectorPlugin,
+            waves::WavePlugin,
         ))
+        .add_plugins((items::ItemsPlugin, crew::CrewPlugin))
         .add_systems(Startup, (setup_camera, rewards::load_reward_font))
         .add_systems(OnEnter(GameState::Menu), log_state_change)
         .add_systems(OnEnter(GameState::Loading), log_state_change)
@@ -271,6 +314,8 @@ fn check_win(
     mut commands: Commands,
     rooms: Res<RoomVec>,
     reaper_q: Query<(), With<enemies::Reaper>>,
+    boss_q: Query<(), With<enemies::Boss>>,
+    boss_state: Res<enemies::boss::BossState>,
     level_complete: Option<Res<LevelComplete>>,
     asset_server: Res<AssetServer>,
 ){
@@ -278,10 +323,12 @@ fn check_win(
     if level_complete.is_some() { return; }
 
     let cleared = rooms.0.iter().filter(|r| r.cleared).count();
+    // A station with a boss room is only done once the boss has actually died.
+    let boss_done = boss_state.defeated || !rooms.0.iter().any(|r| r.is_boss_room);
 
-    // All rooms cleared AND the reaper is dead (or never spawned).
+    // All rooms cleared AND the reaper and the station boss are dead.
     // The airlock is pre-cleared so it counts toward both sides equally.
-    if cleared == rooms.0.len() && reaper_q.is_empty() {
+    if cleared == rooms.0.len() && reaper_q.is_empty() && boss_q.is_empty() && boss_done {
         commands.insert_resource(LevelComplete);
 
         // Show a hint banner telling the player to return to their ship.
@@ -292,7 +339,8 @@ fn check_win(
             Node {
                 position_type: PositionType::Absolute,
                 width: Val::Percent(100.0),
-                bottom: Val::Px(40.0),
+                // Clear of the hotbar.
+                bottom: Val::Px(70.0),
                 justify_content: JustifyContent::Center,
                 ..default()
             },
@@ -316,45 +364,19 @@ fn check_return_to_airlock(
     mut commands: Commands,
     mut next_state: ResMut<NextState<GameState>>,
     rooms: Res<RoomVec>,
-    player_q: Query<(
-        &Health, &player::MaxHealth, &player::MoveSpeed, &weapons::WeaponInventory,
-        &player::NumOfCleared, &player::Armor, &player::AirTank,
-        &player::Regen, &player::Shield, &fluiddynamics::PulledByFluid,
-        &Transform,
-    ), With<Player>>,
+    player_q: Query<(CarriedState, &Transform), With<Player>>,
     level_complete: Option<Res<LevelComplete>>,
 ) {
     if level_complete.is_none() { return; }
 
-    let Ok((health, max_hp, move_spd, inventory, _num_cleared, armor, tank, regen, shield, pull, transform))
-        = player_q.single() else { return; };
-    let weapon = inventory.current();
+    let Ok((carried, transform)) = player_q.single() else { return; };
 
     let player_pos = transform.translation.truncate();
     let in_airlock = rooms.0.iter().any(|r| r.is_airlock && r.bounds_check(player_pos));
     if !in_airlock { return; }
 
     // Player is back on their ship — save buffs and open the win screen.
-    commands.insert_resource(SavedPlayerBuffs {
-        max_health: max_hp.0,
-        health: health.0,
-        move_speed: move_spd.0,
-        fire_rate: weapon.fire_rate,
-        // Reset cleared count so per-station scaling starts fresh.
-        num_cleared: 0,
-        armor: armor.0,
-        air_tank_max: tank.max_capacity,
-        air_tank_drain_rate: tank.drain_rate,
-        weapon_damage: weapon.damage,
-        piercing_pickups: weapon.piercing_pickups,
-        regen_rate: regen.0,
-        shield_max: shield.max,
-        vacuum_mass: pull.mass,
-        extra_weapons: inventory.weapons.iter()
-            .filter(|w| w.weapon_type != weapons::WeaponType::Zapper)
-            .map(|w| w.weapon_type)
-            .collect(),
-    });
+    commands.insert_resource(carried.snapshot());
     next_state.set(GameState::Win);
 }
 
@@ -705,12 +727,13 @@ fn update_ui_health_text(
 fn damage_on_collision(
     time: Res<Time>,
     mut cooldown: ResMut<DamageCooldown>,
-    mut player_q: Query<(&mut Health, &Transform), With<Player>>,
+    player_q: Query<(Entity, &Transform), With<Player>>,
     damaging_q: Query<(&Transform, &Collider, &Damage), With<Collidable>>,
+    mut damage_events: EventWriter<damage::DamageEvent>,
 ) {
     cooldown.0.tick(time.delta());
 
-    if let Ok((mut health, p_tf)) = player_q.single_mut() {
+    if let Ok((player, p_tf)) = player_q.single() {
         if !cooldown.0.finished() { return; }
 
         let player_half = Vec2::splat(TILE_SIZE * 0.5);
@@ -723,8 +746,13 @@ fn damage_on_collision(
             let overlap_y = (py - cy).abs() <= (player_half.y + col.half_extents.y);
 
             if overlap_x && overlap_y {
-                health.0 -= dmg.amount;
-                debug!(" Player took {} damage! HP now = {}", dmg.amount, health.0);
+                damage_events.write(damage::DamageEvent {
+                    source: damage::DamageSource::Station,
+                    target: player,
+                    amount: dmg.amount,
+                    kind: damage::DamageKind::Environment,
+                });
+                debug!(" Player took {} hazard damage!", dmg.amount);
                 cooldown.0.reset();
                 break;
             }
@@ -81,6 +81,17 @@ pub struct WallGrid {
 }
 
 impl WallGrid {
+    /// Builds the grid from wall tile centres laid out on a `cell_size`
+    /// lattice anchored at (`x0`, `y0`).
+    pub fn from_positions(positions: impl IntoIterator<Item = Vec2>, cell_size: f32, x0: f32, y0: f32) -> Self {
+        let mut grid = Self { cells: HashMap::new(), cell_size, x0, y0 };
+        for pos in positions {
+            let key = grid.world_to_key(pos);
+            grid.cells.insert(key, Vec2::splat(cell_size * 0.5));
+        }
+        grid
+    }
+
     fn world_to_key(&self, pos: Vec2) -> (i32, i32) {
         (
             ((pos.x - self.x0) / self.cell_size).round() as i32,
@@ -112,6 +123,26 @@ impl WallGrid {
         out
     }
 
+    /// Distance from `origin` along the normalised `dir` to the first wall
+    /// cell, or `max_dist` if nothing is in the way. Marches in quarter-tile
+    /// steps, which is plenty for wall cells a whole tile wide.
+    pub fn raycast(&self, origin: Vec2, dir: Vec2, max_dist: f32) -> f32 {
+        let step = self.cell_size * 0.25;
+        let mut t = 0.0;
+        while t < max_dist {
+            let p = origin + dir * t;
+            let key = self.world_to_key(p);
+            if let Some(&half) = self.cells.get(&key) {
+                let d = (p - self.key_to_world(key.0, key.1)).abs();
+                if d.x <= half.x && d.y <= half.y {
+                    return t;
+                }
+            }
+            t += step;
+        }
+        max_dist
+    }
+
     /// Convert a world position to a tile-grid (col, row) key.
     pub fn world_to_tile(&self, pos: Vec2) -> (i32, i32) {
         self.world_to_key(pos)
@@ -209,6 +240,24 @@ fn load_map(
     commands.insert_resource(level);
 }
 
+/// A wall tile inside a room's bounds with walkable floor on both sides —
+/// a pillar or partition rather than part of the hull or a room boundary.
+fn is_interior_partition(level: &[String], col: usize, row: usize, rooms: &RoomVec) -> bool {
+    let walkable = |c: usize, r: usize| {
+        level.get(r)
+            .and_then(|line| line.chars().nth(c))
+            .is_some_and(|ch| matches!(ch, '#' | 'S' | 'T' | 'E'))
+    };
+    let inside_room = rooms.0.iter().any(|room| {
+        let (x1, y1) = (room.tile_top_left_corner.x as usize, room.tile_top_left_corner.y as usize);
+        let (x2, y2) = (room.tile_bot_right_corner.x as usize, room.tile_bot_right_corner.y as usize);
+        col > x1 && col < x2 && row > y1 && row < y2
+    });
+    let open_sides = (col > 0 && walkable(col - 1, row) && walkable(col + 1, row))
+        || (row > 0 && walkable(col, row - 1) && walkable(col, row + 1));
+    inside_room && open_sides
+}
+
 pub fn setup_tilemap(
     mut commands: Commands,
     tiles: Res<TileRes>,
@@ -250,6 +299,7 @@ pub fn setup_tilemap(
     // Floor tiles are grouped into contiguous horizontal strips (one entity per run)
     // instead of one entity per tile, cutting floor entity count by ~room_width times.
     let mut wall_positions = Vec::new();
+    let mut partition_positions = Vec::new();
     let mut table_positions = Vec::new();
     let mut glass_positions = Vec::new();
     let mut door_positions = Vec::new();
@@ -301,6 +351,9 @@ pub fn setup_tilemap(
                 ('T', _, false) | (_, true, false) => {
                     table_positions.push(Vec3::new(x, y, Z_FLOOR + 2.0));
                 }
+                ('W', _, _) if is_interior_partition(&level.level, col_i, row_i, &rooms) => {
+                    partition_positions.push(Vec3::new(x, y, Z_FLOOR + 1.0));
+                }
                 ('W', _, _) => {
                     wall_positions.push(Vec3::new(x, y, Z_FLOOR + 1.0));
                 }
@@ -336,23 +389,15 @@ pub fn setup_tilemap(
     // Build wall spatial hash — O(1) neighbourhood lookup replaces
     // the O(n_walls) linear scan done every frame in collision systems.
     // Glass tiles are included so enemies cannot walk through intact windows.
-    let mut wall_cells = HashMap::new();
-    for &pos in wall_positions.iter().chain(glass_positions.iter()) {
-        let key = (
-            ((pos.x - x0) / TILE_SIZE).round() as i32,
-            ((pos.y - y0) / TILE_SIZE).round() as i32,
-        );
-        wall_cells.insert(key, Vec2::splat(TILE_SIZE * 0.5));
-    }
-    commands.insert_resource(WallGrid {
-        cells: wall_cells,
-        cell_size: TILE_SIZE,
+    commands.insert_resource(WallGrid::from_positions(
+        wall_positions.iter().chain(&partition_positions).chain(&glass_positions).map(|p| p.truncate()),
+        TILE_SIZE,
         x0,
         y0,
-    });
+    ));
 
-    // Batch spawn walls
-    let wall_batch: Vec<_> = wall_positions.iter().map(|&pos| {
+    // Batch spawn walls; interior partitions can be shot down.
+    let wall_batch: Vec<_> = wall_positions.iter().chain(&partition_positions).map(|&pos| {
         let mut sprite = Sprite::from_image(tiles.wall.clone());
         sprite.custom_size = Some(Vec2::new(TILE_SIZE,TILE_SIZE*1.5625));
         (
@@ -369,7 +414,13 @@ pub fn setup_tilemap(
             GameEntity,
         )
     }).collect();
-    commands.spawn_batch(wall_batch);
+    let partitions_start = wall_positions.len();
+    for (i, wall) in wall_batch.into_iter().enumerate() {
+        let mut e = commands.spawn(wall);
+        if i >= partitions_start {
+            e.insert(crate::impacts::DestructibleWall { health: crate::impacts::DESTRUCTIBLE_WALL_HEALTH });
+        }
+    }
 
     commands.insert_resource(TablePositions(table_positions));
 
@@ -411,7 +462,7 @@ pub fn setup_tilemap(
             Collidable,
             Collider { half_extents: Vec2::splat(TILE_SIZE * 0.5) },
             window::Window,
-            window::Health(50.0),
+            window::Health(window::MAX_HEALTH),
             window::GlassState::Intact,
             GameEntity,
         )
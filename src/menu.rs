@@ -240,9 +240,11 @@ fn setup_menu(
                         for line in [
                             "Controls",
                             "WASD — Move          Shift — Dash",
-                            "Left Click / Space — Shoot",
-                            "B — Broom (sweep, deflect bullets, fix windows)",
-                            "Tab — Toggle Minimap",
+                            "Mouse / Right Stick — Aim       Left Click / Space / RT — Shoot",
+                            "Q — Swap Weapon       R — Reload       I — Inspect",
+                            "Right Click — Broom: tap to combo, hold to spin, time it to parry",
+                            "E — Open Chest / Buy       1-5 — Use Hotbar Item",
+                            "Hold F — Seal Breach       Tab — Toggle Minimap",
                             "M — Toggle Music       Esc — Pause",
                         ] {
                             let size = if line == "Controls" { 18.0 } else { 15.0 };
@@ -292,6 +294,7 @@ fn handle_buttons(
     asset_server: Res<AssetServer>,
     volume: Res<GameMusicVolume>,
     window_mode: Res<settings::GameWindowMode>,
+    aim_assist: Res<crate::aim::AimAssist>,
     mut interactions: Query<(&Interaction, &MenuButton, Entity), (Changed<Interaction>, With<Button>)>,
     mut next_state: ResMut<NextState<GameState>>,
     mut show_labels: ResMut<ShowAirLabels>,
@@ -307,11 +310,11 @@ fn handle_buttons(
 
         match which {
             MenuButton::Play => {
-                next_state.set(GameState::Loading);
+                next_state.set(GameState::CrewSelect);
             }
             MenuButton::PlayTestRoom => {
                 level_to_load.0 = "assets/rooms/window_room.txt".to_string();
-                next_state.set(GameState::Loading);
+                next_state.set(GameState::CrewSelect);
             }
             MenuButton::Credits => {
                 next_state.set(GameState::EndCredits);
@@ -322,6 +325,7 @@ fn handle_buttons(
                     &asset_server,
                     volume.0,
                     *window_mode,
+                    *aim_assist,
                     settings::SettingsOrigin::MainMenu,
                 );
             }
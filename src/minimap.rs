@@ -173,6 +173,7 @@ fn setup_minimap(
                 legend_item(leg, Color::srgba(0.2, 0.2, 0.2, 0.6),  "Unexplored");
                 legend_item(leg, Color::srgb(1.0, 0.9, 0.0),         "Current");
                 legend_item(leg, Color::srgb(0.15, 0.65, 0.15),      "Cleared");
+                legend_item(leg, Color::srgb(0.6, 0.08, 0.08),       "Boss");
             });
 
             // Map panel
@@ -332,7 +333,7 @@ fn update_minimap(
     let world_max_y = map_px_h * 0.5;
 
     let current_room = match *lvlstate {
-        LevelState::InRoom(i, _, _) | LevelState::EnteredRoom(i) => Some(i),
+        LevelState::InRoom(i, _) | LevelState::EnteredRoom(i) => Some(i),
         LevelState::NotRoom => None,
     };
 
@@ -343,8 +344,12 @@ fn update_minimap(
             Color::srgba(1.0, 0.9, 0.0, 1.0)
         } else if !room.visited {
             Color::srgba(0.04, 0.04, 0.12, 1.0)
+        } else if room.is_shop {
+            Color::srgba(0.85, 0.65, 0.15, 0.9)
         } else if room.cleared {
             Color::srgba(0.15, 0.65, 0.15, 0.9)
+        } else if room.is_boss_room {
+            Color::srgba(0.6, 0.08, 0.08, 0.9)
         } else {
             Color::srgba(0.35, 0.35, 0.55, 0.9)
         };
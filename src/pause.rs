@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use crate::{GameState, GameMusicVolume, FONT_PATH, settings};
+use crate::rewards::{RewardRegistry, RewardStacks, sets, synergy};
 
 pub struct PausePlugin;
 
@@ -42,8 +43,12 @@ fn handle_escape(
     pause_ui_q: Query<Entity, With<PauseUI>>,
     settings_ui_q: Query<Entity, With<settings::SettingsUI>>,
     settings_open: Option<Res<settings::SettingsOrigin>>,
+    upgrade_choice: Option<Res<crate::rewards::choice::UpgradeChoice>>,
+    registry: Res<RewardRegistry>,
+    player_q: Query<&RewardStacks, With<crate::Player>>,
 ) {
-    if !keys.just_pressed(KeyCode::Escape) {
+    // The upgrade screen holds its own pause; it can only be closed by picking.
+    if !keys.just_pressed(KeyCode::Escape) || upgrade_choice.is_some() {
         return;
     }
 
@@ -59,11 +64,17 @@ fn handle_escape(
     if is_paused.is_some() {
         do_resume(&mut commands, &mut virtual_time, &pause_ui_q);
     } else {
-        do_pause(&mut commands, &asset_server, &mut virtual_time);
+        do_pause(&mut commands, &asset_server, &mut virtual_time, player_q.single().ok(), &registry);
     }
 }
 
-fn do_pause(commands: &mut Commands, assets: &AssetServer, time: &mut Time<Virtual>) {
+fn do_pause(
+    commands: &mut Commands,
+    assets: &AssetServer,
+    time: &mut Time<Virtual>,
+    stacks: Option<&RewardStacks>,
+    registry: &RewardRegistry,
+) {
     time.pause();
     commands.insert_resource(IsPaused);
 
@@ -77,6 +88,7 @@ fn do_pause(commands: &mut Commands, assets: &AssetServer, time: &mut Time<Virtu
                 height: Val::Percent(100.0),
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
+                column_gap: Val::Px(20.0),
                 ..default()
             },
             BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.55)),
@@ -110,6 +122,9 @@ fn do_pause(commands: &mut Commands, assets: &AssetServer, time: &mut Time<Virtu
                 spawn_pause_button(panel, font.clone(), "Settings",  PauseButton::Settings, Color::srgba(0.1,  0.1,  0.42, 0.9));
                 spawn_pause_button(panel, font.clone(), "Main Menu", PauseButton::MainMenu, Color::srgba(0.38, 0.08, 0.08, 0.9));
             });
+
+            synergy::spawn_synergy_tracker(root, font.clone(), stacks, registry);
+            sets::spawn_set_tracker(root, font.clone(), stacks, registry);
         });
 }
 
@@ -151,6 +166,7 @@ fn handle_pause_buttons(
     asset_server: Res<AssetServer>,
     volume: Res<GameMusicVolume>,
     window_mode: Res<settings::GameWindowMode>,
+    aim_assist: Res<crate::aim::AimAssist>,
     mut interactions: Query<(&Interaction, &PauseButton), (Changed<Interaction>, With<Button>)>,
     mut next_state: ResMut<NextState<GameState>>,
     mut virtual_time: ResMut<Time<Virtual>>,
@@ -170,6 +186,7 @@ fn handle_pause_buttons(
                     &asset_server,
                     volume.0,
                     *window_mode,
+                    *aim_assist,
                     settings::SettingsOrigin::Paused,
                 );
             }
@@ -9,7 +9,11 @@ use crate::enemies::HitAnimation;
 use crate::map::{LevelRes, MapGridMeta};
 use crate::fluiddynamics::PulledByFluid;
 use crate::bullet::{Bullet, Velocity};
-use crate::weapons::{Weapon, WeaponType, WeaponInventory, fire_weapon, BulletRes, WeaponSounds, BeamRifleRes};
+use crate::weapons::WeaponRegistry;
+use crate::aim::Aim;
+use crate::stats::Stat;
+use crate::damage::{DamageEvent, DamageKind, DamageSource};
+use crate::status::StatusEffects;
 const WALL_SLIDE_FRICTION_MULTIPLIER: f32 = 0.92; // lower is more friction
 
 // #[derive(Resource)]
@@ -32,7 +36,7 @@ pub struct PlayerRes{
     left: (Handle<Image>, Handle<TextureAtlasLayout>),
 }
 
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct Health(pub f32);
 
 #[derive(Component)]
@@ -211,12 +215,14 @@ fn load_player(mut commands: Commands, asset_server: Res<AssetServer>, mut textu
     
 }
 
-fn spawn_player(
+pub fn spawn_player(
     mut commands: Commands,
     player_sheet: Res<PlayerRes>,
     level: Res<LevelRes>,
     grid: Res<MapGridMeta>,
     saved_buffs: Option<Res<crate::SavedPlayerBuffs>>,
+    weapon_registry: Res<WeaponRegistry>,
+    crew: Res<crate::crew::SelectedCrew>,
 ) {
     let (image, layout) = &player_sheet.down;
 
@@ -247,35 +253,18 @@ fn spawn_player(
     let world_x = grid.x0 + gx as f32 * TILE_SIZE + x_player_spawn_offset;
     let world_y = grid.y0 + (grid.rows as f32 - 1.0 - gy as f32) * TILE_SIZE + y_player_spawn_offset;
 
-    // Apply saved buffs from previous station if continuing, otherwise use defaults
-    let (hp, max_hp, move_speed, fire_rate, num_cleared, armor, tank_max, tank_drain,
-         weapon_damage, piercing, regen_rate, shield_max, vacuum_mass) =
-        if let Some(buffs) = &saved_buffs {
-            info!(
-                "Applying saved buffs: max_hp={}, hp={}, move_spd={}, fire_rate={}, cleared={}, armor={}, tank_max={}, tank_drain={}",
-                buffs.max_health, buffs.health, buffs.move_speed, buffs.fire_rate,
-                buffs.num_cleared, buffs.armor, buffs.air_tank_max, buffs.air_tank_drain_rate
-            );
-            (
-                buffs.health, buffs.max_health, buffs.move_speed, buffs.fire_rate,
-                buffs.num_cleared, buffs.armor, buffs.air_tank_max, buffs.air_tank_drain_rate,
-                buffs.weapon_damage, buffs.piercing_pickups, buffs.regen_rate, buffs.shield_max, buffs.vacuum_mass,
-            )
-        } else {
-            (100.0, 100.0, 1.0, 0.5, 0, 0.0, 5.0, 1.0, 25.0, 0u32, 0.0, 0.0, 50.0)
-        };
-
-    let mut weapon = Weapon::new(WeaponType::Zapper);
-    weapon.fire_rate = fire_rate;
-    weapon.shoot_timer = Timer::from_seconds(fire_rate, TimerMode::Once);
-    weapon.damage = weapon_damage;
-    weapon.piercing_pickups = piercing;
-    let mut inventory = WeaponInventory::new(weapon);
-    if let Some(buffs) = &saved_buffs {
-        for &wtype in &buffs.extra_weapons {
-            inventory.weapons.push(Weapon::new(wtype));
+    // Carry the stat sheet and loadout over from the previous station if
+    // continuing; a new run starts from the chosen role's sheet and kit.
+    let role = crew.0;
+    let carried = match &saved_buffs {
+        Some(buffs) => {
+            info!("Applying saved buffs: hp={}, modifiers={}", buffs.health.0, buffs.stats.modifiers().len());
+            (**buffs).clone()
         }
-    }
+        None => role.starting_buffs(&weapon_registry),
+    };
+    let sheet = &carried.stats;
+    let fuel = sheet.get(Stat::FuelCapacity);
 
     commands.spawn((
         Sprite::from_atlas_image(
@@ -289,16 +278,24 @@ fn spawn_player(
         },
         Player,
         Velocity(Vec2::ZERO),
-        Health::new(hp),
-        MaxHealth(max_hp),
+        MaxHealth(sheet.get(Stat::MaxHealth)),
         DamageTimer::new(1.0),
         // grouped into nested tuples to stay within Bevy's 15-element Bundle limit
-        (MoveSpeed(move_speed), Armor(armor), Collidable, Regen(regen_rate), Shield::new(shield_max)),
+        (MoveSpeed(sheet.get(Stat::MoveSpeed)), Armor(sheet.get(Stat::Armor)), Collidable, Regen(sheet.get(Stat::Regen)), Shield::new(sheet.get(Stat::ShieldCharges))),
         Collider { half_extents: Vec2::new(TILE_SIZE * 0.5, TILE_SIZE * 1.0) },
         Facing(FacingDirection::Down),
-        NumOfCleared(num_cleared),
-        (PulledByFluid{mass: vacuum_mass}, AirTank::new(tank_max, tank_drain), ThrusterFuel { current: 0.0, max: 0.0 }),
-        inventory,
+        // Reset cleared count so per-station scaling starts fresh.
+        NumOfCleared(0),
+        (PulledByFluid { mass: sheet.get(Stat::Mass) },
+         AirTank::new(sheet.get(Stat::AirCapacity), sheet.get(Stat::AirDrain)),
+         ThrusterFuel { current: fuel, max: fuel }, Aim::default(),
+         crate::broom::BroomStats {
+             power: sheet.get(Stat::BroomPower),
+             parry_secs: sheet.get(Stat::ParryWindow),
+             spin_charge_secs: sheet.get(Stat::SpinCharge),
+         },
+         role),
+        carried,
         GameEntity,
     ));
 }
@@ -308,25 +305,21 @@ fn spawn_player(
  * With tells bevy to include entities with the Player component
  * Without is the opposite
 */
-fn move_player(
+pub(crate) fn move_player(
     time: Res<Time>,
     input: Res<ButtonInput<KeyCode>>,
-    mut player: Query<(&mut Transform, &mut Velocity, &mut Facing, &MoveSpeed, &mut WeaponInventory), With<Player>>,
+    gamepads: Query<&Gamepad>,
+    mut player: Query<(&mut Transform, &mut Velocity, &MoveSpeed, Has<crate::explosion::RocketBoost>), With<Player>>,
     mut next_state: ResMut<NextState<GameState>>,
     // Excludes permanent wall tiles and tables — tables are handled by player_deflects_tables.
     colliders: Query<(&Transform, &Collider), (With<Collidable>, Without<Player>, Without<Bullet>, Without<Broom>, Without<crate::map::WallTile>, Without<table::Table>)>,
     wall_grid: Res<crate::map::WallGrid>,
-    mut commands: Commands,
-    bullet_res: Res<BulletRes>,
-    beam_res: Res<BeamRifleRes>,
     grid_query: Query<&crate::fluiddynamics::FluidGrid>,
-    buttons: Res<ButtonInput<MouseButton>>,
-    weapon_sounds: Res<WeaponSounds>,
 ) {
     let Ok(grid) = grid_query.single() else {
         return;
     };
-    let Ok((mut transform, mut velocity, mut facing, spd, mut inventory)) = player.single_mut() else {
+    let Ok((mut transform, mut velocity, spd, boosted)) = player.single_mut() else {
         return;
     };
 
@@ -337,65 +330,35 @@ fn move_player(
     }
     if input.pressed(KeyCode::KeyA) {
         dir.x -= 1.;
-        facing.0 = FacingDirection::Left;
     }
     if input.pressed(KeyCode::KeyD) {
         dir.x += 1.;
-        facing.0 = FacingDirection::Right;
     }
     if input.pressed(KeyCode::KeyW) {
         dir.y += 1.;
-        facing.0 = FacingDirection::Up;
     }
     if input.pressed(KeyCode::KeyS) {
         dir.y -= 1.;
-        facing.0 = FacingDirection::Down;
     }
 
-    // decide what direction the player is facing if is diagonal
-    if dir == vec2(1.0,1.0){
-        facing.0 = FacingDirection::UpRight;
-    }
-    if dir == vec2(-1.0,1.0){
-        facing.0 = FacingDirection::UpLeft;
-    }
-    if dir == vec2(1.0,-1.0){
-        facing.0 = FacingDirection::DownRight;
-    }
-    if dir == vec2(-1.0,-1.0){
-        facing.0 = FacingDirection::DownLeft;
-    }
-
-
-    if input.pressed(KeyCode::Space) && inventory.current().can_shoot() && !buttons.pressed(MouseButton::Left) {
-        let bullet_dir = match facing.0 {
-            FacingDirection::Up => Vec2::new(0.0, 1.0),
-            FacingDirection::UpRight => Vec2::new(1.0, 1.0),
-            FacingDirection::UpLeft => Vec2::new(-1.0, 1.0),
-            FacingDirection::Down => Vec2::new(0.0, -1.0),
-            FacingDirection::DownRight => Vec2::new(1.0, -1.0),
-            FacingDirection::DownLeft => Vec2::new(-1.0, -1.0),
-            FacingDirection::Left => Vec2::new(-1.0, 0.0),
-            FacingDirection::Right => Vec2::new(1.0, 0.0),
-        };
-
-        fire_weapon(
-            &mut commands,
-            &mut inventory,
-            &bullet_res,
-            &beam_res,
-            &weapon_sounds,
-            transform.translation.truncate(),
-            bullet_dir,
-        );
+    // Left stick moves when the keyboard isn't. Facing comes from the aim, not from here.
+    if dir == Vec2::ZERO
+        && let Some(stick) = gamepads.iter().map(|g| g.left_stick()).find(|s| s.length() > 0.25)
+    {
+        dir = stick;
     }
 
     //Time based on frame to ensure that movement is the same no matter the fps
     let deltat = time.delta_secs();
     let accel = ACCEL_RATE * deltat;
 
+    // A blast just launched us — keep the extra speed instead of clamping it away.
+    let max_speed = if boosted { velocity.length().max(spd.0) } else { spd.0 };
+
     **velocity = if dir.length() > 0. {
-        (**velocity + (dir.normalize_or_zero() * accel)).clamp_length_max(PLAYER_SPEED + spd.0)
+        (**velocity + (dir.normalize_or_zero() * accel)).clamp_length_max(max_speed)
+    } else if boosted {
+        **velocity
     // allows the player to be moved if the breaches are open
     // the drag helps stop the player so it doesn't feel like they are on ice
     } else if !grid.breaches.is_empty() {
@@ -525,19 +488,25 @@ impl DamageTimer {
 
 fn enemy_hits_player(
     time: Res<Time>,
-    mut player_query: Query<(&Transform, &mut crate::player::Health, &mut DamageTimer, &Armor, &mut Shield), With<crate::player::Player>>,
-    enemy_query: Query<(Entity, &Transform, &crate::enemies::Health), With<Enemy>>,
+    mut player_query: Query<(Entity, &Transform, &mut DamageTimer), With<crate::player::Player>>,
+    mut enemy_query: Query<
+        (Entity, &Transform, &mut crate::enemies::Health, &crate::enemies::MaxHealth, Option<&crate::enemies::Elite>, Option<&StatusEffects>),
+        With<Enemy>,
+    >,
     mut commands: Commands,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
     let player_half = Vec2::splat(32.0);
     let enemy_half = Vec2::splat(ENEMY_SIZE * 0.5);
-    for (player_tf, mut health, mut damage_timer, armor, mut shield) in &mut player_query {
+    for (player_entity, player_tf, mut damage_timer) in &mut player_query {
 
         damage_timer.0.tick(time.delta());
 
         let player_pos = player_tf.translation.truncate();
 
-        for (enemy_entity, enemy_tf, enemy_health) in &enemy_query {
+        for (enemy_entity, enemy_tf, mut enemy_health, enemy_max, elite, statuses) in &mut enemy_query {
+            // Stunned and frozen enemies can't land a hit.
+            if statuses.is_some_and(StatusEffects::incapacitated) { continue; }
             let enemy_pos = enemy_tf.translation.truncate();
             if aabb_overlap(
                 player_pos.x,
@@ -552,14 +521,18 @@ fn enemy_hits_player(
                         "Player hit by entity {:?} at position {:?}",
                         enemy_entity, enemy_pos
                     );
-                    if shield.current >= 1.0 {
-                        shield.current -= 1.0;
-                    } else {
-                        health.0 -= 15.0 * armor_factor(armor.0);
-                    }
+                    damage_events.write(DamageEvent {
+                        source: DamageSource::Enemy,
+                        target: player_entity,
+                        amount: 15.0,
+                        kind: DamageKind::Melee,
+                    });
                     damage_timer.0.reset();
-                    
-               
+
+                    if elite.is_some_and(|e| e.has(crate::enemies::EliteAffix::Vampiric)) {
+                        enemy_health.0 = (enemy_health.0 + crate::enemies::elite::VAMPIRIC_HEAL).min(enemy_max.0);
+                    }
+
                     if enemy_health.0 > 0.0 {
                         commands.entity(enemy_entity).insert(HitAnimation {
                             timer: Timer::from_seconds(0.3, TimerMode::Once),
@@ -579,9 +552,8 @@ fn enemy_hits_player(
 
 fn update_player_sprite(
     time: Res<Time>,
-    mut query: Query<&mut Sprite, With<Player>>,
+    mut query: Query<(&mut Sprite, &Aim, &Velocity), With<Player>>,
     player_res: Res<PlayerRes>,
-    input: Res<ButtonInput<KeyCode>>,
     mut frame_timer: Local<f32>,
 ) {
     *frame_timer += time.delta_secs();
@@ -589,25 +561,28 @@ fn update_player_sprite(
     let frame = ((*frame_timer / 0.1) as usize) % 8;
 
 
-    for mut sprite in &mut query {
-        // Select the current sprite sheet based on input
-        let (image, layout_handle) = if input.pressed(KeyCode::KeyW) {
-            &player_res.up
-        } else if input.pressed(KeyCode::KeyS) {
-            &player_res.down
-        } else if input.pressed(KeyCode::KeyA) {
+    for (mut sprite, aim, velocity) in &mut query {
+        // The sheet follows the aim; the walk cycle only plays while moving.
+        let (image, layout_handle) = if aim.dir.y.abs() >= aim.dir.x.abs() {
+            if aim.dir.y >= 0.0 { &player_res.up } else { &player_res.down }
+        } else if aim.dir.x < 0.0 {
             &player_res.left
-        } else if input.pressed(KeyCode::KeyD) {
-            &player_res.right
         } else {
-            continue;
+            &player_res.right
         };
-        
+        let moving = velocity.length() > 1.0;
+        let index = match &sprite.texture_atlas {
+            Some(atlas) if !moving => atlas.index,
+            _ => frame,
+        };
+
+        if sprite.image != *image {
+            sprite.image = image.clone();
+        }
         sprite.texture_atlas = Some(TextureAtlas {
             layout: layout_handle.clone(),
-            index: frame,
+            index,
         });
-        sprite.image = image.clone();
     }
 }
 //-------------------------------------------------------------------------------------------------------------
@@ -627,12 +602,13 @@ fn regen_system(
 /// away from the table's center. Direction is always outward from the table, so
 /// many tables surrounding the player cancel each other out rather than stacking.
 fn table_hits_player(
-    mut player_query: Query<(&Transform, &mut Velocity, &mut Health, &mut DamageTimer, &Armor, &mut Shield), With<Player>>,
+    mut player_query: Query<(Entity, &Transform, &mut Velocity, &mut DamageTimer), With<Player>>,
     table_query: Query<(&Transform, &Collider, Option<&crate::enemies::Velocity>), With<table::Table>>,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
     let player_half = Vec2::new(TILE_SIZE * 0.5, TILE_SIZE * 1.0);
 
-    for (player_tf, mut player_vel, mut health, mut dmg_timer, armor, mut shield) in &mut player_query {
+    for (player_entity, player_tf, mut player_vel, mut dmg_timer) in &mut player_query {
         let player_pos = player_tf.translation.truncate();
 
         let mut total_impulse = Vec2::ZERO;
@@ -661,11 +637,12 @@ fn table_hits_player(
             **player_vel = (**player_vel + capped).clamp_length_max(PLAYER_SPEED * 2.0);
 
             if dmg_timer.0.finished() {
-                if shield.current >= 1.0 {
-                    shield.current -= 1.0;
-                } else {
-                    health.0 -= fastest_speed * 0.02 * armor_factor(armor.0);
-                }
+                damage_events.write(DamageEvent {
+                    source: DamageSource::Station,
+                    target: player_entity,
+                    amount: fastest_speed * 0.02,
+                    kind: DamageKind::Impact,
+                });
                 dmg_timer.0.reset();
             }
         }
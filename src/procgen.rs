@@ -322,6 +322,12 @@ pub fn build_full_level(
     add_airlock_room(&mut map, &mut room_vec);
     debug!("Finished airlock placement.");
 
+    mark_boss_room(&mut room_vec);
+    debug!("Finished boss room selection.");
+
+    mark_shop_room(&mut room_vec);
+    debug!("Finished shop room selection.");
+
     generate_walls(&mut map);
     debug!("Finished wall generation.");
 
@@ -994,6 +1000,44 @@ pub fn add_airlock_room(map: &mut Vec<Vec<char>>, room_vec: &mut RoomVec) {
     }
 }
 
+/// Flag the room whose centre is furthest from the airlock as the boss room,
+/// so the player has to cross the whole station to reach it.
+pub fn mark_boss_room(room_vec: &mut RoomVec) {
+    let centre = |r: &Room| (r.top_left_corner + r.bot_right_corner) * 0.5;
+    let Some(airlock) = room_vec.0.iter().find(|r| r.is_airlock).map(centre) else { return };
+
+    let furthest = room_vec.0.iter()
+        .enumerate()
+        .filter(|(_, r)| !r.is_airlock)
+        .max_by(|(_, a), (_, b)| {
+            centre(a).distance_squared(airlock).total_cmp(&centre(b).distance_squared(airlock))
+        })
+        .map(|(i, _)| i);
+
+    if let Some(i) = furthest {
+        room_vec.0[i].is_boss_room = true;
+    }
+}
+
+/// Turn the room halfway (by distance) between the airlock and the boss into
+/// the vendor room, so the shop is on the way rather than at either end.
+pub fn mark_shop_room(room_vec: &mut RoomVec) {
+    let centre = |r: &Room| (r.top_left_corner + r.bot_right_corner) * 0.5;
+    let Some(airlock) = room_vec.0.iter().find(|r| r.is_airlock).map(centre) else { return };
+
+    let mut candidates: Vec<(usize, f32)> = room_vec.0.iter()
+        .enumerate()
+        .filter(|(_, r)| !r.is_airlock && !r.is_boss_room)
+        .map(|(i, r)| (i, centre(r).distance_squared(airlock)))
+        .collect();
+    if candidates.len() < 3 { return; }
+    candidates.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    let (i, _) = candidates[candidates.len() / 2];
+    room_vec.0[i].is_shop = true;
+    room_vec.0[i].cleared = true;
+}
+
 pub fn place_windows<R: Rng>(
     map: &mut Vec<Vec<char>>,
     room_vec: &RoomVec,
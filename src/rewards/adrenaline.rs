@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem, Rarity, Stacking};
+use super::cursed::CURSED_TINT;
+use crate::stats::{ModOp, ModSource, Stat};
+
+pub const ID: RewardId = RewardId("adrenaline");
+const DOUBLE: ModOp = ModOp::Mul(2.0);
+
+pub struct Adrenaline;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(Adrenaline);
+}
+
+impl RewardBehavior for Adrenaline {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Adrenaline" }
+
+    fn description(&self) -> &'static str { "Double move speed, but your air tank drains twice as fast." }
+
+    fn icon(&self) -> &'static str { "rewards/MoveSpdBox.png" }
+
+    fn tint(&self) -> Color { CURSED_TINT }
+
+    fn rarity(&self) -> Rarity { Rarity::Cursed }
+
+    fn stacking(&self) -> Stacking { Stacking::Max(1) }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        let s = &target.stats;
+        format!(
+            "Move speed {:.0} -> {:.0}\nAir drain {:.2}/s -> {:.2}/s",
+            s.get(Stat::MoveSpeed), s.preview(Stat::MoveSpeed, DOUBLE),
+            s.get(Stat::AirDrain), s.preview(Stat::AirDrain, DOUBLE),
+        )
+    }
+
+    fn apply(&self, target: &mut RewardTargetItem) {
+        target.stats
+            .add(Stat::MoveSpeed, DOUBLE, ModSource::Reward(ID))
+            .add(Stat::AirDrain, DOUBLE, ModSource::Reward(ID));
+    }
+}
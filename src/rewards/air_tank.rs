@@ -1,9 +1,34 @@
-use crate::player::AirTank;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem};
+use crate::stats::{ModOp, ModSource, Stat};
 
-pub const NAME: &str = "Larger Air Tank";
-pub const ASSET: &str = "rewards/LargerTank.png";
+pub const ID: RewardId = RewardId("air_tank");
+const CAPACITY: ModOp = ModOp::Add(2.5);
 
-pub fn apply(tank: &mut AirTank) {
-    tank.max_capacity += 2.5;
-    tank.current = (tank.current + 2.5).min(tank.max_capacity);
+pub struct LargerTank;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(LargerTank);
+}
+
+impl RewardBehavior for LargerTank {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Larger Air Tank" }
+
+    fn description(&self) -> &'static str { "Holds 2.5 more units of oxygen." }
+
+    fn icon(&self) -> &'static str { "rewards/LargerTank.png" }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        format!(
+            "Air tank {:.1} -> {:.1}",
+            target.stats.get(Stat::AirCapacity),
+            target.stats.preview(Stat::AirCapacity, CAPACITY),
+        )
+    }
+
+    fn apply(&self, target: &mut RewardTargetItem) {
+        target.stats.add(Stat::AirCapacity, CAPACITY, ModSource::Reward(ID));
+        target.player.air_tank.current += 2.5;
+    }
 }
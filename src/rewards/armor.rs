@@ -1,8 +1,29 @@
-use crate::player::Armor;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem};
+use crate::stats::{ModOp, ModSource, Stat};
 
-pub const NAME: &str = "Armor Up";
-pub const ASSET: &str = "rewards/ArmorBox.png";
+pub const ID: RewardId = RewardId("armor");
+const ARMOR: ModOp = ModOp::Add(20.0);
 
-pub fn apply(armor: &mut Armor) {
-    armor.0 += 20.0;
+pub struct ArmorUp;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(ArmorUp);
+}
+
+impl RewardBehavior for ArmorUp {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Armor Up" }
+
+    fn description(&self) -> &'static str { "+20 armor. Every hit hurts a little less." }
+
+    fn icon(&self) -> &'static str { "rewards/ArmorBox.png" }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        format!("Armor {:.0} -> {:.0}", target.stats.get(Stat::Armor), target.stats.preview(Stat::Armor, ARMOR))
+    }
+
+    fn apply(&self, target: &mut RewardTargetItem) {
+        target.stats.add(Stat::Armor, ARMOR, ModSource::Reward(ID));
+    }
 }
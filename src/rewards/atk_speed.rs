@@ -1,11 +1,35 @@
-use std::time::Duration;
-use crate::weapons::Weapon;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem};
+use crate::stats::{ModOp, ModSource, Stat};
 
-pub const NAME: &str = "Attack Speed Up";
-pub const ASSET: &str = "rewards/AtkSpdBox.png";
+pub const ID: RewardId = RewardId("atk_speed");
+const BONUS: ModOp = ModOp::Add(0.1);
 
-pub fn apply(weapon: &mut Weapon) {
-    let new_rate = (weapon.fire_rate - 0.03).max(0.1);
-    weapon.fire_rate = new_rate;
-    weapon.shoot_timer.set_duration(Duration::from_secs_f32(new_rate));
+pub struct AtkSpeed;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(AtkSpeed);
+}
+
+impl RewardBehavior for AtkSpeed {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Attack Speed Up" }
+
+    fn description(&self) -> &'static str { "Every weapon fires 10% faster." }
+
+    fn icon(&self) -> &'static str { "rewards/AtkSpdBox.png" }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        let rate = target.inventory.current().fire_rate;
+        let s = &target.stats;
+        format!(
+            "Fire delay {:.2}s -> {:.2}s",
+            rate / s.get(Stat::FireRate),
+            rate / s.preview(Stat::FireRate, BONUS),
+        )
+    }
+
+    fn apply(&self, target: &mut RewardTargetItem) {
+        target.stats.add(Stat::FireRate, BONUS, ModSource::Reward(ID));
+    }
 }
@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem, Rarity, Stacking};
+use crate::stats::{ModOp, ModSource, Stat};
+
+pub const ID: RewardId = RewardId("broom_parry");
+const PARRY: ModOp = ModOp::Add(0.05);
+const SPIN: ModOp = ModOp::Mul(0.85);
+const TINT: Color = Color::srgb(1.0, 0.75, 0.4);
+
+pub struct QuickReflexes;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(QuickReflexes);
+}
+
+impl RewardBehavior for QuickReflexes {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Quick Reflexes" }
+
+    fn description(&self) -> &'static str { "Longer parry window and a faster-charging spin." }
+
+    /// No crate art of its own yet — reuses the shield crate, tinted.
+    fn icon(&self) -> &'static str { "rewards/Shield.png" }
+
+    fn tint(&self) -> Color { TINT }
+
+    fn rarity(&self) -> Rarity { Rarity::Legendary }
+
+    fn stacking(&self) -> Stacking { Stacking::Max(4) }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        let s = &target.stats;
+        format!(
+            "Parry {:.2}s -> {:.2}s, spin charge {:.2}s -> {:.2}s",
+            s.get(Stat::ParryWindow), s.preview(Stat::ParryWindow, PARRY),
+            s.get(Stat::SpinCharge), s.preview(Stat::SpinCharge, SPIN),
+        )
+    }
+
+    /// Wider parry window and a faster-charging spin.
+    fn apply(&self, target: &mut RewardTargetItem) {
+        target.stats
+            .add(Stat::ParryWindow, PARRY, ModSource::Reward(ID))
+            .add(Stat::SpinCharge, SPIN, ModSource::Reward(ID));
+    }
+}
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem, Rarity};
+use crate::stats::{ModOp, ModSource, Stat};
+
+pub const ID: RewardId = RewardId("broom_power");
+const POWER: ModOp = ModOp::Add(0.25);
+const TINT: Color = Color::srgb(0.85, 0.65, 0.35);
+
+pub struct StiffBristles;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(StiffBristles);
+}
+
+impl RewardBehavior for StiffBristles {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Stiff Bristles" }
+
+    fn description(&self) -> &'static str { "Broom swings hit harder and knock enemies further." }
+
+    /// No crate art of its own yet — reuses the damage crate, tinted.
+    fn icon(&self) -> &'static str { "rewards/DamageUp.png" }
+
+    fn tint(&self) -> Color { TINT }
+
+    fn rarity(&self) -> Rarity { Rarity::Rare }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        format!(
+            "Broom power {:.0}% -> {:.0}%",
+            target.stats.get(Stat::BroomPower) * 100.0,
+            target.stats.preview(Stat::BroomPower, POWER) * 100.0,
+        )
+    }
+
+    /// Broom swings hit harder and knock enemies further.
+    fn apply(&self, target: &mut RewardTargetItem) {
+        target.stats.add(Stat::BroomPower, POWER, ModSource::Reward(ID));
+    }
+}
@@ -0,0 +1,295 @@
+use bevy::prelude::*;
+
+use super::{RewardFont, RewardId, RewardPool, RewardRegistry, RewardStacks, RewardTarget, spawn_popup};
+use crate::Player;
+use crate::pause::IsPaused;
+
+/// How many upgrades each choice offers.
+const OFFER_COUNT: usize = 3;
+/// HP the first reroll costs; each further reroll of the same choice costs more.
+const REROLL_BASE_HP: f32 = 10.0;
+const REROLL_STEP_HP: f32 = 5.0;
+
+/// Ask for the pick-one-of-three screen. Sent with the standard pool when a
+/// room is cleared and with the cursed pool when a cursed chest is opened.
+/// `picks` is how many upgrades are taken before the screen closes; each
+/// pick after the first gets a fresh set of offers.
+#[derive(Event, Clone, Copy)]
+pub struct OfferUpgradeEvent {
+    pub pool: RewardPool,
+    pub picks: u32,
+}
+
+/// Present while the choice screen is open. Game time is paused and
+/// `IsPaused` is set, exactly as for the pause menu.
+#[derive(Resource)]
+pub struct UpgradeChoice {
+    pub offers: Vec<RewardId>,
+    pub rerolls: u32,
+    pub pool: RewardPool,
+    pub picks: u32,
+}
+
+impl UpgradeChoice {
+    pub fn reroll_cost(&self) -> f32 {
+        REROLL_BASE_HP + REROLL_STEP_HP * self.rerolls as f32
+    }
+}
+
+#[derive(Component)]
+pub struct UpgradeChoiceUI;
+
+#[derive(Component, Clone, Copy)]
+pub enum ChoiceButton {
+    Pick(usize),
+    Reroll,
+    /// Walk away from a cursed chest without taking anything.
+    Decline,
+}
+
+/// Rolls the offers and freezes the game. A second request while the
+/// screen is already up is dropped.
+pub fn open_upgrade_choice(
+    mut commands: Commands,
+    mut events: EventReader<OfferUpgradeEvent>,
+    existing: Option<Res<UpgradeChoice>>,
+    registry: Res<RewardRegistry>,
+    director: Res<crate::director::Director>,
+    player_q: Query<&RewardStacks, With<Player>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    let Some(&OfferUpgradeEvent { pool, picks }) = events.read().last() else { return; };
+    if existing.is_some() {
+        return;
+    }
+    let Ok(stacks) = player_q.single() else { return; };
+    let offers = registry.roll_many(stacks, director.reward_quality(), OFFER_COUNT, pool);
+    if offers.is_empty() {
+        return;
+    }
+
+    virtual_time.pause();
+    commands.insert_resource(IsPaused);
+    commands.insert_resource(UpgradeChoice { offers, rerolls: 0, pool, picks: picks.max(1) });
+}
+
+/// Clicks on the cards (or 1/2/3) take an upgrade; the reroll button (or R)
+/// pays HP for a fresh set of offers. Cursed offers can't be rerolled but
+/// can be turned down (X). The screen is redrawn whenever the offers change.
+pub fn handle_upgrade_choice(
+    mut commands: Commands,
+    choice: Option<ResMut<UpgradeChoice>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    interactions: Query<(&Interaction, &ChoiceButton), (Changed<Interaction>, With<Button>)>,
+    registry: Res<RewardRegistry>,
+    director: Res<crate::director::Director>,
+    font: Res<RewardFont>,
+    mut player_q: Query<(&Transform, &mut RewardStacks, RewardTarget), With<Player>>,
+    ui_q: Query<Entity, With<UpgradeChoiceUI>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    let Some(mut choice) = choice else { return; };
+
+    let pressed = interactions
+        .iter()
+        .find(|(i, _)| **i == Interaction::Pressed)
+        .map(|(_, b)| *b);
+    let action = pressed.or_else(|| {
+        [KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3]
+            .iter()
+            .position(|k| keys.just_pressed(*k))
+            .map(ChoiceButton::Pick)
+    })
+    .or_else(|| keys.just_pressed(KeyCode::KeyR).then_some(ChoiceButton::Reroll))
+    .or_else(|| keys.just_pressed(KeyCode::KeyX).then_some(ChoiceButton::Decline));
+    let Some(action) = action else { return; };
+    let Ok((player_tf, mut stacks, mut target)) = player_q.single_mut() else { return; };
+
+    match action {
+        ChoiceButton::Pick(i) => {
+            let Some(&id) = choice.offers.get(i) else { return; };
+            let Some(behavior) = registry.grant(id, &mut target, &mut stacks) else { return; };
+            spawn_popup(&mut commands, &font, player_tf.translation.truncate(), behavior.name());
+            choice.picks -= 1;
+            let offers = match choice.picks {
+                0 => Vec::new(),
+                _ => registry.roll_many(&stacks, director.reward_quality(), OFFER_COUNT, choice.pool),
+            };
+            if offers.is_empty() {
+                close(&mut commands, &mut virtual_time, &ui_q);
+            } else {
+                choice.offers = offers;
+            }
+        }
+        ChoiceButton::Decline => {
+            if choice.pool == RewardPool::Cursed {
+                close(&mut commands, &mut virtual_time, &ui_q);
+            }
+        }
+        ChoiceButton::Reroll => {
+            if choice.pool != RewardPool::Standard { return; }
+            // Never let a reroll be the thing that kills you.
+            let cost = choice.reroll_cost();
+            if target.player.health.0 <= cost { return; }
+            target.player.health.0 -= cost;
+            choice.rerolls += 1;
+            let offers = registry.roll_many(&stacks, director.reward_quality(), OFFER_COUNT, choice.pool);
+            if !offers.is_empty() {
+                choice.offers = offers;
+            }
+        }
+    }
+}
+
+fn close(commands: &mut Commands, virtual_time: &mut Time<Virtual>, ui_q: &Query<Entity, With<UpgradeChoiceUI>>) {
+    virtual_time.unpause();
+    commands.remove_resource::<IsPaused>();
+    commands.remove_resource::<UpgradeChoice>();
+    for e in ui_q {
+        commands.entity(e).despawn();
+    }
+}
+
+/// Rebuilds the cards whenever the offers change. Each card shows what the
+/// upgrade would do to the player's current stats.
+pub fn draw_upgrade_choice(
+    mut commands: Commands,
+    choice: Option<Res<UpgradeChoice>>,
+    registry: Res<RewardRegistry>,
+    font: Res<RewardFont>,
+    player_q: Query<RewardTarget, With<Player>>,
+    ui_q: Query<Entity, With<UpgradeChoiceUI>>,
+) {
+    let Some(choice) = choice else { return; };
+    if !choice.is_changed() {
+        return;
+    }
+    let Ok(target) = player_q.single() else { return; };
+    for e in &ui_q {
+        commands.entity(e).despawn();
+    }
+
+    let font = font.0.clone();
+    let cursed = choice.pool == RewardPool::Cursed;
+    let can_reroll = target.player.health.0 > choice.reroll_cost();
+    let title = if cursed { "CURSED CHEST — TAKE ONE IF YOU DARE" } else { "ROOM CLEARED — CHOOSE AN UPGRADE" };
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(24.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+            ZIndex(250),
+            UpgradeChoiceUI,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new(title),
+                TextFont { font: font.clone(), font_size: 30.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+
+            root.spawn(Node { column_gap: Val::Px(20.0), ..default() })
+                .with_children(|row| {
+                    for (i, &id) in choice.offers.iter().enumerate() {
+                        let Some(behavior) = registry.get(id) else { continue; };
+                        let rarity = behavior.rarity();
+                        row.spawn((
+                            Button,
+                            ChoiceButton::Pick(i),
+                            Node {
+                                width: Val::Px(230.0),
+                                min_height: Val::Px(300.0),
+                                padding: UiRect::all(Val::Px(16.0)),
+                                flex_direction: FlexDirection::Column,
+                                align_items: AlignItems::Center,
+                                row_gap: Val::Px(10.0),
+                                border: UiRect::all(Val::Px(2.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgba(0.05, 0.05, 0.14, 0.97)),
+                            BorderColor(rarity.color()),
+                            BorderRadius::all(Val::Px(8.0)),
+                        ))
+                        .with_children(|card| {
+                            card.spawn((
+                                Text::new(format!("[{}]", i + 1)),
+                                TextFont { font: font.clone(), font_size: 14.0, ..default() },
+                                TextColor(Color::srgba(1.0, 1.0, 1.0, 0.5)),
+                            ));
+                            card.spawn((
+                                ImageNode::new(registry.icon(id)).with_color(behavior.tint()),
+                                Node { width: Val::Px(64.0), height: Val::Px(64.0), ..default() },
+                            ));
+                            card.spawn((
+                                Text::new(behavior.name()),
+                                TextFont { font: font.clone(), font_size: 22.0, ..default() },
+                                TextColor(Color::WHITE),
+                            ));
+                            card.spawn((
+                                Text::new(rarity.label()),
+                                TextFont { font: font.clone(), font_size: 14.0, ..default() },
+                                TextColor(rarity.color()),
+                            ));
+                            card.spawn((
+                                Text::new(behavior.description()),
+                                TextFont { font: font.clone(), font_size: 15.0, ..default() },
+                                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                                TextLayout::new_with_justify(JustifyText::Center),
+                            ));
+                            card.spawn((
+                                Text::new(behavior.preview(&target)),
+                                TextFont { font: font.clone(), font_size: 15.0, ..default() },
+                                TextColor(Color::srgb(1.0, 1.0, 0.4)),
+                                TextLayout::new_with_justify(JustifyText::Center),
+                            ));
+                        });
+                    }
+                });
+
+            let (label, bg, button) = if cursed {
+                ("[X] Leave it".to_string(), Color::srgba(0.25, 0.1, 0.3, 0.9), ChoiceButton::Decline)
+            } else if can_reroll {
+                (format!("[R] Reroll  (-{:.0} HP)", choice.reroll_cost()), Color::srgba(0.38, 0.2, 0.08, 0.9), ChoiceButton::Reroll)
+            } else {
+                ("Not enough HP to reroll".to_string(), Color::srgba(0.2, 0.2, 0.2, 0.9), ChoiceButton::Reroll)
+            };
+            root.spawn((
+                Button,
+                button,
+                Node {
+                    width: Val::Px(280.0),
+                    height: Val::Px(48.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(bg),
+                BorderColor(Color::srgba(1.0, 1.0, 1.0, 0.25)),
+                BorderRadius::all(Val::Px(6.0)),
+            ))
+            .with_children(|b| {
+                b.spawn((
+                    Text::new(label),
+                    TextFont { font: font.clone(), font_size: 20.0, ..default() },
+                    TextColor(Color::WHITE),
+                ));
+            });
+        });
+}
+
+/// Leaving Playing with the screen still up drops it without applying anything.
+pub fn close_on_exit(mut commands: Commands, ui_q: Query<Entity, With<UpgradeChoiceUI>>) {
+    commands.remove_resource::<UpgradeChoice>();
+    for e in &ui_q {
+        commands.entity(e).despawn();
+    }
+}
@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+
+use super::RewardFont;
+use super::choice::OfferUpgradeEvent;
+use super::RewardPool;
+use crate::collidable::{Collidable, Collider};
+use crate::player::{Player, aabb_overlap};
+use crate::stats::{Stat, StatSheet};
+use crate::room::{LevelState, RoomVec};
+use crate::{GameEntity, TILE_SIZE, Z_ENTITIES, window};
+
+/// Chance a cleared room leaves a cursed chest behind.
+pub const CURSED_CHEST_CHANCE: f32 = 0.3;
+/// Purple wash on the cursed chest and every cursed reward icon.
+pub const CURSED_TINT: Color = Color::srgb(0.7, 0.3, 0.9);
+/// A cracked window starts on this much of its 50 HP.
+const CRACKED_WINDOW_HP: f32 = 8.0;
+const CRACKED_WINDOW_TINT: Color = Color::srgb(0.85, 0.8, 0.7);
+
+/// Opens onto the cursed upgrade pool. Press E next to it.
+#[derive(Component)]
+pub struct CursedChest;
+
+pub fn spawn_cursed_chest(commands: &mut Commands, image: Handle<Image>, pos: Vec2) {
+    let mut sprite = Sprite::from_image(image);
+    sprite.color = CURSED_TINT;
+    commands.spawn((
+        sprite,
+        Transform::from_xyz(pos.x, pos.y, Z_ENTITIES),
+        CursedChest,
+        Collidable,
+        Collider { half_extents: Vec2::splat(TILE_SIZE * 0.5) },
+        GameEntity,
+    ));
+}
+
+/// Press E next to a cursed chest to open the cursed choice screen.
+pub fn open_cursed_chest(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    player_q: Query<&Transform, With<Player>>,
+    chest_q: Query<(Entity, &Transform), With<CursedChest>>,
+    font: Res<RewardFont>,
+    mut offers: EventWriter<OfferUpgradeEvent>,
+) {
+    if !input.just_pressed(KeyCode::KeyE) { return; }
+    let Ok(player_tf) = player_q.single() else { return };
+    let pp = player_tf.translation;
+    let interact_half = Vec2::splat(TILE_SIZE * 1.5);
+    let chest_half = Vec2::splat(TILE_SIZE * 0.5);
+
+    for (entity, chest_tf) in &chest_q {
+        let cp = chest_tf.translation;
+        if aabb_overlap(pp.x, pp.y, interact_half, cp.x, cp.y, chest_half) {
+            commands.entity(entity).despawn();
+            super::spawn_popup(&mut commands, &font, cp.truncate(), "Cursed!");
+            offers.write(OfferUpgradeEvent { pool: RewardPool::Cursed, picks: 1 });
+            break;
+        }
+    }
+}
+
+/// With the glass curse, the windows of each room crack as the player
+/// walks in.
+pub fn crack_windows_on_entry(
+    lvlstate: Res<LevelState>,
+    rooms: Res<RoomVec>,
+    player_q: Query<&StatSheet, With<Player>>,
+    mut windows: Query<(&Transform, &mut window::Health, &mut Sprite, &window::GlassState), With<window::Window>>,
+    mut last_room: Local<Option<usize>>,
+) {
+    let idx = match *lvlstate {
+        LevelState::EnteredRoom(i) | LevelState::InRoom(i, _) => i,
+        LevelState::NotRoom => {
+            *last_room = None;
+            return;
+        }
+    };
+    if *last_room == Some(idx) { return; }
+    *last_room = Some(idx);
+
+    let Ok(sheet) = player_q.single() else { return; };
+    let Some(room) = rooms.0.get(idx) else { return; };
+    if sheet.get(Stat::CrackedWindows) <= 0.0 || room.cleared { return; }
+
+    for (tf, mut health, mut sprite, state) in &mut windows {
+        if *state != window::GlassState::Intact || !room.bounds_check(tf.translation.truncate()) {
+            continue;
+        }
+        health.0 = health.0.min(CRACKED_WINDOW_HP);
+        sprite.color = CRACKED_WINDOW_TINT;
+    }
+}
@@ -1,8 +1,37 @@
-use crate::weapons::Weapon;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem, Rarity};
+use crate::stats::{ModOp, ModSource, Stat};
 
-pub const NAME: &str = "Damage Up";
-pub const ASSET: &str = "rewards/DamageUp.png";
+pub const ID: RewardId = RewardId("damage_up");
+const BONUS: ModOp = ModOp::Add(0.2);
 
-pub fn apply(weapon: &mut Weapon) {
-    weapon.damage += 10.0;
+pub struct DamageUp;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(DamageUp);
+}
+
+impl RewardBehavior for DamageUp {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Damage Up" }
+
+    fn description(&self) -> &'static str { "Every weapon deals +20% damage." }
+
+    fn icon(&self) -> &'static str { "rewards/DamageUp.png" }
+
+    fn rarity(&self) -> Rarity { Rarity::Rare }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        let damage = target.inventory.current().damage;
+        let s = &target.stats;
+        format!(
+            "Damage {:.0} -> {:.0}",
+            damage * s.get(Stat::WeaponDamage),
+            damage * s.preview(Stat::WeaponDamage, BONUS),
+        )
+    }
+
+    fn apply(&self, target: &mut RewardTargetItem) {
+        target.stats.add(Stat::WeaponDamage, BONUS, ModSource::Reward(ID));
+    }
 }
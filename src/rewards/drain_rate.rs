@@ -1,9 +1,36 @@
-use crate::player::AirTank;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem, Stacking};
+use crate::stats::{ModOp, ModSource, Stat};
 
-pub const NAME: &str = "Slower Air Drain";
-pub const ASSET: &str = "rewards/DrainRate.png";
+pub const ID: RewardId = RewardId("drain_rate");
+const DRAIN: ModOp = ModOp::Mul(0.8);
 
-pub fn apply(tank: &mut AirTank) {
-    // 20% reduction per pickup, minimum 0.2 units/sec
-    tank.drain_rate = (tank.drain_rate * 0.8).max(0.2);
+pub struct DrainRate;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(DrainRate);
+}
+
+impl RewardBehavior for DrainRate {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Slower Air Drain" }
+
+    fn description(&self) -> &'static str { "Oxygen drains 20% slower." }
+
+    fn icon(&self) -> &'static str { "rewards/DrainRate.png" }
+
+    fn stacking(&self) -> Stacking { Stacking::Max(7) }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        format!(
+            "Air drain {:.2}/s -> {:.2}/s",
+            target.stats.get(Stat::AirDrain),
+            target.stats.preview(Stat::AirDrain, DRAIN),
+        )
+    }
+
+    fn apply(&self, target: &mut RewardTargetItem) {
+        // 20% reduction per pickup; the stat itself never drops below 0.2/s
+        target.stats.add(Stat::AirDrain, DRAIN, ModSource::Reward(ID));
+    }
 }
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem, Rarity, Stacking};
+use super::cursed::CURSED_TINT;
+use crate::stats::{ModOp, ModSource, Stat};
+
+pub const ID: RewardId = RewardId("glass_cannon");
+const BONUS: ModOp = ModOp::Mul(1.5);
+
+pub struct GlassCannon;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(GlassCannon);
+}
+
+impl RewardBehavior for GlassCannon {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Glass Cannon" }
+
+    fn description(&self) -> &'static str { "+50% damage on every weapon, but windows in every room start cracked." }
+
+    fn icon(&self) -> &'static str { "rewards/DamageUp.png" }
+
+    fn tint(&self) -> Color { CURSED_TINT }
+
+    fn rarity(&self) -> Rarity { Rarity::Cursed }
+
+    fn stacking(&self) -> Stacking { Stacking::Max(1) }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        let damage = target.inventory.current().damage;
+        let s = &target.stats;
+        format!(
+            "Damage {:.0} -> {:.0}",
+            damage * s.get(Stat::WeaponDamage),
+            damage * s.preview(Stat::WeaponDamage, BONUS),
+        )
+    }
+
+    fn apply(&self, target: &mut RewardTargetItem) {
+        target
+            .stats
+            .add(Stat::WeaponDamage, BONUS, ModSource::Reward(ID))
+            .add(Stat::CrackedWindows, ModOp::Add(1.0), ModSource::Reward(ID));
+    }
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem, Rarity, Stacking};
+use super::cursed::CURSED_TINT;
+use crate::stats::{ModOp, ModSource, Stat};
+
+pub const ID: RewardId = RewardId("greed");
+const DROPS: ModOp = ModOp::Mul(2.0);
+const REAPER_DELAY: ModOp = ModOp::Mul(0.7);
+
+pub struct Greed;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(Greed);
+}
+
+impl RewardBehavior for Greed {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Greed" }
+
+    fn description(&self) -> &'static str { "Cleared rooms and elites give two rewards, but the reaper arrives 30% sooner." }
+
+    fn icon(&self) -> &'static str { "rewards/HeartBox.png" }
+
+    fn tint(&self) -> Color { CURSED_TINT }
+
+    fn rarity(&self) -> Rarity { Rarity::Cursed }
+
+    fn stacking(&self) -> Stacking { Stacking::Max(1) }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        let s = &target.stats;
+        format!(
+            "Reward drops {:.0} -> {:.0}\nReaper delay {:.0}% -> {:.0}%",
+            s.get(Stat::RewardDrops), s.preview(Stat::RewardDrops, DROPS),
+            s.get(Stat::ReaperDelay) * 100.0, s.preview(Stat::ReaperDelay, REAPER_DELAY) * 100.0,
+        )
+    }
+
+    fn apply(&self, target: &mut RewardTargetItem) {
+        target.stats
+            .add(Stat::RewardDrops, DROPS, ModSource::Reward(ID))
+            .add(Stat::ReaperDelay, REAPER_DELAY, ModSource::Reward(ID));
+    }
+}
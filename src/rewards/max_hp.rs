@@ -1,11 +1,32 @@
 use rand::random_range;
-use crate::player::{Health, MaxHealth};
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem};
+use crate::stats::{ModOp, ModSource, Stat};
 
-pub const NAME: &str = "Max HP Up";
-pub const ASSET: &str = "rewards/HeartBox.png";
+pub const ID: RewardId = RewardId("max_hp");
 
-pub fn apply(hp: &mut Health, maxhp: &mut MaxHealth) {
-    let increase = random_range(5..=20) as f32;
-    maxhp.0 += increase;
-    hp.0 += increase;
+pub struct MaxHp;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(MaxHp);
+}
+
+impl RewardBehavior for MaxHp {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Max HP Up" }
+
+    fn description(&self) -> &'static str { "Raises max HP by 5-20 and heals the same amount." }
+
+    fn icon(&self) -> &'static str { "rewards/HeartBox.png" }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        let max = target.stats.get(Stat::MaxHealth);
+        format!("Max HP {:.0} -> {:.0}-{:.0}", max, max + 5.0, max + 20.0)
+    }
+
+    fn apply(&self, target: &mut RewardTargetItem) {
+        let increase = random_range(5..=20) as f32;
+        target.stats.add(Stat::MaxHealth, ModOp::Add(increase), ModSource::Reward(ID));
+        target.player.health.0 += increase;
+    }
 }
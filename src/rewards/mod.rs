@@ -1,21 +1,33 @@
+pub mod adrenaline;
 pub mod air_tank;
 pub mod armor;
 pub mod atk_speed;
+pub mod broom_parry;
+pub mod broom_power;
+pub mod choice;
+pub mod cursed;
 pub mod damage_up;
 pub mod drain_rate;
+pub mod glass_cannon;
+pub mod greed;
 pub mod max_hp;
 pub mod move_speed;
 pub mod piercing;
 pub mod regen;
+pub mod ricochet;
+pub mod sets;
 pub mod shield;
+pub mod synergy;
 pub mod vacuum_res;
 
+use bevy::ecs::query::QueryData;
 use bevy::prelude::*;
-use rand::random_range;
+use rand::seq::IndexedRandom;
+use std::collections::HashMap;
 use crate::{TILE_SIZE, GameEntity};
 use crate::Player;
-use crate::player::{Health, MaxHealth, MoveSpeed, Armor, AirTank, Regen, Shield, ThrusterFuel, aabb_overlap};
-use crate::fluiddynamics::PulledByFluid;
+use crate::player::aabb_overlap;
+use crate::stats::{PlayerStats, StatSheet};
 use crate::weapons::WeaponInventory;
 
 // Popup 
@@ -45,33 +57,257 @@ pub fn tick_reward_popups(
     }
 }
 
-// Reward component & asset resource 
+// Reward definitions & registry
 
-#[derive(Component)]
-pub struct Reward(pub usize);
+/// Stable identifier for a reward kind. Also what gets saved between stations.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct RewardId(pub &'static str);
 
-#[allow(dead_code)]
-#[derive(Resource)]
-pub struct RewardRes {
-    max_hp:     Handle<Image>,
-    atk_spd:    Handle<Image>,
-    mov_spd:    Handle<Image>,
-    armor:      Handle<Image>,
-    air_tank:   Handle<Image>,
-    drain_rate: Handle<Image>,
-    vacuum_res: Handle<Image>,
-    regen:      Handle<Image>,
-    piercing:   Handle<Image>,
-    damage_up:  Handle<Image>,
-    shield_burst: Handle<Image>,
+/// How often a reward turns up. Legendary rewards roll least often, a
+/// little more so for a player the director thinks is struggling. Cursed
+/// rewards never roll normally; only a cursed chest offers them.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Rarity {
+    Common,
+    Rare,
+    Legendary,
+    Cursed,
+}
+
+impl Rarity {
+    pub fn label(self) -> &'static str {
+        match self {
+            Rarity::Common => "Common",
+            Rarity::Rare => "Rare",
+            Rarity::Legendary => "Legendary",
+            Rarity::Cursed => "Cursed",
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            Rarity::Common => Color::srgb(0.8, 0.8, 0.8),
+            Rarity::Rare => Color::srgb(0.4, 0.6, 1.0),
+            Rarity::Legendary => Color::srgb(1.0, 0.6, 0.15),
+            Rarity::Cursed => cursed::CURSED_TINT,
+        }
+    }
+
+    /// Relative roll weight. `quality` is the director's reward quality (0..1).
+    fn weight(self, quality: f32) -> f32 {
+        match self {
+            Rarity::Common => 6.0,
+            Rarity::Rare => 3.0,
+            Rarity::Legendary => 0.5 + 1.5 * quality,
+            Rarity::Cursed => 1.0,
+        }
+    }
+}
+
+/// Which set of rewards a roll draws from.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub enum RewardPool {
+    #[default]
+    Standard,
+    Cursed,
+}
+
+impl RewardPool {
+    fn contains(self, rarity: Rarity) -> bool {
+        (rarity == Rarity::Cursed) == (self == RewardPool::Cursed)
+    }
+}
+
+/// How many times a reward may be picked up in one run.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Stacking {
+    Unlimited,
+    /// Stops rolling once the player has this many.
+    Max(u32),
+}
+
+/// Everything on the player a reward is allowed to change. Stat changes go
+/// on `stats` as modifiers; `player` is only for topping up pools (health,
+/// air, shield) and is re-derived from the sheet after every grant.
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct RewardTarget {
+    pub stats: &'static mut StatSheet,
+    pub player: PlayerStats,
+    pub inventory: &'static mut WeaponInventory,
+}
+
+/// One kind of upgrade. Implement it in the reward's own module, give the
+/// module a `register` function, and add that to `RewardPlugin::build`.
+pub trait RewardBehavior: Send + Sync + 'static {
+    fn id(&self) -> RewardId;
+    fn name(&self) -> &'static str;
+    fn description(&self) -> &'static str;
+    /// Crate sprite, relative to the assets folder.
+    fn icon(&self) -> &'static str;
+    /// Tint for rewards that borrow another reward's crate art.
+    fn tint(&self) -> Color {
+        Color::WHITE
+    }
+    fn rarity(&self) -> Rarity {
+        Rarity::Common
+    }
+    fn stacking(&self) -> Stacking {
+        Stacking::Unlimited
+    }
+    /// What picking this would change, e.g. "Armor 20 -> 40". Shown on the
+    /// upgrade choice cards.
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String;
+    fn apply(&self, target: &mut RewardTargetItem);
+}
+
+struct RewardEntry {
+    behavior: Box<dyn RewardBehavior>,
+    icon: Handle<Image>,
 }
 
+/// All known reward kinds, looked up by `RewardId`.
+#[derive(Resource, Default)]
+pub struct RewardRegistry {
+    entries: Vec<RewardEntry>,
+}
+
+impl RewardRegistry {
+    pub fn register(&mut self, behavior: impl RewardBehavior) -> &mut Self {
+        self.entries.push(RewardEntry { behavior: Box::new(behavior), icon: Handle::default() });
+        self
+    }
+
+    pub fn get(&self, id: RewardId) -> Option<&dyn RewardBehavior> {
+        self.entries.iter().find(|e| e.behavior.id() == id).map(|e| e.behavior.as_ref())
+    }
+
+    pub fn icon(&self, id: RewardId) -> Handle<Image> {
+        self.entries.iter().find(|e| e.behavior.id() == id).map_or_else(Handle::default, |e| e.icon.clone())
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = RewardId> + '_ {
+        self.entries.iter().map(|e| e.behavior.id())
+    }
+
+    pub fn name(&self, id: RewardId) -> &'static str {
+        self.get(id).map_or("???", |b| b.name())
+    }
+
+    /// Applies reward `id` to the player, counts the stack and re-derives
+    /// their stats. Every way of getting a reward goes through here.
+    pub fn grant(&self, id: RewardId, target: &mut RewardTargetItem, stacks: &mut RewardStacks) -> Option<&dyn RewardBehavior> {
+        let behavior = self.get(id)?;
+        behavior.apply(target);
+        *stacks.0.entry(id).or_default() += 1;
+        target.player.refresh(&target.stats);
+        Some(behavior)
+    }
+
+    /// Whether the player can still take another of this reward.
+    pub fn available(&self, id: RewardId, stacks: &RewardStacks) -> bool {
+        match self.get(id).map(|b| b.stacking()) {
+            Some(Stacking::Unlimited) => true,
+            Some(Stacking::Max(n)) => stacks.count(id) < n,
+            None => false,
+        }
+    }
+
+    /// Rewards in `pool` the player hasn't maxed out.
+    fn pool(&self, stacks: &RewardStacks, pool: RewardPool) -> Vec<RewardId> {
+        self.ids()
+            .filter(|&id| self.available(id, stacks))
+            .filter(|&id| self.get(id).is_some_and(|b| pool.contains(b.rarity())))
+            .collect()
+    }
+
+    /// Weighted pick among the standard rewards the player hasn't maxed out.
+    pub fn roll(&self, stacks: &RewardStacks, quality: f32) -> Option<RewardId> {
+        self.pool(stacks, RewardPool::Standard)
+            .choose_weighted(&mut rand::rng(), |&id| {
+                self.get(id).map_or(0.0, |b| b.rarity().weight(quality))
+            })
+            .ok()
+            .copied()
+    }
+
+    /// Up to `count` different rewards from `pool`, each rolled like `roll`.
+    pub fn roll_many(&self, stacks: &RewardStacks, quality: f32, count: usize, pool: RewardPool) -> Vec<RewardId> {
+        self.pool(stacks, pool)
+            .choose_multiple_weighted(&mut rand::rng(), count, |&id| {
+                self.get(id).map_or(0.0, |b| b.rarity().weight(quality))
+            })
+            .map(|picked| picked.copied().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// How many of each reward the player has picked up this run.
+#[derive(Component, Default, Clone)]
+pub struct RewardStacks(pub HashMap<RewardId, u32>);
+
+impl RewardStacks {
+    pub fn count(&self, id: RewardId) -> u32 {
+        self.0.get(&id).copied().unwrap_or(0)
+    }
+}
+
+#[derive(Component)]
+pub struct Reward(pub RewardId);
+
 
 pub struct RewardPlugin;
 
 impl Plugin for RewardPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, load_crates);
+        // Each reward module registers its own rewards.
+        let mut registry = RewardRegistry::default();
+        for register in [
+            max_hp::register,
+            atk_speed::register,
+            move_speed::register,
+            armor::register,
+            air_tank::register,
+            drain_rate::register,
+            vacuum_res::register,
+            regen::register,
+            piercing::register,
+            damage_up::register,
+            shield::register,
+            ricochet::register,
+            broom_power::register,
+            broom_parry::register,
+            glass_cannon::register,
+            adrenaline::register,
+            greed::register,
+        ] {
+            register(&mut registry);
+        }
+
+        app.insert_resource(registry)
+            .add_event::<choice::OfferUpgradeEvent>()
+            .add_systems(Startup, load_reward_icons)
+            .add_systems(OnExit(crate::GameState::Playing), choice::close_on_exit)
+            .add_systems(
+                Update,
+                (choice::open_upgrade_choice, choice::handle_upgrade_choice, choice::draw_upgrade_choice)
+                    .chain()
+                    .run_if(in_state(crate::GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (
+                    synergy::announce_synergies,
+                    sets::apply_item_sets,
+                    synergy::recharging_barrier,
+                    synergy::volatile_rounds.after(crate::bullet::bullet_collision),
+                    synergy::counter_guard.after(crate::broom::broom_hit_bullets_system),
+                    cursed::crack_windows_on_entry,
+                    cursed::open_cursed_chest
+                        .run_if(not(resource_exists::<crate::pause::IsPaused>)),
+                )
+                    .run_if(in_state(crate::GameState::Playing)),
+            );
     }
 }
 
@@ -86,49 +322,30 @@ pub fn load_reward_font(mut commands: Commands, asset_server: Res<AssetServer>)
     commands.insert_resource(RewardFont(handle));
 }
 
-fn load_crates(mut commands: Commands, asset_server: Res<AssetServer>) {
-    commands.insert_resource(RewardRes {
-        max_hp:       asset_server.load(max_hp::ASSET),
-        atk_spd:      asset_server.load(atk_speed::ASSET),
-        mov_spd:      asset_server.load(move_speed::ASSET),
-        armor:        asset_server.load(armor::ASSET),
-        air_tank:     asset_server.load(air_tank::ASSET),
-        drain_rate:   asset_server.load(drain_rate::ASSET),
-        vacuum_res:   asset_server.load(vacuum_res::ASSET),
-        regen:        asset_server.load(regen::ASSET),
-        piercing:     asset_server.load(piercing::ASSET),
-        damage_up:    asset_server.load(damage_up::ASSET),
-        shield_burst: asset_server.load(shield::ASSET),
-    });
+fn load_reward_icons(asset_server: Res<AssetServer>, mut registry: ResMut<RewardRegistry>) {
+    for entry in registry.entries.iter_mut() {
+        entry.icon = asset_server.load(entry.behavior.icon());
+    }
 }
 
 // Spawn
 
-pub fn spawn_reward(commands: &mut Commands, pos: Vec3, box_sprite: &RewardRes) {
-    let reward_type: usize = random_range(1..=11);
-    let reward_img = match reward_type {
-        1  => box_sprite.max_hp.clone(),
-        2  => box_sprite.atk_spd.clone(),
-        3  => box_sprite.mov_spd.clone(),
-        4  => box_sprite.armor.clone(),
-        5  => box_sprite.air_tank.clone(),
-        6  => box_sprite.drain_rate.clone(),
-        7  => box_sprite.vacuum_res.clone(),
-        8  => box_sprite.regen.clone(),
-        9  => box_sprite.piercing.clone(),
-        10 => box_sprite.damage_up.clone(),
-        11 => box_sprite.shield_burst.clone(),
-        _  => panic!("reward image out of range"),
-    };
+/// Drops a random reward crate the player can still use at `pos`.
+pub fn spawn_reward(commands: &mut Commands, pos: Vec3, registry: &RewardRegistry, stacks: &RewardStacks, quality: f32) {
+    let Some(id) = registry.roll(stacks, quality) else { return; };
+    let Some(behavior) = registry.get(id) else { return; };
+
+    let mut sprite = Sprite::from_image(registry.icon(id));
+    sprite.color = behavior.tint();
 
     commands.spawn((
-        Sprite::from_image(reward_img),
+        sprite,
         Transform {
             translation: pos,
             scale: Vec3::new(0.75, 0.75, 1.0),
             ..Default::default()
         },
-        Reward(reward_type),
+        Reward(id),
         GameEntity,
     ));
 }
@@ -137,77 +354,42 @@ pub fn spawn_reward(commands: &mut Commands, pos: Vec3, box_sprite: &RewardRes)
 
 pub fn player_pickup_reward(
     mut commands: Commands,
-    mut player_query: Query<(
-        Entity, &Transform,
-        &mut Health, &mut MaxHealth, &mut MoveSpeed, &mut Armor, &mut AirTank,
-        &mut Regen, &mut Shield, &mut PulledByFluid, &mut ThrusterFuel,
-    ), With<Player>>,
+    mut player_query: Query<(&Transform, &mut RewardStacks, RewardTarget), With<Player>>,
     reward_query: Query<(Entity, &Transform, &Reward)>,
-    mut player_weapon_q: Query<&mut WeaponInventory, With<Player>>,
+    registry: Res<RewardRegistry>,
     font: Res<RewardFont>,
 ) {
-    let Ok((
-        _player_entity, player_tf,
-        mut hp, mut maxhp, mut movspd, mut arm, mut tank,
-        mut reg, mut shld, mut pull, mut fuel,
-    )) = player_query.single_mut() else {
+    let Ok((player_tf, mut stacks, mut target)) = player_query.single_mut() else {
         return;
     };
     let player_pos = player_tf.translation;
     let player_half = Vec2::splat(TILE_SIZE * 0.5);
 
-    for (reward_entity, reward_tf, reward_type) in &reward_query {
+    for (reward_entity, reward_tf, reward) in &reward_query {
         let reward_pos = reward_tf.translation;
         let reward_half = Vec2::splat(TILE_SIZE * 0.5);
         if !aabb_overlap(player_pos.x, player_pos.y, player_half, reward_pos.x, reward_pos.y, reward_half) {
             continue;
         }
-
-        if let Ok(mut inv) = player_weapon_q.single_mut() {
-            let weapon = inv.current_mut();
-            match reward_type.0 {
-                1  => max_hp::apply(&mut hp, &mut maxhp),
-                2  => atk_speed::apply(weapon),
-                3  => move_speed::apply(&mut movspd, &mut fuel),
-                4  => armor::apply(&mut arm),
-                5  => air_tank::apply(&mut tank),
-                6  => drain_rate::apply(&mut tank),
-                7  => vacuum_res::apply(&mut pull),
-                8  => regen::apply(&mut reg),
-                9  => piercing::apply(weapon),
-                10 => damage_up::apply(weapon),
-                11 => shield::apply(&mut shld),
-                _  => panic!("Reward Type Not Found"),
-            }
-        }
+        let Some(behavior) = registry.grant(reward.0, &mut target, &mut stacks) else {
+            warn!("No reward registered for {:?}", reward.0);
+            continue;
+        };
 
         if let Ok(mut ec) = commands.get_entity(reward_entity) { ec.despawn(); }
 
-        let name = reward_name(reward_type.0);
-        commands.spawn((
-            Text2d::new(name),
-            TextFont { font: font.0.clone(), font_size: 20.0, ..default() },
-            TextColor(Color::srgba(1.0, 1.0, 0.3, 1.0)),
-            Transform::from_translation(Vec3::new(reward_pos.x, reward_pos.y + TILE_SIZE, 10.0)),
-            RewardPopup { timer: Timer::from_seconds(1.5, TimerMode::Once) },
-            GameEntity,
-        ));
-    }
-}
-
-fn reward_name(id: usize) -> &'static str {
-    match id {
-        1  => max_hp::NAME,
-        2  => atk_speed::NAME,
-        3  => move_speed::NAME,
-        4  => armor::NAME,
-        5  => air_tank::NAME,
-        6  => drain_rate::NAME,
-        7  => vacuum_res::NAME,
-        8  => regen::NAME,
-        9  => piercing::NAME,
-        10 => damage_up::NAME,
-        11 => shield::NAME,
-        _  => "???",
+        spawn_popup(&mut commands, &font, reward_pos.truncate(), behavior.name());
     }
 }
+
+/// Floating text that rises and fades above `pos`.
+pub fn spawn_popup(commands: &mut Commands, font: &RewardFont, pos: Vec2, text: impl Into<String>) {
+    commands.spawn((
+        Text2d::new(text),
+        TextFont { font: font.0.clone(), font_size: 20.0, ..default() },
+        TextColor(Color::srgba(1.0, 1.0, 0.3, 1.0)),
+        Transform::from_translation(Vec3::new(pos.x, pos.y + TILE_SIZE, 10.0)),
+        RewardPopup { timer: Timer::from_seconds(1.5, TimerMode::Once) },
+        GameEntity,
+    ));
+}
@@ -1,13 +1,43 @@
-use crate::player::{MoveSpeed, ThrusterFuel};
-
-pub const NAME: &str = "Move Speed Up";
-pub const ASSET: &str = "rewards/MoveSpdBox.png";
-
-pub fn apply(movspd: &mut MoveSpeed, fuel: &mut ThrusterFuel) {
-    movspd.0 = (movspd.0 + 20.0).min(600.0);
-    // Each Speed Up also extends the thruster fuel tank (max 10 charges)
-    let new_max = (fuel.max + 3.0).min(10.0);
-    let added = new_max - fuel.max;
-    fuel.max = new_max;
-    fuel.current = (fuel.current + added).min(fuel.max);
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem, Stacking};
+use crate::stats::{ModOp, ModSource, Stat};
+
+pub const ID: RewardId = RewardId("move_speed");
+const SPEED: ModOp = ModOp::Add(20.0);
+const FUEL: ModOp = ModOp::Add(3.0);
+
+pub struct MoveSpeedUp;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(MoveSpeedUp);
+}
+
+impl RewardBehavior for MoveSpeedUp {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Move Speed Up" }
+
+    fn description(&self) -> &'static str { "Move faster and carry more thruster fuel." }
+
+    fn icon(&self) -> &'static str { "rewards/MoveSpdBox.png" }
+
+    /// Thirty pickups is the old +600 speed cap.
+    fn stacking(&self) -> Stacking { Stacking::Max(30) }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        let stats = &target.stats;
+        format!(
+            "Speed {:.0} -> {:.0}, fuel {:.0} -> {:.0}",
+            stats.get(Stat::MoveSpeed), stats.preview(Stat::MoveSpeed, SPEED),
+            stats.get(Stat::FuelCapacity), stats.preview(Stat::FuelCapacity, FUEL),
+        )
+    }
+
+    fn apply(&self, target: &mut RewardTargetItem) {
+        // Each Speed Up also extends the thruster fuel tank (max 10 charges)
+        let added = target.stats.preview(Stat::FuelCapacity, FUEL) - target.stats.get(Stat::FuelCapacity);
+        target.stats
+            .add(Stat::MoveSpeed, SPEED, ModSource::Reward(ID))
+            .add(Stat::FuelCapacity, FUEL, ModSource::Reward(ID));
+        target.player.fuel.current += added;
+    }
 }
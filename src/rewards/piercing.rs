@@ -1,11 +1,36 @@
-use crate::weapons::Weapon;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem, Rarity};
+use crate::stats::{ModOp, ModSource, Stat};
 
-pub const NAME: &str = "Piercing Rounds";
-pub const ASSET: &str = "rewards/Piercing.png";
+pub const ID: RewardId = RewardId("piercing");
 
-/// Each pickup adds one raw piercing stack.
-/// Bullets use `Weapon::effective_pierce_count()` to determine the actual pierce level,
-/// which applies diminishing returns after 4 pickups (2 pickups per +1 pierce level).
-pub fn apply(weapon: &mut Weapon) {
-    weapon.piercing_pickups += 1;
+pub struct PiercingRounds;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(PiercingRounds);
+}
+
+impl RewardBehavior for PiercingRounds {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Piercing Rounds" }
+
+    fn description(&self) -> &'static str { "Every weapon's shots pass through more enemies." }
+
+    fn icon(&self) -> &'static str { "rewards/Piercing.png" }
+
+    fn rarity(&self) -> Rarity { Rarity::Rare }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        let weapon = target.inventory.current().scaled(target.stats);
+        let mut after = weapon.clone();
+        after.piercing_pickups += 1;
+        format!("Pierce {} -> {}", weapon.effective_pierce_count(), after.effective_pierce_count())
+    }
+
+    /// Each pickup adds one raw piercing stack to the stat sheet.
+    /// Bullets use `Weapon::effective_pierce_count()` to determine the actual pierce level,
+    /// which applies diminishing returns after 4 pickups (2 pickups per +1 pierce level).
+    fn apply(&self, target: &mut RewardTargetItem) {
+        target.stats.add(Stat::Pierce, ModOp::Add(1.0), ModSource::Reward(ID));
+    }
 }
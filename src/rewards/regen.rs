@@ -1,8 +1,31 @@
-use crate::player::Regen;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem, Rarity};
+use crate::stats::{ModOp, ModSource, Stat};
 
-pub const NAME: &str = "Regen";
-pub const ASSET: &str = "rewards/HealthRegen.png";
+pub const ID: RewardId = RewardId("regen");
+const REGEN: ModOp = ModOp::Add(2.0);
 
-pub fn apply(regen: &mut Regen) {
-    regen.0 += 2.0;
+pub struct RegenUp;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(RegenUp);
+}
+
+impl RewardBehavior for RegenUp {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Regen" }
+
+    fn description(&self) -> &'static str { "Regenerate 2 more HP per second." }
+
+    fn icon(&self) -> &'static str { "rewards/HealthRegen.png" }
+
+    fn rarity(&self) -> Rarity { Rarity::Rare }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        format!("Regen {:.0}/s -> {:.0}/s", target.stats.get(Stat::Regen), target.stats.preview(Stat::Regen, REGEN))
+    }
+
+    fn apply(&self, target: &mut RewardTargetItem) {
+        target.stats.add(Stat::Regen, REGEN, ModSource::Reward(ID));
+    }
 }
@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem, Rarity, Stacking};
+
+pub const ID: RewardId = RewardId("ricochet");
+const TINT: Color = Color::srgb(0.6, 1.0, 0.7);
+
+pub struct RicochetRounds;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(RicochetRounds);
+}
+
+impl RewardBehavior for RicochetRounds {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Ricochet Rounds" }
+
+    fn description(&self) -> &'static str { "Current weapon's shots bounce off one more wall." }
+
+    fn icon(&self) -> &'static str { "rewards/Ricochet.png" }
+
+    fn tint(&self) -> Color { TINT }
+
+    fn rarity(&self) -> Rarity { Rarity::Legendary }
+
+    fn stacking(&self) -> Stacking { Stacking::Max(3) }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        let bounces = target.inventory.current().bounce_pickups;
+        format!("Bounces {} -> {}", bounces, bounces + 1)
+    }
+
+    /// Each pickup lets the weapon's bullets bounce off one more wall.
+    fn apply(&self, target: &mut RewardTargetItem) {
+        target.inventory.current_mut().bounce_pickups += 1;
+    }
+}
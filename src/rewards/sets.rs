@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+
+use super::{RewardFont, RewardId, RewardRegistry, RewardStacks, spawn_popup};
+use super::{air_tank, armor, broom_parry, broom_power, drain_rate, max_hp, move_speed, regen, shield, vacuum_res};
+use crate::Player;
+use crate::stats::{ModOp, ModSource, Stat, StatSheet};
+
+/// A themed group of rewards. Each distinct piece held counts toward the
+/// set, and every bonus whose piece count is reached stays on the stat
+/// sheet for as long as the pieces are held.
+pub struct ItemSet {
+    pub name: &'static str,
+    pub pieces: &'static [RewardId],
+    pub bonuses: &'static [SetBonus],
+}
+
+pub struct SetBonus {
+    pub pieces: u32,
+    pub stat: Stat,
+    pub op: ModOp,
+    pub description: &'static str,
+}
+
+impl ItemSet {
+    pub fn held(&self, stacks: &RewardStacks) -> u32 {
+        self.pieces.iter().filter(|&&id| stacks.count(id) > 0).count() as u32
+    }
+}
+
+pub const JANITORS_KIT: ItemSet = ItemSet {
+    name: "Janitor's Kit",
+    pieces: &[broom_power::ID, broom_parry::ID, vacuum_res::ID],
+    bonuses: &[
+        SetBonus { pieces: 2, stat: Stat::BroomPower, op: ModOp::Mul(1.2), description: "+20% broom power" },
+        SetBonus { pieces: 3, stat: Stat::ParryWindow, op: ModOp::Add(0.06), description: "+0.06s parry window" },
+    ],
+};
+
+pub const LIFE_SUPPORT: ItemSet = ItemSet {
+    name: "Life Support",
+    pieces: &[air_tank::ID, drain_rate::ID, regen::ID, max_hp::ID],
+    bonuses: &[
+        SetBonus { pieces: 2, stat: Stat::AirCapacity, op: ModOp::Mul(1.25), description: "+25% air capacity" },
+        SetBonus { pieces: 4, stat: Stat::Regen, op: ModOp::Add(1.0), description: "+1 HP/s regen" },
+    ],
+};
+
+pub const HAZARD_SUIT: ItemSet = ItemSet {
+    name: "Hazard Suit",
+    pieces: &[armor::ID, shield::ID, move_speed::ID],
+    bonuses: &[
+        SetBonus { pieces: 2, stat: Stat::MoveSpeed, op: ModOp::Mul(1.1), description: "+10% move speed" },
+        SetBonus { pieces: 3, stat: Stat::ShieldCharges, op: ModOp::Add(1.0), description: "+1 shield charge" },
+    ],
+};
+
+pub const SETS: &[ItemSet] = &[JANITORS_KIT, LIFE_SUPPORT, HAZARD_SUIT];
+
+/// Re-applies every set's bonuses whenever the player's rewards change, and
+/// announces each newly reached bonus.
+pub fn apply_item_sets(
+    mut commands: Commands,
+    font: Res<RewardFont>,
+    mut player_q: Query<(&Transform, &RewardStacks, &mut StatSheet), (With<Player>, Changed<RewardStacks>)>,
+    mut reached: Local<Vec<(&'static str, u32)>>,
+) {
+    let Ok((tf, stacks, mut sheet)) = player_q.single_mut() else { return; };
+    for set in SETS {
+        let held = set.held(stacks);
+        let source = ModSource::Set(set.name);
+        sheet.remove_source(source);
+        for bonus in set.bonuses.iter().filter(|b| held >= b.pieces) {
+            sheet.add(bonus.stat, bonus.op, source);
+        }
+
+        let before = reached.iter().find(|(n, _)| *n == set.name).map_or(0, |(_, h)| *h);
+        if set.bonuses.iter().any(|b| before < b.pieces && held >= b.pieces) {
+            let pos = tf.translation.truncate() + Vec2::Y * 40.0;
+            spawn_popup(&mut commands, &font, pos, format!("SET: {} ({held})", set.name));
+        }
+        reached.retain(|(n, _)| *n != set.name);
+        reached.push((set.name, held));
+    }
+}
+
+/// The pause-screen panel: every set with the pieces held and the bonuses
+/// reached lit up.
+pub fn spawn_set_tracker(
+    parent: &mut ChildSpawnerCommands,
+    font: Handle<Font>,
+    stacks: Option<&RewardStacks>,
+    registry: &RewardRegistry,
+) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(300.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.14, 0.97)),
+            BorderColor(Color::srgba(0.3, 0.3, 0.6, 0.8)),
+            BorderRadius::all(Val::Px(10.0)),
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new("SETS"),
+                TextFont { font: font.clone(), font_size: 24.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+
+            for set in SETS {
+                let held = stacks.map_or(0, |s| set.held(s));
+                let title_color = if held > 0 { Color::srgb(1.0, 0.85, 0.3) } else { Color::srgb(0.5, 0.5, 0.5) };
+                let pieces = set
+                    .pieces
+                    .iter()
+                    .map(|&id| {
+                        let has = stacks.is_some_and(|s| s.count(id) > 0);
+                        format!("{} {}", if has { "+" } else { "-" }, registry.name(id))
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                panel
+                    .spawn(Node { flex_direction: FlexDirection::Column, row_gap: Val::Px(2.0), ..default() })
+                    .with_children(|entry| {
+                        entry.spawn((
+                            Text::new(format!("{} ({held}/{})", set.name, set.pieces.len())),
+                            TextFont { font: font.clone(), font_size: 18.0, ..default() },
+                            TextColor(title_color),
+                        ));
+                        for bonus in set.bonuses {
+                            let color = if held >= bonus.pieces {
+                                Color::srgb(0.9, 0.9, 0.9)
+                            } else {
+                                Color::srgb(0.4, 0.4, 0.4)
+                            };
+                            entry.spawn((
+                                Text::new(format!("({}) {}", bonus.pieces, bonus.description)),
+                                TextFont { font: font.clone(), font_size: 14.0, ..default() },
+                                TextColor(color),
+                            ));
+                        }
+                        entry.spawn((
+                            Text::new(pieces),
+                            TextFont { font: font.clone(), font_size: 13.0, ..default() },
+                            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                        ));
+                    });
+            }
+        });
+}
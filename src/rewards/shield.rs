@@ -1,9 +1,36 @@
-use crate::player::Shield;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem, Rarity};
+use crate::stats::{ModOp, ModSource, Stat};
 
-pub const NAME: &str = "Shield Charge";
-pub const ASSET: &str = "rewards/Shield.png";
+pub const ID: RewardId = RewardId("shield");
+const CHARGE: ModOp = ModOp::Add(1.0);
 
-pub fn apply(shield: &mut Shield) {
-    shield.max += 1.0;
-    shield.current = (shield.current + 1.0).min(shield.max);
+pub struct ShieldCharge;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(ShieldCharge);
+}
+
+impl RewardBehavior for ShieldCharge {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Shield Charge" }
+
+    fn description(&self) -> &'static str { "One more shield charge. Each blocks a whole hit." }
+
+    fn icon(&self) -> &'static str { "rewards/Shield.png" }
+
+    fn rarity(&self) -> Rarity { Rarity::Rare }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        format!(
+            "Shield charges {:.0} -> {:.0}",
+            target.stats.get(Stat::ShieldCharges),
+            target.stats.preview(Stat::ShieldCharges, CHARGE),
+        )
+    }
+
+    fn apply(&self, target: &mut RewardTargetItem) {
+        target.stats.add(Stat::ShieldCharges, CHARGE, ModSource::Reward(ID));
+        target.player.shield.current += 1.0;
+    }
 }
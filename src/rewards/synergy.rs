@@ -0,0 +1,217 @@
+use bevy::prelude::*;
+
+use super::{RewardFont, RewardId, RewardRegistry, RewardStacks, spawn_popup};
+use super::{broom_parry, damage_up, piercing, regen, shield};
+use crate::Player;
+use crate::broom::BulletDeflectedEvent;
+use crate::bullet::Piercing;
+use crate::explosion::ExplosionEvent;
+use crate::player::{Health, Shield};
+use crate::weapons::BulletDamage;
+use crate::weapons::mods::BulletHitEvent;
+
+/// Seconds without losing HP or shield before the barrier starts recharging.
+const BARRIER_CALM_SECS: f32 = 4.0;
+/// One shield charge comes back this often once recharging.
+const BARRIER_RECHARGE_SECS: f32 = 2.5;
+
+const VOLATILE_RADIUS: f32 = 40.0;
+/// Fraction of the bullet's damage the burst deals around the hit.
+const VOLATILE_DAMAGE_FRAC: f32 = 0.35;
+
+/// A bonus effect that switches on while every reward in `requires` is held.
+pub struct Synergy {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub requires: &'static [RewardId],
+}
+
+impl Synergy {
+    pub fn active(&self, stacks: &RewardStacks) -> bool {
+        self.requires.iter().all(|&id| stacks.count(id) > 0)
+    }
+}
+
+pub const RECHARGING_BARRIER: Synergy = Synergy {
+    name: "Recharging Barrier",
+    description: "Shield charges regenerate when out of combat.",
+    requires: &[shield::ID, regen::ID],
+};
+
+pub const VOLATILE_ROUNDS: Synergy = Synergy {
+    name: "Volatile Rounds",
+    description: "Piercing shots explode on every hit.",
+    requires: &[piercing::ID, damage_up::ID],
+};
+
+pub const COUNTER_GUARD: Synergy = Synergy {
+    name: "Counter Guard",
+    description: "Every parry restores a shield charge.",
+    requires: &[broom_parry::ID, shield::ID],
+};
+
+pub const SYNERGIES: &[Synergy] = &[RECHARGING_BARRIER, VOLATILE_ROUNDS, COUNTER_GUARD];
+
+/// Announces a synergy the moment its last piece is picked up.
+pub fn announce_synergies(
+    mut commands: Commands,
+    font: Res<RewardFont>,
+    player_q: Query<(&Transform, &RewardStacks), (With<Player>, Changed<RewardStacks>)>,
+    mut known: Local<Vec<&'static str>>,
+) {
+    let Ok((tf, stacks)) = player_q.single() else { return; };
+    for synergy in SYNERGIES {
+        let active = synergy.active(stacks);
+        let was = known.contains(&synergy.name);
+        if active && !was {
+            known.push(synergy.name);
+            spawn_popup(&mut commands, &font, tf.translation.truncate() + Vec2::Y * 20.0, format!("SYNERGY: {}", synergy.name));
+        } else if !active && was {
+            known.retain(|n| *n != synergy.name);
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct BarrierState {
+    last_hp: f32,
+    last_shield: f32,
+    calm: f32,
+    recharge: f32,
+}
+
+/// Recharging Barrier: once the player has gone a while without taking a
+/// hit, shield charges trickle back up to max.
+pub fn recharging_barrier(
+    time: Res<Time>,
+    mut player_q: Query<(&Health, &mut Shield, &RewardStacks), With<Player>>,
+    mut state: Local<BarrierState>,
+) {
+    let Ok((health, mut shield, stacks)) = player_q.single_mut() else { return; };
+
+    if health.0 < state.last_hp || shield.current < state.last_shield {
+        state.calm = 0.0;
+        state.recharge = 0.0;
+    } else {
+        state.calm += time.delta_secs();
+    }
+
+    if RECHARGING_BARRIER.active(stacks) && state.calm >= BARRIER_CALM_SECS && shield.current < shield.max {
+        state.recharge += time.delta_secs();
+        if state.recharge >= BARRIER_RECHARGE_SECS {
+            state.recharge = 0.0;
+            shield.current = (shield.current + 1.0).min(shield.max);
+        }
+    }
+
+    state.last_hp = health.0;
+    state.last_shield = shield.current;
+}
+
+/// Volatile Rounds: every hit from a piercing bullet sets off a small blast
+/// that only hurts enemies.
+pub fn volatile_rounds(
+    mut hits: EventReader<BulletHitEvent>,
+    mut explosions: EventWriter<ExplosionEvent>,
+    bullets: Query<&BulletDamage, With<Piercing>>,
+    player_q: Query<&RewardStacks, With<Player>>,
+) {
+    let active = player_q.single().is_ok_and(|s| VOLATILE_ROUNDS.active(s));
+    if !active {
+        hits.clear();
+        return;
+    }
+    for hit in hits.read() {
+        let Ok(damage) = bullets.get(hit.bullet) else { continue; };
+        explosions.write(ExplosionEvent {
+            pos: hit.pos,
+            radius: VOLATILE_RADIUS,
+            damage: damage.0 * VOLATILE_DAMAGE_FRAC,
+            knockback: 0.0,
+            hurts_player: false,
+        });
+    }
+}
+
+/// Counter Guard: a parry tops the shield up by one charge.
+pub fn counter_guard(
+    mut deflects: EventReader<BulletDeflectedEvent>,
+    mut player_q: Query<(&mut Shield, &RewardStacks), With<Player>>,
+) {
+    let Ok((mut shield, stacks)) = player_q.single_mut() else {
+        deflects.clear();
+        return;
+    };
+    let parries = deflects.read().filter(|d| d.parried).count();
+    if parries > 0 && COUNTER_GUARD.active(stacks) {
+        shield.current = (shield.current + parries as f32).min(shield.max);
+    }
+}
+
+/// The pause-screen panel: every synergy, lit up if held, otherwise greyed
+/// out with the rewards it still needs.
+pub fn spawn_synergy_tracker(
+    parent: &mut ChildSpawnerCommands,
+    font: Handle<Font>,
+    stacks: Option<&RewardStacks>,
+    registry: &RewardRegistry,
+) {
+    parent
+        .spawn((
+            Node {
+                width: Val::Px(300.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.14, 0.97)),
+            BorderColor(Color::srgba(0.3, 0.3, 0.6, 0.8)),
+            BorderRadius::all(Val::Px(10.0)),
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new("SYNERGIES"),
+                TextFont { font: font.clone(), font_size: 24.0, ..default() },
+                TextColor(Color::WHITE),
+            ));
+
+            for synergy in SYNERGIES {
+                let active = stacks.is_some_and(|s| synergy.active(s));
+                let (title_color, body_color) = if active {
+                    (Color::srgb(1.0, 0.85, 0.3), Color::srgb(0.9, 0.9, 0.9))
+                } else {
+                    (Color::srgb(0.5, 0.5, 0.5), Color::srgb(0.4, 0.4, 0.4))
+                };
+                let needs = synergy
+                    .requires
+                    .iter()
+                    .map(|&id| {
+                        let held = stacks.is_some_and(|s| s.count(id) > 0);
+                        format!("{} {}", if held { "+" } else { "-" }, registry.name(id))
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                panel
+                    .spawn(Node { flex_direction: FlexDirection::Column, row_gap: Val::Px(2.0), ..default() })
+                    .with_children(|entry| {
+                        entry.spawn((
+                            Text::new(synergy.name),
+                            TextFont { font: font.clone(), font_size: 18.0, ..default() },
+                            TextColor(title_color),
+                        ));
+                        entry.spawn((
+                            Text::new(synergy.description),
+                            TextFont { font: font.clone(), font_size: 14.0, ..default() },
+                            TextColor(body_color),
+                        ));
+                        entry.spawn((
+                            Text::new(needs),
+                            TextFont { font: font.clone(), font_size: 13.0, ..default() },
+                            TextColor(body_color),
+                        ));
+                    });
+            }
+        });
+}
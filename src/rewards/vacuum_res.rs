@@ -1,9 +1,30 @@
-use crate::fluiddynamics::PulledByFluid;
+use super::{RewardBehavior, RewardId, RewardRegistry, RewardTargetItem, RewardTargetReadOnlyItem};
+use crate::stats::{ModOp, ModSource, Stat};
 
-pub const NAME: &str = "Vacuum Resistance";
-pub const ASSET: &str = "rewards/VaccuumResistance.png";
+pub const ID: RewardId = RewardId("vacuum_res");
+const MASS: ModOp = ModOp::Add(25.0);
 
-pub fn apply(pull: &mut PulledByFluid) {
-    // Heavier = harder to suck into breaches
-    pull.mass += 25.0;
+pub struct VacuumRes;
+
+pub fn register(registry: &mut RewardRegistry) {
+    registry.register(VacuumRes);
+}
+
+impl RewardBehavior for VacuumRes {
+    fn id(&self) -> RewardId { ID }
+
+    fn name(&self) -> &'static str { "Vacuum Resistance" }
+
+    fn description(&self) -> &'static str { "Heavier, so breaches pull you in less." }
+
+    fn icon(&self) -> &'static str { "rewards/VaccuumResistance.png" }
+
+    fn preview(&self, target: &RewardTargetReadOnlyItem) -> String {
+        format!("Mass {:.0} -> {:.0}", target.stats.get(Stat::Mass), target.stats.preview(Stat::Mass, MASS))
+    }
+
+    fn apply(&self, target: &mut RewardTargetItem) {
+        // Heavier = harder to suck into breaches
+        target.stats.add(Stat::Mass, MASS, ModSource::Reward(ID));
+    }
 }
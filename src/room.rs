@@ -9,8 +9,10 @@ use crate::{GameEntity, GameState, TILE_SIZE, Z_ENTITIES};
 use crate::map::{Door, TablePositions};
 use crate::map::TileRes;
 use crate::player::{NumOfCleared, Player};
-use crate::enemies::{EnemyRes, LastKillPos, RangedEnemyRes, TurretRes, spawn_enemy_at, spawn_ranged_enemy_at, spawn_turret_enemy_at};
+use crate::enemies::elite;
+use crate::enemies::{EnemyRes, LastKillPos, RangedEnemyRes, TurretRes};
 use crate::table;
+use crate::waves::{RoomWaves, plan_waves, spawn_wave_enemy};
 
 #[derive(Resource)]
 pub struct EnemyPosition(pub HashSet<(usize, usize)>);
@@ -18,7 +20,8 @@ pub struct EnemyPosition(pub HashSet<(usize, usize)>);
 #[derive(Resource)]
 pub enum LevelState{
     EnteredRoom(usize),
-    InRoom(usize, Vec3, Vec3),
+    /// The room being fought in and where its key chest goes.
+    InRoom(usize, Vec3),
     NotRoom
 }
 
@@ -29,6 +32,10 @@ pub struct Room{
     pub cleared: bool,
     pub visited: bool,
     pub is_airlock: bool,
+    /// The room furthest from the airlock; the station boss waits here.
+    pub is_boss_room: bool,
+    /// The vendor room. Pre-cleared like the airlock; nothing spawns here.
+    pub is_shop: bool,
     pub doors:Vec<Entity>,
     pub numofenemies: usize,
     pub top_left_corner: Vec2,
@@ -38,6 +45,8 @@ pub struct Room{
     layout: Vec<String>,
     pub air_pressure: f32,
     pub breaches: Vec<Vec2>,
+    /// Reinforcements still to come while the room is locked.
+    pub waves: Option<RoomWaves>,
 }
 
 impl Room{
@@ -46,6 +55,8 @@ impl Room{
             cleared: false,
             visited: false,
             is_airlock: false,
+            is_boss_room: false,
+            is_shop: false,
             doors:Vec::new(),
             numofenemies: 0,
             top_left_corner: tlc.clone(),
@@ -55,6 +66,7 @@ impl Room{
             layout: room_layout.clone(),
             air_pressure: 100.0,
             breaches: Vec::new(),
+            waves: None,
         }
     }
 
@@ -110,7 +122,7 @@ fn sync_active_room(
     rooms: Res<RoomVec>,
 ) {
     match *lvlstate {
-        LevelState::EnteredRoom(i) | LevelState::InRoom(i, _, _) => {
+        LevelState::EnteredRoom(i) | LevelState::InRoom(i, _) => {
             active.0 = Some(i);
         }
         LevelState::NotRoom => {
@@ -224,7 +236,7 @@ pub fn track_rooms(
 
     // Only look for a new room trigger when we are not already processing one.
     match *lvlstate {
-        LevelState::EnteredRoom(_) | LevelState::InRoom(_, _, _) => {}
+        LevelState::EnteredRoom(_) | LevelState::InRoom(_, _) => {}
         LevelState::NotRoom => {
             for (index, room) in rooms.0.iter_mut().enumerate() {
                 if !room.cleared && room.within_bounds_check(player_pos) {
@@ -246,7 +258,10 @@ pub fn entered_room(
     turret_res: Res<TurretRes>,
     play_query: Single<&NumOfCleared, With<Player>>,
     station_level: Res<crate::StationLevel>,
+    director: Res<crate::director::Director>,
     mut shield_query: Query<&mut crate::player::Shield, With<Player>>,
+    wall_grid: Res<crate::map::WallGrid>,
+    grid: Res<crate::map::MapGridMeta>,
 ){
     match *lvlstate
     {
@@ -263,8 +278,17 @@ pub fn entered_room(
                 commands.entity(*door).insert(Sprite::from_image(tiles.closed_door.clone()));
             }
 
-            if let Some((pos, chest_pos)) = generate_enemies_in_room(1, None, &mut rooms, index, &mut commands, &enemy_res, &ranged_res, &turret_res, &play_query, station_level.0){
-                *lvlstate = LevelState::InRoom(index, pos, chest_pos);
+            if let Some(chest_pos) = generate_enemies_in_room(1, None, &mut rooms, index, &mut commands, &enemy_res, &ranged_res, &turret_res, &play_query, station_level.0, &director){
+                *lvlstate = LevelState::InRoom(index, chest_pos);
+            } else if rooms.0[index].is_boss_room {
+                // The boss spawns on its own once the room is entered, so an
+                // awkward layout must never let the boss room clear without it.
+                let room = &mut rooms.0[index];
+                room.numofenemies = 0;
+                room.waves = None;
+                let centre = (room.top_left_corner + room.bot_right_corner) * 0.5;
+                let chest_pos = nearest_floor_pos(centre, &wall_grid, &grid);
+                *lvlstate = LevelState::InRoom(index, chest_pos.extend(Z_ENTITIES));
             } else {
                 // Room is too small/tight to place any enemies — clear it immediately
                 // and reopen the doors so the player is never locked in.
@@ -283,7 +307,7 @@ pub fn entered_room(
 
 /// Returns the world position of the nearest non-wall, in-bounds tile to `pos`.
 /// Searches outward shell by shell (Chebyshev distance) up to 60 tiles away.
-fn nearest_floor_pos(
+pub fn nearest_floor_pos(
     pos: Vec2,
     wall_grid: &crate::map::WallGrid,
     grid: &crate::map::MapGridMeta,
@@ -315,23 +339,40 @@ pub fn playing_room(
     mut lvlstate: ResMut<LevelState>,
     mut commands: Commands,
     tiles: Res<TileRes>,
-    mut player: Single<&mut NumOfCleared, With<Player>>,
+    player: Single<(&mut NumOfCleared, &crate::stats::StatSheet), With<Player>>,
     heart_res: Res<crate::heart::HeartRes>,
-    reward_res: Res<crate::rewards::RewardRes>,
+    mut offers: EventWriter<crate::rewards::choice::OfferUpgradeEvent>,
     last_kill_pos: Res<LastKillPos>,
     wall_grid: Res<crate::map::WallGrid>,
     grid: Res<crate::map::MapGridMeta>,
+    director: Res<crate::director::Director>,
+    chest_res: Res<crate::key_chest::KeyChestRes>,
 ){
+    let (mut cleared, sheet) = player.into_inner();
     match *lvlstate
     {
-        LevelState::InRoom(index, reward_pos, _) =>
+        LevelState::InRoom(index, _) =>
         {
-            if rooms.0[index].numofenemies == 0{
+            let waves_done = rooms.0[index].waves.as_ref().is_none_or(|w| w.is_done());
+            if rooms.0[index].numofenemies == 0 && waves_done {
                 debug!("All enemies defeated");
 
                 let heart_pos = nearest_floor_pos(last_kill_pos.0, &wall_grid, &grid);
                 crate::heart::spawn_heart(&mut commands, &heart_res, heart_pos);
-                crate::rewards::spawn_reward(&mut commands, reward_pos, &reward_res);
+                let ammo_pos = nearest_floor_pos(last_kill_pos.0 - Vec2::new(TILE_SIZE * 1.5, 0.0), &wall_grid, &grid);
+                crate::heart::spawn_ammo(&mut commands, ammo_pos);
+                if director.bonus_heart() {
+                    let extra = nearest_floor_pos(last_kill_pos.0 + Vec2::new(TILE_SIZE * 1.5, 0.0), &wall_grid, &grid);
+                    crate::heart::spawn_heart(&mut commands, &heart_res, extra);
+                }
+                offers.write(crate::rewards::choice::OfferUpgradeEvent {
+                    pool: crate::rewards::RewardPool::Standard,
+                    picks: sheet.get(crate::stats::Stat::RewardDrops) as u32,
+                });
+                if rand::random::<f32>() < crate::rewards::cursed::CURSED_CHEST_CHANCE {
+                    let chest_pos = nearest_floor_pos(last_kill_pos.0 + Vec2::new(0.0, TILE_SIZE * 1.5), &wall_grid, &grid);
+                    crate::rewards::cursed::spawn_cursed_chest(&mut commands, chest_res.chest_img.clone(), chest_pos);
+                }
 
                 for door in rooms.0[index].doors.iter(){
                     commands.entity(*door).remove::<Collidable>();
@@ -341,7 +382,7 @@ pub fn playing_room(
 
                 rooms.0[index].cleared = true;
                 //rooms.0.remove(index);
-                player.0 += 1;
+                cleared.0 += 1;
                 *lvlstate = LevelState::NotRoom;
             }
         }
@@ -349,19 +390,22 @@ pub fn playing_room(
     }
 }
 
+/// Spare floor tiles kept per room for reinforcements to emerge from.
+const VENT_COUNT: usize = 4;
+
 pub fn generate_enemies_in_room(
     num_of_enemies: usize,
     seed: Option<u64>,
     rooms: &mut RoomVec,
     index: usize,
-    mut commands: &mut Commands,
+    commands: &mut Commands,
     enemy_res: &EnemyRes,
     ranged_res: &RangedEnemyRes,
     turret_res: &TurretRes,
     play_query: &NumOfCleared,
     station_level: u32,
-
-) -> Option<(Vec3, Vec3)> {
+    director: &crate::director::Director,
+) -> Option<Vec3> {
     let rooms_cleared = play_query.0;
     let mut floors: Vec<(f32, f32)> = Vec::new();
 
@@ -369,8 +413,14 @@ pub fn generate_enemies_in_room(
     // Scale enemy count: base + rooms_cleared + station_level bonus
     // Each station adds 2 extra enemies per room
     let station_bonus = (station_level as usize) * 2;
-    let scaled_num_enemies = 1 * rooms_cleared + num_of_enemies + station_bonus;
-    room.numofenemies = scaled_num_enemies;
+    let base_num_enemies = rooms_cleared + num_of_enemies + station_bonus;
+    // The director trims or pads the budget depending on how the player is doing.
+    let scaled_num_enemies = ((base_num_enemies as f32 * director.spawn_budget_mult()).round() as usize).max(1);
+
+    // Only the first wave spawns now; the rest are reinforcements.
+    let mut wave_sizes = plan_waves(room, scaled_num_enemies, station_level);
+    let first_wave = wave_sizes.remove(0);
+    room.numofenemies = first_wave;
 
     // Health multiplier: each station increases enemy health by 50%
     let health_multiplier = 1.0 + (station_level as f32) * 0.5;
@@ -418,8 +468,19 @@ pub fn generate_enemies_in_room(
     let mut actually_spawned: usize = 0;
     let mut spawn_idx: usize = 0; // separate counter so ranged ratio stays consistent
     let mut valid_floors: Vec<(f32, f32)> = Vec::new();
+    let mut waves = RoomWaves {
+        threshold: (first_wave / 3).max(1),
+        remaining: wave_sizes,
+        health_multiplier,
+        speed_bonus,
+        elite_chance: (elite::elite_chance(station_level) + director.elite_chance_bonus()).clamp(0.0, 0.5),
+        station_level,
+        vents: Vec::new(),
+        next_spawn_idx: 0,
+    };
     for (x, y) in floors.iter() {
-        if actually_spawned >= scaled_num_enemies {
+        // Keep a few spare valid tiles past the first wave to use as vents.
+        if actually_spawned >= first_wave && valid_floors.len() >= first_wave + VENT_COUNT {
             break;
         }
 
@@ -463,22 +524,22 @@ pub fn generate_enemies_in_room(
         }
 
         valid_floors.push((*x, *y));
+        if actually_spawned >= first_wave {
+            waves.vents.push(Vec3::new(*x, *y, Z_ENTITIES));
+            continue;
+        }
         let pos = Vec3::new(*x, *y, Z_ENTITIES);
 
-        if spawn_idx % 8 == 6 {
-            spawn_turret_enemy_at(&mut commands, turret_res, pos, true, health_multiplier, speed_bonus);
-        } else if spawn_idx % 8 == 2 {
-            spawn_ranged_enemy_at(&mut commands, ranged_res, pos, true, health_multiplier, speed_bonus);
-        } else {
-            spawn_enemy_at(&mut commands, enemy_res, pos, true, health_multiplier, speed_bonus);
-        }
+        spawn_wave_enemy(commands, enemy_res, ranged_res, turret_res, pos, spawn_idx, &waves);
         actually_spawned += 1;
         spawn_idx += 1;
     }
+    waves.next_spawn_idx = spawn_idx;
 
     // numofenemies must equal what was actually spawned — a mismatch would permanently
     // lock the room if some tiles were rejected by the wall-adjacency filter.
     room.numofenemies = actually_spawned;
+    room.waves = Some(waves);
 
     if actually_spawned == 0 {
         info!("Room {}: all candidate tiles were adjacent to walls, cannot spawn.", index);
@@ -493,13 +554,8 @@ pub fn generate_enemies_in_room(
         valid_floors.shuffle(&mut trng);
     }
 
-    let mut it = valid_floors.into_iter();
-    let reward_tile = it.next()?;
-    let chest_tile = it.next().unwrap_or((reward_tile.0 + TILE_SIZE, reward_tile.1));
-    Some((
-        Vec3::new(reward_tile.0, reward_tile.1, Z_ENTITIES),
-        Vec3::new(chest_tile.0, chest_tile.1, Z_ENTITIES),
-    ))
+    let (x, y) = valid_floors.into_iter().next()?;
+    Some(Vec3::new(x, y, Z_ENTITIES))
 
     // debug!("Room {}: spawned {} enemies", index, scaled_num_enemies);
 }
@@ -694,9 +750,10 @@ pub fn apply_breach_forces_to_entities(
 pub fn damage_player_from_low_pressure(
     time: Res<Time>,
     rooms: Res<RoomVec>,
-    mut player: Query<(&Transform, &mut crate::player::Health, &mut crate::player::DamageTimer, &mut crate::player::AirTank), With<crate::player::Player>>,
+    mut player: Query<(Entity, &Transform, &mut crate::player::DamageTimer, &mut crate::player::AirTank), With<crate::player::Player>>,
+    mut damage_events: EventWriter<crate::damage::DamageEvent>,
 ) {
-    let Ok((transform, mut health, mut damage_timer, mut tank)) = player.single_mut() else {
+    let Ok((entity, transform, mut damage_timer, mut tank)) = player.single_mut() else {
         return;
     };
 
@@ -725,14 +782,15 @@ pub fn damage_player_from_low_pressure(
             damage_timer.tick(time.delta());
 
             if damage_timer.finished() {
-                let damage = 5.0;
-                health.0 -= damage;
+                damage_events.write(crate::damage::DamageEvent {
+                    source: crate::damage::DamageSource::Station,
+                    target: entity,
+                    amount: 5.0,
+                    kind: crate::damage::DamageKind::Environment,
+                });
                 damage_timer.reset();
 
-                debug!(
-                    "Player taking pressure damage! Room pressure: {:.1}% - HP: {:.1}",
-                    room.air_pressure, health.0
-                );
+                debug!("Player taking pressure damage! Room pressure: {:.1}%", room.air_pressure);
             }
         }
     }
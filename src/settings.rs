@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use bevy::audio::Volume;
 use bevy::window::{WindowMode, PrimaryWindow, MonitorSelection, VideoModeSelection};
 use crate::{GameMusicVolume, MusicTrack, FONT_PATH};
+use crate::aim::AimAssist;
 
 pub struct SettingsPlugin;
 
@@ -65,12 +66,17 @@ struct VolumeDisplay;
 #[derive(Component)]
 struct WindowModeDisplay;
 
+#[derive(Component)]
+struct AimAssistDisplay;
+
 #[derive(Component)]
 enum SettingsButton {
     VolumeDown,
     VolumeUp,
     WindowModeLeft,
     WindowModeRight,
+    /// Both arrows on the aim assist row; there are only two states.
+    ToggleAimAssist,
     Back,
 }
 
@@ -82,13 +88,14 @@ impl Plugin for SettingsPlugin {
             )
             .add_systems(Update, update_volume_display)
             .add_systems(Update, update_window_mode_display)
+            .add_systems(Update, update_aim_assist_display)
             .add_systems(Update, sync_volume_to_sinks)
             .add_systems(Update, sync_window_mode);
     }
 }
 
 /// Spawn the settings overlay. Caller decides the origin context.
-pub fn open_settings(commands: &mut Commands, assets: &AssetServer, current_volume: f32, current_window_mode: GameWindowMode, origin: SettingsOrigin) {
+pub fn open_settings(commands: &mut Commands, assets: &AssetServer, current_volume: f32, current_window_mode: GameWindowMode, aim_assist: AimAssist, origin: SettingsOrigin) {
     commands.insert_resource(origin);
 
     let font: Handle<Font> = assets.load(FONT_PATH);
@@ -223,6 +230,50 @@ pub fn open_settings(commands: &mut Commands, assets: &AssetServer, current_volu
                         });
                     });
 
+                // Aim Assist row
+                panel
+                    .spawn((Node {
+                        width: Val::Percent(100.0),
+                        justify_content: JustifyContent::SpaceBetween,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },))
+                    .with_children(|row| {
+                        row.spawn((Node::default(),)).with_children(|c| {
+                            c.spawn((
+                                Text::new("Aim Assist"),
+                                TextFont { font: font.clone(), font_size: 22.0, ..default() },
+                                TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                            ));
+                        });
+
+                        row.spawn((Node {
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(10.0),
+                            ..default()
+                        },))
+                        .with_children(|ctrl| {
+                            spawn_small_button(ctrl, font.clone(), "<", SettingsButton::ToggleAimAssist);
+
+                            ctrl.spawn((Node {
+                                width: Val::Px(110.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },))
+                            .with_children(|c| {
+                                c.spawn((
+                                    Text::new(aim_assist.label()),
+                                    TextFont { font: font.clone(), font_size: 18.0, ..default() },
+                                    TextColor(Color::WHITE),
+                                    AimAssistDisplay,
+                                ));
+                            });
+
+                            spawn_small_button(ctrl, font.clone(), ">", SettingsButton::ToggleAimAssist);
+                        });
+                    });
+
                 // Back button
                 panel
                     .spawn((
@@ -281,6 +332,7 @@ fn handle_settings_buttons(
     mut interactions: Query<(&Interaction, &SettingsButton), (Changed<Interaction>, With<Button>)>,
     mut volume: ResMut<GameMusicVolume>,
     mut window_mode: ResMut<GameWindowMode>,
+    mut aim_assist: ResMut<AimAssist>,
     ui_q: Query<Entity, With<SettingsUI>>,
 ) {
     for (interaction, button) in &mut interactions {
@@ -300,6 +352,9 @@ fn handle_settings_buttons(
             SettingsButton::WindowModeRight => {
                 *window_mode = window_mode.next();
             }
+            SettingsButton::ToggleAimAssist => {
+                aim_assist.0 = !aim_assist.0;
+            }
             SettingsButton::Back => {
                 commands.remove_resource::<SettingsOrigin>();
                 for e in &ui_q {
@@ -347,6 +402,18 @@ fn update_window_mode_display(
     }
 }
 
+fn update_aim_assist_display(
+    assist: Res<AimAssist>,
+    mut text_q: Query<&mut Text, With<AimAssistDisplay>>,
+) {
+    if !assist.is_changed() {
+        return;
+    }
+    for mut t in &mut text_q {
+        *t = Text::new(assist.label());
+    }
+}
+
 fn sync_window_mode(
     mode: Res<GameWindowMode>,
     mut windows: Query<&mut Window, With<PrimaryWindow>>,
@@ -0,0 +1,315 @@
+use bevy::prelude::*;
+use rand::seq::IndexedRandom;
+
+use crate::items::{Consumables, ItemKind};
+use crate::player::Player;
+use crate::rewards::{RewardFont, RewardId, RewardRegistry, RewardStacks, RewardTarget, spawn_popup};
+use crate::room::{RoomVec, nearest_floor_pos};
+use crate::stats::Stat;
+use crate::weapons::{WeaponId, WeaponInventory, WeaponRegistry};
+use crate::{GameEntity, GameState, StationLevel, TILE_SIZE, Z_ENTITIES};
+
+const SCRAP_COLOR: Color = Color::srgb(0.7, 0.72, 0.78);
+/// Scrap dropped by an ordinary kill, inclusive.
+const SCRAP_PER_KILL: (u32, u32) = (1, 3);
+/// Scrap drifts toward the player inside this distance...
+const SCRAP_MAGNET_RADIUS: f32 = TILE_SIZE * 3.0;
+const SCRAP_MAGNET_SPEED: f32 = 260.0;
+/// ...and is picked up inside this one.
+const SCRAP_COLLECT_RADIUS: f32 = TILE_SIZE;
+
+const SHOP_HEAL: f32 = 40.0;
+/// Each station's prices are this much higher than the last one's.
+const PRICE_STEP_PER_STATION: f32 = 0.35;
+
+/// Scrap the player is carrying. Spent at the vendor, kept between stations.
+#[derive(Component, Default, Clone, Copy)]
+pub struct Scrap(pub u32);
+
+#[derive(Component)]
+pub struct ScrapPickup(pub u32);
+
+/// What a vendor pedestal sells.
+#[derive(Clone, Copy, Debug)]
+pub enum ShopStock {
+    Reward(RewardId),
+    Weapon(WeaponId),
+    Heal,
+    AirRefill,
+    /// Goes on the hotbar.
+    Item(ItemKind),
+}
+
+impl ShopStock {
+    fn base_price(self) -> f32 {
+        match self {
+            ShopStock::Reward(_) => 40.0,
+            ShopStock::Weapon(_) => 90.0,
+            ShopStock::Heal => 20.0,
+            ShopStock::AirRefill => 15.0,
+            ShopStock::Item(kind) => kind.base_price(),
+        }
+    }
+
+    fn label(self, rewards: &RewardRegistry, weapons: &WeaponRegistry) -> String {
+        match self {
+            ShopStock::Reward(id) => rewards.name(id).to_string(),
+            ShopStock::Weapon(id) => weapons.name(id).to_string(),
+            ShopStock::Heal => format!("+{SHOP_HEAL:.0} HP"),
+            ShopStock::AirRefill => "Air Refill".to_string(),
+            ShopStock::Item(kind) => kind.name().to_string(),
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            ShopStock::Reward(_) => Color::srgb(0.4, 0.6, 1.0),
+            ShopStock::Weapon(_) => Color::srgb(1.0, 0.6, 0.15),
+            ShopStock::Heal => Color::srgb(0.9, 0.25, 0.3),
+            ShopStock::AirRefill => Color::srgb(0.2, 1.0, 0.5),
+            ShopStock::Item(kind) => kind.color(),
+        }
+    }
+}
+
+/// One item for sale. Walk up and press E to buy it.
+#[derive(Component)]
+pub struct ShopItem {
+    pub stock: ShopStock,
+    pub price: u32,
+}
+
+#[derive(Component)]
+struct ScrapHud;
+
+pub struct ShopPlugin;
+
+impl Plugin for ShopPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+                OnEnter(GameState::Playing),
+                (stock_shop.after(crate::player::spawn_player), spawn_scrap_hud),
+            )
+            .add_systems(
+                Update,
+                (
+                    collect_scrap,
+                    buy_shop_item.run_if(not(resource_exists::<crate::pause::IsPaused>)),
+                    update_scrap_hud,
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// What something costs at the given station.
+pub fn price(stock: ShopStock, station_level: u32) -> u32 {
+    (stock.base_price() * (1.0 + PRICE_STEP_PER_STATION * station_level as f32)).round() as u32
+}
+
+/// Drops a handful of scrap around `pos`. Called when an enemy dies.
+pub fn drop_scrap(commands: &mut Commands, pos: Vec2) {
+    let pieces = rand::random_range(SCRAP_PER_KILL.0..=SCRAP_PER_KILL.1);
+    for _ in 0..pieces {
+        let jitter = Vec2::new(rand::random_range(-10.0..10.0), rand::random_range(-10.0..10.0));
+        commands.spawn((
+            Sprite::from_color(SCRAP_COLOR, Vec2::new(7.0, 5.0)),
+            Transform::from_translation((pos + jitter).extend(Z_ENTITIES))
+                .with_rotation(Quat::from_rotation_z(rand::random_range(0.0..std::f32::consts::TAU))),
+            ScrapPickup(1),
+            GameEntity,
+        ));
+    }
+}
+
+/// Scrap slides toward a nearby player and is banked on contact.
+fn collect_scrap(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut player_q: Query<(&Transform, &mut Scrap), With<Player>>,
+    mut scrap_q: Query<(Entity, &mut Transform, &ScrapPickup), Without<Player>>,
+) {
+    let Ok((player_tf, mut scrap)) = player_q.single_mut() else { return; };
+    let player_pos = player_tf.translation.truncate();
+
+    for (entity, mut tf, pickup) in &mut scrap_q {
+        let offset = player_pos - tf.translation.truncate();
+        let dist = offset.length();
+        if dist < SCRAP_COLLECT_RADIUS {
+            scrap.0 += pickup.0;
+            commands.entity(entity).despawn();
+        } else if dist < SCRAP_MAGNET_RADIUS {
+            let step = offset / dist * SCRAP_MAGNET_SPEED * time.delta_secs();
+            tf.translation += step.extend(0.0);
+        }
+    }
+}
+
+/// Lays the vendor's wares out in a row across the middle of the shop room:
+/// two rewards, a weapon the player doesn't own, the staples, a hull patch
+/// and one other hotbar item.
+fn stock_shop(
+    mut commands: Commands,
+    rooms: Res<RoomVec>,
+    wall_grid: Res<crate::map::WallGrid>,
+    grid: Res<crate::map::MapGridMeta>,
+    station_level: Res<StationLevel>,
+    director: Res<crate::director::Director>,
+    rewards: Res<RewardRegistry>,
+    weapons: Res<WeaponRegistry>,
+    font: Res<RewardFont>,
+    player_q: Query<(&RewardStacks, &WeaponInventory), With<Player>>,
+) {
+    let Some(room) = rooms.0.iter().find(|r| r.is_shop) else { return; };
+    let Ok((stacks, inventory)) = player_q.single() else { return; };
+
+    let mut stock = Vec::new();
+    let quality = director.reward_quality();
+    for _ in 0..2 {
+        if let Some(id) = rewards.roll(stacks, quality)
+            && !stock.iter().any(|s| matches!(s, ShopStock::Reward(r) if *r == id))
+        {
+            stock.push(ShopStock::Reward(id));
+        }
+    }
+    let unowned: Vec<_> = weapons.ids()
+        .filter(|id| !inventory.weapons.iter().any(|w| w.id == *id))
+        .collect();
+    if let Some(&id) = unowned.choose(&mut rand::rng()) {
+        stock.push(ShopStock::Weapon(id));
+    }
+    stock.extend([ShopStock::Heal, ShopStock::AirRefill, ShopStock::Item(ItemKind::HullPatch)]);
+    let extras: Vec<_> = ItemKind::ALL.into_iter().filter(|k| *k != ItemKind::HullPatch).collect();
+    if let Some(&kind) = extras.choose(&mut rand::rng()) {
+        stock.push(ShopStock::Item(kind));
+    }
+
+    let centre = (room.top_left_corner + room.bot_right_corner) * 0.5;
+    let spacing = TILE_SIZE * 2.5;
+    let start = centre.x - spacing * (stock.len() as f32 - 1.0) * 0.5;
+
+    for (i, item) in stock.into_iter().enumerate() {
+        let pos = nearest_floor_pos(Vec2::new(start + spacing * i as f32, centre.y), &wall_grid, &grid);
+        let price = price(item, station_level.0);
+        commands
+            .spawn((
+                Sprite::from_color(item.color(), Vec2::splat(TILE_SIZE * 0.6)),
+                Transform::from_translation(pos.extend(Z_ENTITIES)),
+                ShopItem { stock: item, price },
+                GameEntity,
+            ))
+            .with_children(|p| {
+                p.spawn((
+                    Text2d::new(format!("{}\n{} scrap", item.label(&rewards, &weapons), price)),
+                    TextFont { font: font.0.clone(), font_size: 12.0, ..default() },
+                    TextColor(Color::WHITE),
+                    TextLayout::new_with_justify(JustifyText::Center),
+                    Transform::from_xyz(0.0, TILE_SIZE * 0.9, 1.0),
+                ));
+            });
+    }
+}
+
+/// Press E next to a pedestal to buy what's on it.
+fn buy_shop_item(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    font: Res<RewardFont>,
+    rewards: Res<RewardRegistry>,
+    weapons: Res<WeaponRegistry>,
+    items: Query<(Entity, &Transform, &ShopItem)>,
+    mut player_q: Query<(&Transform, &mut Scrap, &mut Consumables, &mut RewardStacks, RewardTarget), With<Player>>,
+) {
+    if !input.just_pressed(KeyCode::KeyE) { return; }
+    let Ok((player_tf, mut scrap, mut hotbar, mut stacks, mut target)) = player_q.single_mut() else { return; };
+    let player_pos = player_tf.translation.truncate();
+
+    let Some((entity, item_tf, item)) = items
+        .iter()
+        .filter(|(_, tf, _)| tf.translation.truncate().distance(player_pos) < TILE_SIZE * 1.5)
+        .min_by(|a, b| {
+            a.1.translation.truncate().distance(player_pos)
+                .total_cmp(&b.1.translation.truncate().distance(player_pos))
+        })
+    else {
+        return;
+    };
+    let pos = item_tf.translation.truncate();
+
+    if scrap.0 < item.price {
+        spawn_popup(&mut commands, &font, pos, format!("Need {} scrap", item.price));
+        return;
+    }
+
+    match item.stock {
+        ShopStock::Reward(id) => {
+            if rewards.grant(id, &mut target, &mut stacks).is_none() { return; }
+        }
+        ShopStock::Weapon(id) => {
+            let Some(weapon) = weapons.create(id) else { return; };
+            target.inventory.weapons.push(weapon);
+        }
+        ShopStock::Heal => {
+            let health = &mut target.player.health;
+            health.0 = (health.0 + SHOP_HEAL).min(target.stats.get(Stat::MaxHealth));
+        }
+        ShopStock::AirRefill => {
+            let tank = &mut target.player.air_tank;
+            tank.current = tank.max_capacity;
+        }
+        ShopStock::Item(kind) => {
+            if !hotbar.add(kind) {
+                spawn_popup(&mut commands, &font, pos, "Hotbar full");
+                return;
+            }
+        }
+    }
+
+    scrap.0 -= item.price;
+    spawn_popup(&mut commands, &font, pos, item.stock.label(&rewards, &weapons));
+    commands.entity(entity).despawn();
+}
+
+fn spawn_scrap_hud(mut commands: Commands, font: Res<RewardFont>) {
+    commands.spawn((
+        Text::new("Scrap: 0"),
+        TextFont { font: font.0.clone(), font_size: 24.0, ..default() },
+        TextColor(SCRAP_COLOR),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(66.0),
+            right: Val::Px(10.0),
+            ..default()
+        },
+        ScrapHud,
+        GameEntity,
+    ));
+}
+
+fn update_scrap_hud(
+    player_q: Query<&Scrap, (With<Player>, Changed<Scrap>)>,
+    mut hud_q: Query<&mut Text, With<ScrapHud>>,
+) {
+    let Ok(scrap) = player_q.single() else { return; };
+    let Ok(mut text) = hud_q.single_mut() else { return; };
+    text.0 = format!("Scrap: {}", scrap.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_station_charges_the_base_price() {
+        assert_eq!(price(ShopStock::Heal, 0), 20);
+        assert_eq!(price(ShopStock::Item(ItemKind::Turret), 0), 50);
+    }
+
+    #[test]
+    fn prices_rise_with_each_station() {
+        // 40 * (1 + 0.35 * 2) = 68
+        assert_eq!(price(ShopStock::Reward(RewardId("max_hp")), 2), 68);
+        let prices: Vec<_> = (0..5).map(|level| price(ShopStock::AirRefill, level)).collect();
+        assert!(prices.windows(2).all(|w| w[0] < w[1]), "{prices:?}");
+    }
+}
@@ -0,0 +1,364 @@
+use bevy::ecs::query::QueryData;
+use bevy::prelude::*;
+
+use crate::broom::BroomStats;
+use crate::fluiddynamics::PulledByFluid;
+use crate::player::{AirTank, Armor, Health, MaxHealth, MoveSpeed, Player, Regen, Shield, ThrusterFuel};
+use crate::rewards::RewardId;
+use crate::{GameState, PLAYER_SPEED};
+
+/// Every player number that upgrades, curses or status effects can change,
+/// including the run-wide downsides of cursed upgrades.
+/// The components the rest of the game reads (`MaxHealth`, `AirTank`, ...)
+/// are derived from these.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Stat {
+    MaxHealth,
+    /// Top walking speed, in world units per second.
+    MoveSpeed,
+    Armor,
+    AirCapacity,
+    /// Air used per second in low-pressure rooms.
+    AirDrain,
+    Regen,
+    ShieldCharges,
+    /// Thruster dash charges.
+    FuelCapacity,
+    /// Resistance to being pulled toward breaches.
+    Mass,
+    BroomPower,
+    ParryWindow,
+    SpinCharge,
+    /// How quickly breaches are sealed by hand, as a multiplier.
+    RepairSpeed,
+    /// Above 0, windows in each room start cracked.
+    CrackedWindows,
+    /// Multiplies how long the reaper waits before entering a room.
+    ReaperDelay,
+    /// Reward crates dropped wherever one would normally drop.
+    RewardDrops,
+    /// Multiplies the damage of every weapon the player fires.
+    WeaponDamage,
+    /// Multiplies how often every weapon can fire.
+    FireRate,
+    /// Piercing pickups, before `Weapon::effective_pierce_count`'s
+    /// diminishing returns.
+    Pierce,
+}
+
+impl Stat {
+    pub const ALL: [Stat; 19] = [
+        Stat::MaxHealth,
+        Stat::MoveSpeed,
+        Stat::Armor,
+        Stat::AirCapacity,
+        Stat::AirDrain,
+        Stat::Regen,
+        Stat::ShieldCharges,
+        Stat::FuelCapacity,
+        Stat::Mass,
+        Stat::BroomPower,
+        Stat::ParryWindow,
+        Stat::SpinCharge,
+        Stat::RepairSpeed,
+        Stat::CrackedWindows,
+        Stat::ReaperDelay,
+        Stat::RewardDrops,
+        Stat::WeaponDamage,
+        Stat::FireRate,
+        Stat::Pierce,
+    ];
+
+    /// What a fresh run starts with.
+    fn default_base(self) -> f32 {
+        match self {
+            Stat::MaxHealth => 100.0,
+            Stat::MoveSpeed => PLAYER_SPEED,
+            Stat::Armor => 0.0,
+            Stat::AirCapacity => 5.0,
+            Stat::AirDrain => 1.0,
+            Stat::Regen => 0.0,
+            Stat::ShieldCharges => 0.0,
+            Stat::FuelCapacity => 0.0,
+            Stat::Mass => 50.0,
+            Stat::BroomPower => 1.0,
+            Stat::ParryWindow => 0.12,
+            Stat::SpinCharge => 0.9,
+            Stat::RepairSpeed => 1.0,
+            Stat::CrackedWindows => 0.0,
+            Stat::ReaperDelay => 1.0,
+            Stat::RewardDrops => 1.0,
+            Stat::WeaponDamage => 1.0,
+            Stat::FireRate => 1.0,
+            Stat::Pierce => 0.0,
+        }
+    }
+
+    /// Hard limits on the final value, whatever the modifiers say.
+    fn clamp(self, value: f32) -> f32 {
+        match self {
+            Stat::MaxHealth | Stat::Mass | Stat::RewardDrops => value.max(1.0),
+            Stat::AirDrain => value.max(0.2),
+            Stat::FuelCapacity => value.clamp(0.0, 10.0),
+            Stat::SpinCharge => value.max(0.4),
+            Stat::RepairSpeed | Stat::FireRate => value.max(0.1),
+            _ => value.max(0.0),
+        }
+    }
+
+    fn index(self) -> usize {
+        Stat::ALL.iter().position(|&s| s == self).unwrap_or(0)
+    }
+}
+
+/// How a modifier changes its stat. All `Add`s are summed onto the base
+/// first, then the result is scaled by every `Mul`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ModOp {
+    Add(f32),
+    Mul(f32),
+}
+
+/// Where a modifier came from, so it can be listed or taken away again.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ModSource {
+    Reward(RewardId),
+    /// An item set bonus, by set name.
+    Set(&'static str),
+    Status(&'static str),
+}
+
+#[derive(Clone, Debug)]
+pub struct StatModifier {
+    pub stat: Stat,
+    pub op: ModOp,
+    pub source: ModSource,
+    /// Seconds left, or `None` for a modifier that lasts the whole run.
+    pub remaining: Option<f32>,
+}
+
+/// The player's base stats plus every modifier currently on them. Carrying
+/// the player over to the next station is a clone of this.
+#[derive(Component, Clone, Debug)]
+pub struct StatSheet {
+    base: [f32; Stat::ALL.len()],
+    modifiers: Vec<StatModifier>,
+}
+
+impl Default for StatSheet {
+    fn default() -> Self {
+        Self {
+            base: Stat::ALL.map(Stat::default_base),
+            modifiers: Vec::new(),
+        }
+    }
+}
+
+impl StatSheet {
+    pub fn base(&self, stat: Stat) -> f32 {
+        self.base[stat.index()]
+    }
+
+    pub fn set_base(&mut self, stat: Stat, value: f32) -> &mut Self {
+        self.base[stat.index()] = value;
+        self
+    }
+
+    /// The final value of `stat` with every modifier applied.
+    pub fn get(&self, stat: Stat) -> f32 {
+        self.compute(stat, None)
+    }
+
+    /// What `stat` would be if `op` were added. Used for upgrade previews.
+    pub fn preview(&self, stat: Stat, op: ModOp) -> f32 {
+        self.compute(stat, Some(op))
+    }
+
+    fn compute(&self, stat: Stat, extra: Option<ModOp>) -> f32 {
+        let ops = self
+            .modifiers
+            .iter()
+            .filter(|m| m.stat == stat)
+            .map(|m| m.op)
+            .chain(extra);
+        let (add, mul) = ops.fold((0.0, 1.0), |(add, mul), op| match op {
+            ModOp::Add(v) => (add + v, mul),
+            ModOp::Mul(v) => (add, mul * v),
+        });
+        stat.clamp((self.base(stat) + add) * mul)
+    }
+
+    /// A modifier that lasts for the rest of the run.
+    pub fn add(&mut self, stat: Stat, op: ModOp, source: ModSource) -> &mut Self {
+        self.modifiers.push(StatModifier { stat, op, source, remaining: None });
+        self
+    }
+
+    /// A modifier that drops off after `secs`.
+    pub fn add_timed(&mut self, stat: Stat, op: ModOp, source: ModSource, secs: f32) -> &mut Self {
+        self.modifiers.push(StatModifier { stat, op, source, remaining: Some(secs) });
+        self
+    }
+
+    /// Takes off every modifier that came from `source`.
+    pub fn remove_source(&mut self, source: ModSource) {
+        self.modifiers.retain(|m| m.source != source);
+    }
+
+    pub fn modifiers(&self) -> &[StatModifier] {
+        &self.modifiers
+    }
+}
+
+/// The player components derived from the stat sheet, plus the pools
+/// (health, air, shield, fuel) that have to stay within their new maximums.
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct PlayerStats {
+    pub health: &'static mut Health,
+    pub max_health: &'static mut MaxHealth,
+    pub move_speed: &'static mut MoveSpeed,
+    pub armor: &'static mut Armor,
+    pub air_tank: &'static mut AirTank,
+    pub regen: &'static mut Regen,
+    pub shield: &'static mut Shield,
+    pub fuel: &'static mut ThrusterFuel,
+    pub pull: &'static mut PulledByFluid,
+    pub broom: &'static mut BroomStats,
+}
+
+impl PlayerStatsItem<'_> {
+    /// Writes every derived value from `sheet` and clamps the pools.
+    pub fn refresh(&mut self, sheet: &StatSheet) {
+        self.max_health.0 = sheet.get(Stat::MaxHealth);
+        self.health.0 = self.health.0.min(self.max_health.0);
+        self.move_speed.0 = sheet.get(Stat::MoveSpeed);
+        self.armor.0 = sheet.get(Stat::Armor);
+        self.air_tank.max_capacity = sheet.get(Stat::AirCapacity);
+        self.air_tank.drain_rate = sheet.get(Stat::AirDrain);
+        self.air_tank.current = self.air_tank.current.min(self.air_tank.max_capacity);
+        self.regen.0 = sheet.get(Stat::Regen);
+        self.shield.max = sheet.get(Stat::ShieldCharges);
+        self.shield.current = self.shield.current.min(self.shield.max);
+        self.fuel.max = sheet.get(Stat::FuelCapacity);
+        self.fuel.current = self.fuel.current.min(self.fuel.max);
+        self.pull.mass = sheet.get(Stat::Mass);
+        self.broom.power = sheet.get(Stat::BroomPower);
+        self.broom.parry_secs = sheet.get(Stat::ParryWindow);
+        self.broom.spin_charge_secs = sheet.get(Stat::SpinCharge);
+    }
+}
+
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (tick_stat_modifiers, sync_player_stats)
+                .chain()
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// Counts timed modifiers down and drops the ones that have run out.
+fn tick_stat_modifiers(time: Res<Time>, mut sheets: Query<&mut StatSheet>) {
+    let dt = time.delta_secs();
+    for mut sheet in &mut sheets {
+        if sheet.modifiers.iter().all(|m| m.remaining.is_none()) {
+            continue;
+        }
+        for m in sheet.modifiers.iter_mut() {
+            if let Some(t) = m.remaining.as_mut() {
+                *t -= dt;
+            }
+        }
+        sheet.modifiers.retain(|m| m.remaining.is_none_or(|t| t > 0.0));
+    }
+}
+
+/// Re-derives the player's components whenever the sheet changes.
+fn sync_player_stats(mut player_q: Query<(&StatSheet, PlayerStats), (With<Player>, Changed<StatSheet>)>) {
+    for (sheet, mut stats) in &mut player_q {
+        stats.refresh(sheet);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    const A: ModSource = ModSource::Reward(RewardId("a"));
+    const B: ModSource = ModSource::Reward(RewardId("b"));
+
+    #[test]
+    fn adds_are_summed_before_muls_scale() {
+        let mut sheet = StatSheet::default();
+        sheet
+            .set_base(Stat::MaxHealth, 100.0)
+            .add(Stat::MaxHealth, ModOp::Mul(1.5), A)
+            .add(Stat::MaxHealth, ModOp::Add(20.0), A)
+            .add(Stat::MaxHealth, ModOp::Mul(2.0), B);
+        assert_eq!(sheet.get(Stat::MaxHealth), (100.0 + 20.0) * 1.5 * 2.0);
+    }
+
+    #[test]
+    fn preview_does_not_change_the_sheet() {
+        let mut sheet = StatSheet::default();
+        sheet.set_base(Stat::Armor, 2.0);
+        assert_eq!(sheet.preview(Stat::Armor, ModOp::Add(3.0)), 5.0);
+        assert_eq!(sheet.get(Stat::Armor), 2.0);
+        assert!(sheet.modifiers().is_empty());
+    }
+
+    #[test]
+    fn remove_source_only_takes_off_that_source() {
+        let mut sheet = StatSheet::default();
+        sheet
+            .set_base(Stat::Regen, 0.0)
+            .add(Stat::Regen, ModOp::Add(1.0), A)
+            .add(Stat::Regen, ModOp::Add(2.0), B)
+            .add(Stat::Regen, ModOp::Add(4.0), A);
+        sheet.remove_source(A);
+        assert_eq!(sheet.get(Stat::Regen), 2.0);
+        assert_eq!(sheet.modifiers().len(), 1);
+    }
+
+    #[test]
+    fn results_are_clamped_per_stat() {
+        let mut sheet = StatSheet::default();
+        sheet
+            .add(Stat::MaxHealth, ModOp::Mul(0.0), A)
+            .add(Stat::Armor, ModOp::Add(-50.0), A)
+            .add(Stat::FuelCapacity, ModOp::Add(100.0), A);
+        assert_eq!(sheet.get(Stat::MaxHealth), 1.0);
+        assert_eq!(sheet.get(Stat::Armor), 0.0);
+        assert_eq!(sheet.get(Stat::FuelCapacity), 10.0);
+    }
+
+    #[test]
+    fn timed_modifiers_drop_off() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)))
+            .add_systems(Update, tick_stat_modifiers);
+        let mut sheet = StatSheet::default();
+        sheet
+            .set_base(Stat::MoveSpeed, 100.0)
+            .add_timed(Stat::MoveSpeed, ModOp::Mul(0.5), ModSource::Status("slowed"), 0.25)
+            .add(Stat::MoveSpeed, ModOp::Add(10.0), A);
+        let player = app.world_mut().spawn(sheet).id();
+
+        // The first update only starts the clock.
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(app.world().get::<StatSheet>(player).unwrap().get(Stat::MoveSpeed), 55.0);
+        app.update();
+        app.update();
+        assert_eq!(app.world().get::<StatSheet>(player).unwrap().get(Stat::MoveSpeed), 110.0);
+    }
+}
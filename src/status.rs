@@ -0,0 +1,347 @@
+use bevy::prelude::*;
+
+use crate::damage::{DamageEvent, DamageKind, DamageSource};
+use crate::enemies::EnemyMoveSpeed;
+use crate::stats::{ModOp, ModSource, Stat, StatSheet};
+use crate::GameState;
+
+/// Seconds between damage ticks for burning and poison.
+const TICK_SECS: f32 = 0.5;
+/// Slowed stacks up to this, then turns into a short freeze.
+const SLOW_STACKS_TO_FREEZE: u32 = 3;
+const FREEZE_SECS: f32 = 1.2;
+/// Extra damage taken per stack of Vulnerable.
+pub const VULNERABLE_PER_STACK: f32 = 0.25;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum StatusKind {
+    Burning,
+    Poisoned,
+    Slowed,
+    Stunned,
+    Frozen,
+    Vulnerable,
+}
+
+impl StatusKind {
+    pub const ALL: [StatusKind; 6] = [
+        StatusKind::Burning,
+        StatusKind::Poisoned,
+        StatusKind::Slowed,
+        StatusKind::Stunned,
+        StatusKind::Frozen,
+        StatusKind::Vulnerable,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            StatusKind::Burning => "Burning",
+            StatusKind::Poisoned => "Poisoned",
+            StatusKind::Slowed => "Slowed",
+            StatusKind::Stunned => "Stunned",
+            StatusKind::Frozen => "Frozen",
+            StatusKind::Vulnerable => "Vulnerable",
+        }
+    }
+
+    pub fn max_stacks(self) -> u32 {
+        match self {
+            StatusKind::Burning | StatusKind::Slowed | StatusKind::Vulnerable => 3,
+            StatusKind::Poisoned => 5,
+            StatusKind::Stunned | StatusKind::Frozen => 1,
+        }
+    }
+
+    /// Damage per second for each stack.
+    fn tick_dps(self) -> f32 {
+        match self {
+            StatusKind::Burning => 6.0,
+            StatusKind::Poisoned => 3.0,
+            _ => 0.0,
+        }
+    }
+
+    /// What the holder's move speed is multiplied by.
+    fn speed_mult(self) -> f32 {
+        match self {
+            StatusKind::Slowed => 0.35,
+            StatusKind::Stunned | StatusKind::Frozen => 0.0,
+            _ => 1.0,
+        }
+    }
+
+    /// Stunned and frozen things can't move or attack.
+    pub fn incapacitates(self) -> bool {
+        matches!(self, StatusKind::Stunned | StatusKind::Frozen)
+    }
+
+    fn tint(self) -> Color {
+        match self {
+            StatusKind::Burning => Color::srgb(1.0, 0.5, 0.15),
+            StatusKind::Poisoned => Color::srgb(0.4, 0.9, 0.3),
+            StatusKind::Slowed => Color::srgb(0.5, 0.8, 1.0),
+            StatusKind::Stunned => Color::srgb(1.0, 1.0, 0.4),
+            StatusKind::Frozen => Color::srgb(0.7, 0.95, 1.0),
+            StatusKind::Vulnerable => Color::srgb(0.9, 0.3, 0.6),
+        }
+    }
+
+    fn index(self) -> usize {
+        StatusKind::ALL.iter().position(|&k| k == self).unwrap_or(0)
+    }
+}
+
+/// Put `stacks` of `kind` on `target` for `secs`. Reapplying an effect the
+/// target already has adds stacks (up to the cap) and refreshes its timer.
+#[derive(Event, Clone, Copy)]
+pub struct ApplyStatusEvent {
+    pub target: Entity,
+    pub kind: StatusKind,
+    pub stacks: u32,
+    pub secs: f32,
+}
+
+#[derive(Clone, Debug)]
+pub struct ActiveStatus {
+    pub kind: StatusKind,
+    pub stacks: u32,
+    pub remaining: f32,
+    tick: f32,
+}
+
+/// Every effect currently on an entity. Added the first time anything is
+/// applied; the sprite colour and move speed it had then are what it goes
+/// back to once the effects wear off.
+#[derive(Component, Default)]
+pub struct StatusEffects {
+    active: Vec<ActiveStatus>,
+    base_color: Option<Color>,
+    base_speed: Option<f32>,
+    /// Set when an effect starts or ends, so speed and tint are only redone then.
+    dirty: bool,
+}
+
+impl StatusEffects {
+    pub fn stacks(&self, kind: StatusKind) -> u32 {
+        self.active.iter().find(|s| s.kind == kind).map_or(0, |s| s.stacks)
+    }
+
+    pub fn has(&self, kind: StatusKind) -> bool {
+        self.stacks(kind) > 0
+    }
+
+    pub fn incapacitated(&self) -> bool {
+        self.active.iter().any(|s| s.kind.incapacitates())
+    }
+
+    pub fn active(&self) -> &[ActiveStatus] {
+        &self.active
+    }
+
+    /// The effect that decides the tint: anything incapacitating first, then
+    /// the most stacks, then the most recently applied.
+    pub fn strongest(&self) -> Option<&ActiveStatus> {
+        self.active.iter().max_by_key(|s| (s.kind.incapacitates(), s.stacks))
+    }
+
+    fn apply(&mut self, kind: StatusKind, stacks: u32, secs: f32) {
+        self.dirty = true;
+        match self.active.iter_mut().find(|s| s.kind == kind) {
+            Some(s) => {
+                s.stacks = (s.stacks + stacks).min(kind.max_stacks());
+                s.remaining = s.remaining.max(secs);
+            }
+            None => self.active.push(ActiveStatus {
+                kind,
+                stacks: stacks.min(kind.max_stacks()),
+                remaining: secs,
+                tick: 0.0,
+            }),
+        }
+
+        // Piling on enough slows freezes the target solid.
+        if kind == StatusKind::Slowed && self.stacks(StatusKind::Slowed) >= SLOW_STACKS_TO_FREEZE {
+            self.active.retain(|s| s.kind != StatusKind::Slowed);
+            self.apply(StatusKind::Frozen, 1, FREEZE_SECS);
+        }
+    }
+
+    fn speed_mult(&self) -> f32 {
+        self.active.iter().map(|s| s.kind.speed_mult()).product()
+    }
+}
+
+/// Cuts how long effects last on an entity: 0.0 takes the full duration,
+/// 1.0 or more is immune.
+#[derive(Component, Clone, Copy, Default)]
+pub struct StatusResist([f32; StatusKind::ALL.len()]);
+
+impl StatusResist {
+    pub fn with(mut self, kind: StatusKind, resist: f32) -> Self {
+        self.0[kind.index()] = resist;
+        self
+    }
+
+    /// Immune to slows, stuns and freezes.
+    pub fn unstoppable() -> Self {
+        Self::default()
+            .with(StatusKind::Slowed, 1.0)
+            .with(StatusKind::Stunned, 1.0)
+            .with(StatusKind::Frozen, 1.0)
+    }
+
+    pub fn get(&self, kind: StatusKind) -> f32 {
+        self.0[kind.index()]
+    }
+}
+
+pub struct StatusPlugin;
+
+impl Plugin for StatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ApplyStatusEvent>()
+            .add_systems(
+                Update,
+                (apply_status_events, tick_status_effects, sync_status_effects)
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn apply_status_events(
+    mut commands: Commands,
+    mut events: EventReader<ApplyStatusEvent>,
+    mut targets: Query<(Option<&mut StatusEffects>, Option<&StatusResist>, Option<&Sprite>, Option<&EnemyMoveSpeed>)>,
+) {
+    for ev in events.read() {
+        let Ok((effects, resist, sprite, speed)) = targets.get_mut(ev.target) else { continue; };
+        let resist = resist.map_or(0.0, |r| r.get(ev.kind));
+        if resist >= 1.0 { continue; }
+        let secs = ev.secs * (1.0 - resist);
+
+        match effects {
+            Some(mut effects) => effects.apply(ev.kind, ev.stacks, secs),
+            None => {
+                let mut effects = StatusEffects {
+                    base_color: sprite.map(|s| s.color),
+                    base_speed: speed.map(|s| s.0),
+                    ..default()
+                };
+                effects.apply(ev.kind, ev.stacks, secs);
+                commands.entity(ev.target).try_insert(effects);
+            }
+        }
+    }
+}
+
+/// Counts effects down, sends burning and poison damage, and drops the
+/// ones that have run out.
+fn tick_status_effects(
+    time: Res<Time>,
+    mut targets: Query<(Entity, &mut StatusEffects)>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut effects) in &mut targets {
+        if effects.active.is_empty() { continue; }
+
+        for s in effects.active.iter_mut() {
+            s.remaining -= dt;
+            s.tick += dt;
+            if s.tick >= TICK_SECS {
+                s.tick -= TICK_SECS;
+                let amount = s.kind.tick_dps() * s.stacks as f32 * TICK_SECS;
+                if amount > 0.0 {
+                    damage_events.write(DamageEvent {
+                        source: DamageSource::Status(s.kind),
+                        target: entity,
+                        amount,
+                        kind: DamageKind::Status,
+                    });
+                }
+            }
+        }
+        let before = effects.active.len();
+        effects.active.retain(|s| s.remaining > 0.0);
+        if effects.active.len() != before {
+            effects.dirty = true;
+        }
+    }
+}
+
+/// Re-applies speed changes and the tint of the strongest effect whenever
+/// the set of effects changes. The player's speed goes through its stat
+/// sheet as timed modifiers, so a slow never outlives its effect even if
+/// the sheet is saved mid-slow; enemies scale their `EnemyMoveSpeed`.
+fn sync_status_effects(
+    mut targets: Query<(
+        &mut StatusEffects,
+        Option<&mut Sprite>,
+        Option<&mut EnemyMoveSpeed>,
+        Option<&mut StatSheet>,
+    )>,
+) {
+    for (mut effects, sprite, speed, sheet) in &mut targets {
+        if !effects.dirty { continue; }
+        effects.dirty = false;
+
+        if let Some(mut sheet) = sheet {
+            for kind in StatusKind::ALL {
+                sheet.remove_source(ModSource::Status(kind.name()));
+            }
+            for s in &effects.active {
+                let mult = s.kind.speed_mult();
+                if mult < 1.0 {
+                    sheet.add_timed(Stat::MoveSpeed, ModOp::Mul(mult), ModSource::Status(s.kind.name()), s.remaining);
+                }
+            }
+        }
+
+        if let (Some(mut speed), Some(base)) = (speed, effects.base_speed) {
+            speed.0 = base * effects.speed_mult();
+        }
+
+        if let (Some(mut sprite), Some(base)) = (sprite, effects.base_color) {
+            sprite.color = match effects.strongest() {
+                Some(s) => base.mix(&s.kind.tint(), 0.6),
+                None => base,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::damage::mitigate;
+
+    #[test]
+    fn vulnerable_stacks_raise_mitigated_damage() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusKind::Vulnerable, 2, 1.0);
+        assert_eq!(mitigate(10.0, Some(&effects)), 10.0 * (1.0 + 2.0 * VULNERABLE_PER_STACK));
+    }
+
+    #[test]
+    fn vulnerable_bonus_stops_at_max_stacks() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusKind::Vulnerable, 10, 1.0);
+        let max = StatusKind::Vulnerable.max_stacks() as f32;
+        assert_eq!(mitigate(10.0, Some(&effects)), 10.0 * (1.0 + max * VULNERABLE_PER_STACK));
+    }
+
+    #[test]
+    fn strongest_prefers_incapacitating_then_stacks() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusKind::Frozen, 1, 1.0);
+        effects.apply(StatusKind::Slowed, 1, 1.0);
+        effects.apply(StatusKind::Poisoned, 4, 1.0);
+        assert_eq!(effects.strongest().map(|s| s.kind), Some(StatusKind::Frozen));
+
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusKind::Poisoned, 4, 1.0);
+        effects.apply(StatusKind::Burning, 1, 1.0);
+        assert_eq!(effects.strongest().map(|s| s.kind), Some(StatusKind::Poisoned));
+    }
+}
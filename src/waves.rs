@@ -0,0 +1,193 @@
+use bevy::prelude::*;
+
+use crate::enemies::{EnemyRes, RangedEnemyRes, TurretRes, elite, spawn_enemy_at, spawn_ranged_enemy_at, spawn_turret_enemy_at};
+use crate::map::Door;
+use crate::room::{LevelState, Room, RoomVec, nearest_floor_pos};
+use crate::{GameEntity, GameState, TILE_SIZE, Z_ENTITIES};
+
+/// Reinforcement plan for a locked room. The first wave is spawned by
+/// `generate_enemies_in_room`; the rest arrive here once the living count
+/// drops to `threshold`.
+pub struct RoomWaves {
+    /// Sizes of the waves still to come, in order.
+    pub remaining: Vec<usize>,
+    /// Call in the next wave once this many enemies (or fewer) are left.
+    pub threshold: usize,
+    pub health_multiplier: f32,
+    pub speed_bonus: f32,
+    pub elite_chance: f32,
+    pub station_level: u32,
+    /// Floor tiles inside the room that reinforcements can crawl out of.
+    pub vents: Vec<Vec3>,
+    /// Keeps the chaser/ranger/turret mix going across waves.
+    pub next_spawn_idx: usize,
+}
+
+impl RoomWaves {
+    pub fn is_done(&self) -> bool {
+        self.remaining.is_empty()
+    }
+}
+
+/// Red marker where a reinforcement is about to appear.
+#[derive(Component)]
+pub struct WaveTelegraph {
+    timer: Timer,
+    room: usize,
+    spawn_idx: usize,
+}
+
+const TELEGRAPH_SECS: f32 = 1.25;
+
+/// Split a room's enemy budget into waves. Bigger rooms and later stations
+/// get more waves; the boss room keeps everything in one group because the
+/// boss summons its own reinforcements.
+pub fn plan_waves(room: &Room, budget: usize, station_level: u32) -> Vec<usize> {
+    if room.is_boss_room || budget <= 2 {
+        return vec![budget];
+    }
+
+    let w = (room.bot_right_corner.x - room.top_left_corner.x) / TILE_SIZE;
+    let h = (room.top_left_corner.y - room.bot_right_corner.y) / TILE_SIZE;
+    let size_waves = match w * h {
+        a if a < 1600.0 => 1,
+        a if a < 3000.0 => 2,
+        _ => 3,
+    };
+    let count = (size_waves + station_level as usize / 2).clamp(1, 4).min(budget / 2).max(1);
+
+    // Spread the budget evenly, front-loading any remainder.
+    (0..count)
+        .map(|i| budget / count + usize::from(i < budget % count))
+        .collect()
+}
+
+/// Enemy type follows the same 8-slot rotation as the first wave.
+pub fn spawn_wave_enemy(
+    commands: &mut Commands,
+    enemy_res: &EnemyRes,
+    ranged_res: &RangedEnemyRes,
+    turret_res: &TurretRes,
+    pos: Vec3,
+    spawn_idx: usize,
+    waves: &RoomWaves,
+) {
+    let (hp, speed) = (waves.health_multiplier, waves.speed_bonus);
+    let enemy = if spawn_idx % 8 == 6 {
+        spawn_turret_enemy_at(commands, turret_res, pos, true, hp, speed)
+    } else if spawn_idx % 8 == 2 {
+        spawn_ranged_enemy_at(commands, ranged_res, pos, true, hp, speed)
+    } else {
+        spawn_enemy_at(commands, enemy_res, pos, true, hp, speed)
+    };
+    if rand::random::<f32>() < waves.elite_chance {
+        elite::make_elite(commands, enemy, elite::roll_affixes(waves.station_level));
+    }
+}
+
+// ── Plugin ─────────────────────────────────────────────────────────────────
+
+pub struct WavePlugin;
+
+impl Plugin for WavePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                call_in_next_wave.before(crate::room::playing_room),
+                resolve_wave_telegraphs.after(call_in_next_wave),
+            )
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// Once the room is thinned out, telegraph the next wave at the doors and vents.
+/// Incoming enemies are counted in `numofenemies` straight away so the room
+/// can't clear while they're on their way.
+fn call_in_next_wave(
+    mut commands: Commands,
+    lvlstate: Res<LevelState>,
+    mut rooms: ResMut<RoomVec>,
+    doors: Query<&Transform, With<Door>>,
+    wall_grid: Res<crate::map::WallGrid>,
+    grid: Res<crate::map::MapGridMeta>,
+) {
+    let LevelState::InRoom(index, _) = *lvlstate else { return };
+    let room = &mut rooms.0[index];
+    let alive = room.numofenemies;
+    let centre = ((room.top_left_corner + room.bot_right_corner) * 0.5).extend(Z_ENTITIES);
+
+    let door_points: Vec<Vec3> = room.doors.iter()
+        .filter_map(|&d| doors.get(d).ok())
+        .map(|tf| {
+            // Step a couple of tiles in from the door so they appear on the floor.
+            let inward = (centre - tf.translation).truncate().normalize_or_zero() * TILE_SIZE * 2.0;
+            nearest_floor_pos(tf.translation.truncate() + inward, &wall_grid, &grid).extend(Z_ENTITIES)
+        })
+        .collect();
+
+    let Some(waves) = room.waves.as_mut() else { return };
+    if waves.is_done() || alive > waves.threshold {
+        return;
+    }
+
+    let mut points: Vec<Vec3> = door_points.iter().chain(waves.vents.iter()).copied().collect();
+    if points.is_empty() {
+        // No doors or vents to come through: bring them in around the middle
+        // rather than dropping the wave.
+        points.push(nearest_floor_pos(centre.truncate(), &wall_grid, &grid).extend(Z_ENTITIES));
+    }
+    let size = waves.remaining.remove(0);
+
+    for i in 0..size {
+        let base = points[i % points.len()];
+        // Fan out a little so a whole wave doesn't stack on one tile.
+        let jitter = Vec2::from_angle(i as f32 * 2.4) * TILE_SIZE * 0.5 * (i / points.len()) as f32;
+        let pos = nearest_floor_pos(base.truncate() + jitter, &wall_grid, &grid).extend(Z_ENTITIES);
+        commands.spawn((
+            Sprite {
+                color: Color::srgba(1.0, 0.15, 0.1, 0.6),
+                custom_size: Some(Vec2::splat(TILE_SIZE * 1.5)),
+                ..default()
+            },
+            Transform::from_translation(pos),
+            WaveTelegraph {
+                timer: Timer::from_seconds(TELEGRAPH_SECS, TimerMode::Once),
+                room: index,
+                spawn_idx: waves.next_spawn_idx,
+            },
+            GameEntity,
+        ));
+        waves.next_spawn_idx += 1;
+    }
+    room.numofenemies += size;
+}
+
+/// Pulses the telegraph markers and swaps them for enemies when they expire.
+fn resolve_wave_telegraphs(
+    mut commands: Commands,
+    time: Res<Time>,
+    rooms: Res<RoomVec>,
+    mut telegraphs: Query<(Entity, &Transform, &mut Sprite, &mut WaveTelegraph)>,
+    enemy_res: Res<EnemyRes>,
+    ranged_res: Res<RangedEnemyRes>,
+    turret_res: Res<TurretRes>,
+) {
+    for (entity, tf, mut sprite, mut telegraph) in &mut telegraphs {
+        telegraph.timer.tick(time.delta());
+        let pulse = (telegraph.timer.elapsed_secs() * 12.0).sin() * 0.5 + 0.5;
+        sprite.color.set_alpha(0.25 + 0.5 * pulse);
+
+        if !telegraph.timer.finished() {
+            continue;
+        }
+        commands.entity(entity).despawn();
+        if let Some(waves) = rooms.0.get(telegraph.room).and_then(|r| r.waves.as_ref()) {
+            spawn_wave_enemy(
+                &mut commands, &enemy_res, &ranged_res, &turret_res,
+                tf.translation, telegraph.spawn_idx, waves,
+            );
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use super::{Ammo, BulletDamage, TriggerMode, Weapon, WeaponBehavior, WeaponId};
+use crate::bullet::{Bullet, BulletOwner, Velocity, AnimationTimer, AnimationFrameCount, Piercing, HitEnemies};
+use crate::collidable::Collider;
+use crate::GameEntity;
+
+pub const ID: WeaponId = WeaponId("charge_cannon");
+
+const FULL_CHARGE_SECS: f32 = 1.2;
+/// A tap fires this fraction of full damage.
+const MIN_DAMAGE_FRAC: f32 = 0.25;
+/// Extra enemies a fully charged shot passes through, on top of pickups.
+const MAX_CHARGE_PIERCE: f32 = 4.0;
+const CHARGE_TINT: Color = Color::srgb(0.45, 0.85, 1.0);
+
+/// Hold to wind up, release to fire. Damage, size and pierce all grow with
+/// how long the trigger was held.
+#[derive(Default)]
+pub struct ChargeCannon {
+    bullet: Handle<Image>,
+    layout: Handle<TextureAtlasLayout>,
+    sound: Handle<AudioSource>,
+}
+
+impl WeaponBehavior for ChargeCannon {
+    fn id(&self) -> WeaponId { ID }
+
+    fn name(&self) -> &'static str { "Charge Cannon" }
+
+    fn base_weapon(&self) -> Weapon {
+        Weapon {
+            id: ID,
+            fire_rate: 0.3,
+            bullet_speed: 950.0,
+            damage: 90.0,
+            bullet_size: 0.3,
+            shoot_timer: Timer::from_seconds(0.3, TimerMode::Once),
+            piercing_pickups: 0,
+            bounce_pickups: 0,
+            charge: 0.0,
+            mods: Vec::new(),
+            ammo: Ammo::Battery { per_shot: 30.0 },
+        }
+    }
+
+    fn load(&mut self, asset_server: &AssetServer, atlases: &mut Assets<TextureAtlasLayout>) {
+        self.bullet = asset_server.load("bullet_animation.png");
+        self.layout = atlases.add(TextureAtlasLayout::from_grid(UVec2::splat(100), 3, 1, None, None));
+        self.sound = asset_server.load("audio/laser_zap.ogg");
+    }
+
+    fn fire(&self, commands: &mut Commands, weapon: &Weapon, pos: Vec2, dir: Vec2) {
+        let charge = weapon.charge.clamp(0.0, 1.0);
+        let damage = weapon.damage * (MIN_DAMAGE_FRAC + (1.0 - MIN_DAMAGE_FRAC) * charge);
+        let scale = weapon.bullet_size * (1.0 + charge * 1.5);
+        let pierce = weapon.effective_pierce_count() + (charge * MAX_CHARGE_PIERCE).floor() as u32;
+
+        let mut sprite = Sprite::from_atlas_image(
+            self.bullet.clone(),
+            TextureAtlas { layout: self.layout.clone(), index: 0 },
+        );
+        sprite.color = CHARGE_TINT;
+
+        let mut bullet = commands.spawn((
+            sprite,
+            Transform {
+                translation: Vec3::new(pos.x, pos.y, 910.0),
+                scale: Vec3::splat(scale),
+                ..Default::default()
+            },
+            AnimationTimer(Timer::from_seconds(0.1, TimerMode::Repeating)),
+            AnimationFrameCount(3),
+            Velocity(dir.normalize_or_zero() * weapon.bullet_speed),
+            Bullet,
+            BulletOwner::Player,
+            Collider { half_extents: Vec2::splat(6.0 + charge * 8.0) },
+            BulletDamage(damage),
+            HitEnemies::default(),
+            GameEntity,
+        ));
+        if pierce > 0 {
+            bullet.insert(Piercing(pierce));
+        }
+    }
+
+    fn sound(&self) -> Handle<AudioSource> { self.sound.clone() }
+
+    fn hud_icon(&self) -> ImageNode {
+        ImageNode::from_atlas_image(
+            self.bullet.clone(),
+            TextureAtlas { layout: self.layout.clone(), index: 0 },
+        )
+        .with_color(CHARGE_TINT)
+    }
+
+    fn trigger_mode(&self) -> TriggerMode {
+        TriggerMode::Charge { full_secs: FULL_CHARGE_SECS }
+    }
+}
@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+use super::{Ammo, TriggerMode, Weapon, WeaponBehavior, WeaponId};
+use crate::bullet::Piercing;
+use crate::collidable::Collider;
+use crate::damage::{DamageEvent, DamageKind, DamageSource};
+use crate::enemies::{Enemy, Reaper, ENEMY_SIZE};
+use crate::GameEntity;
+
+pub const ID: WeaponId = WeaponId("laser");
+
+const RANGE: f32 = 700.0;
+const BEAM_WIDTH: f32 = 6.0;
+const BEAM_TINT: Color = Color::srgb(1.0, 0.35, 0.35);
+
+/// Continuous hitscan beam. Deals `damage` per second to whatever it touches
+/// and stops at the first wall — or at an enemy once its pierce runs out.
+#[derive(Default)]
+pub struct Laser {
+    beam: Handle<Image>,
+    sound: Handle<AudioSource>,
+}
+
+/// One frame's worth of beam. A fresh one is spawned every frame the
+/// trigger is held; `trace_laser_beams` resolves it and clears it out the
+/// frame after.
+#[derive(Component)]
+pub struct LaserBeam {
+    origin: Vec2,
+    dir: Vec2,
+    dps: f32,
+    traced: bool,
+}
+
+impl WeaponBehavior for Laser {
+    fn id(&self) -> WeaponId { ID }
+
+    fn name(&self) -> &'static str { "Cutting Laser" }
+
+    fn base_weapon(&self) -> Weapon {
+        Weapon {
+            id: ID,
+            fire_rate: 0.25,
+            bullet_speed: 0.0,
+            damage: 55.0,
+            bullet_size: 1.0,
+            shoot_timer: Timer::from_seconds(0.25, TimerMode::Once),
+            piercing_pickups: 0,
+            bounce_pickups: 0,
+            charge: 0.0,
+            mods: Vec::new(),
+            ammo: Ammo::Battery { per_shot: 5.0 },
+        }
+    }
+
+    fn load(&mut self, asset_server: &AssetServer, _atlases: &mut Assets<TextureAtlasLayout>) {
+        self.beam = asset_server.load("beam.png");
+        self.sound = asset_server.load("audio/laser_zap.ogg");
+    }
+
+    fn fire(&self, commands: &mut Commands, weapon: &Weapon, pos: Vec2, dir: Vec2) {
+        let mut sprite = Sprite::from_image(self.beam.clone());
+        sprite.color = BEAM_TINT;
+        sprite.custom_size = Some(Vec2::ZERO);
+
+        let mut beam = commands.spawn((
+            sprite,
+            Transform::from_xyz(pos.x, pos.y, 910.0),
+            LaserBeam {
+                origin: pos,
+                dir: dir.normalize_or(Vec2::X),
+                dps: weapon.damage,
+                traced: false,
+            },
+            GameEntity,
+        ));
+        let pierce = weapon.effective_pierce_count();
+        if pierce > 0 {
+            beam.insert(Piercing(pierce));
+        }
+    }
+
+    fn sound(&self) -> Handle<AudioSource> { self.sound.clone() }
+
+    fn hud_icon(&self) -> ImageNode {
+        ImageNode::new(self.beam.clone()).with_color(BEAM_TINT)
+    }
+
+    fn trigger_mode(&self) -> TriggerMode {
+        TriggerMode::Continuous
+    }
+}
+
+/// Entry distance of a ray into an axis-aligned box, if it hits at all.
+fn ray_hits_box(origin: Vec2, dir: Vec2, center: Vec2, half: Vec2) -> Option<f32> {
+    let inv = Vec2::new(1.0 / dir.x, 1.0 / dir.y);
+    let t1 = (center - half - origin) * inv;
+    let t2 = (center + half - origin) * inv;
+    let near = t1.min(t2).max_element();
+    let far = t1.max(t2).min_element();
+    (far >= near.max(0.0)).then_some(near.max(0.0))
+}
+
+/// Raycasts each new beam against the walls and enemies, applies this
+/// frame's damage and stretches the sprite to where the beam stopped.
+pub fn trace_laser_beams(
+    mut commands: Commands,
+    time: Res<Time>,
+    wall_grid: Res<crate::map::WallGrid>,
+    mut beams: Query<(Entity, &mut LaserBeam, &mut Sprite, &mut Transform, Option<&Piercing>)>,
+    enemies: Query<
+        (Entity, &Transform, Option<&Collider>),
+        (With<Enemy>, Without<Reaper>, Without<LaserBeam>),
+    >,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    for (entity, mut beam, mut sprite, mut tf, piercing) in &mut beams {
+        if beam.traced {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        beam.traced = true;
+
+        let wall_dist = wall_grid.raycast(beam.origin, beam.dir, RANGE);
+
+        let mut hits: Vec<(f32, Entity)> = Vec::new();
+        for (enemy, enemy_tf, collider) in &enemies {
+            let half = collider.map_or(Vec2::splat(ENEMY_SIZE * 0.5), |c| c.half_extents);
+            if let Some(d) = ray_hits_box(beam.origin, beam.dir, enemy_tf.translation.truncate(), half)
+                && d < wall_dist
+            {
+                hits.push((d, enemy));
+            }
+        }
+        hits.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        // Piercing(n) lets the beam pass through n enemies and stop in the next.
+        let max_hits = piercing.map_or(1, |p| p.0 as usize + 1);
+        let mut length = wall_dist;
+        for (i, (d, enemy)) in hits.into_iter().take(max_hits).enumerate() {
+            damage_events.write(DamageEvent {
+                source: DamageSource::Player,
+                target: enemy,
+                amount: beam.dps * time.delta_secs(),
+                kind: DamageKind::Beam,
+            });
+            if i + 1 == max_hits {
+                length = d + ENEMY_SIZE * 0.25;
+            }
+        }
+
+        let angle = beam.dir.y.atan2(beam.dir.x);
+        let mid = beam.origin + beam.dir * length * 0.5;
+        sprite.custom_size = Some(Vec2::new(length, BEAM_WIDTH));
+        tf.translation = mid.extend(tf.translation.z);
+        tf.rotation = Quat::from_rotation_z(angle);
+    }
+}
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+use super::{Ammo, BulletDamage, Weapon, WeaponBehavior, WeaponId};
+use crate::bullet::{Bullet, BulletOwner, Velocity, AnimationTimer, AnimationFrameCount, HitEnemies, MarkedForDespawn};
+use crate::collidable::Collider;
+use crate::explosion::ExplosionEvent;
+use crate::GameEntity;
+
+pub const ID: WeaponId = WeaponId("launcher");
+
+const BLAST_RADIUS: f32 = 130.0;
+const BLAST_KNOCKBACK: f32 = 1100.0;
+const FUSE_SECS: f32 = 1.4;
+const ROCKET_TINT: Color = Color::srgb(1.0, 0.55, 0.2);
+
+/// Slow-firing launcher. Rockets burst on whatever they touch (or when the
+/// fuse runs out) and the blast shoves everything nearby — player included.
+#[derive(Default)]
+pub struct Launcher {
+    bullet: Handle<Image>,
+    layout: Handle<TextureAtlasLayout>,
+    sound: Handle<AudioSource>,
+}
+
+#[derive(Component)]
+pub struct Rocket {
+    pub damage: f32,
+    fuse: Timer,
+}
+
+impl WeaponBehavior for Launcher {
+    fn id(&self) -> WeaponId { ID }
+
+    fn name(&self) -> &'static str { "Launcher" }
+
+    fn base_weapon(&self) -> Weapon {
+        Weapon {
+            id: ID,
+            fire_rate: 0.9,
+            bullet_speed: 650.0,
+            damage: 35.0,
+            bullet_size: 0.45,
+            shoot_timer: Timer::from_seconds(0.9, TimerMode::Once),
+            piercing_pickups: 0,
+            bounce_pickups: 0,
+            charge: 0.0,
+            mods: Vec::new(),
+            ammo: Ammo::magazine(3, 9, 1.8),
+        }
+    }
+
+    fn load(&mut self, asset_server: &AssetServer, atlases: &mut Assets<TextureAtlasLayout>) {
+        self.bullet = asset_server.load("bullet_animation.png");
+        self.layout = atlases.add(TextureAtlasLayout::from_grid(UVec2::splat(100), 3, 1, None, None));
+        self.sound = asset_server.load("audio/shoot.ogg");
+    }
+
+    fn fire(&self, commands: &mut Commands, weapon: &Weapon, pos: Vec2, dir: Vec2) {
+        let mut sprite = Sprite::from_atlas_image(
+            self.bullet.clone(),
+            TextureAtlas { layout: self.layout.clone(), index: 0 },
+        );
+        sprite.color = ROCKET_TINT;
+
+        commands.spawn((
+            sprite,
+            Transform {
+                translation: Vec3::new(pos.x, pos.y, 910.0),
+                scale: Vec3::splat(weapon.bullet_size),
+                ..Default::default()
+            },
+            AnimationTimer(Timer::from_seconds(0.1, TimerMode::Repeating)),
+            AnimationFrameCount(3),
+            Velocity(dir.normalize_or_zero() * weapon.bullet_speed),
+            Bullet,
+            BulletOwner::Player,
+            Collider { half_extents: Vec2::splat(8.0) },
+            // Direct hits do half; the blast does the rest.
+            BulletDamage(weapon.damage * 0.5),
+            HitEnemies::default(),
+            Rocket {
+                damage: weapon.damage,
+                fuse: Timer::from_seconds(FUSE_SECS, TimerMode::Once),
+            },
+            GameEntity,
+        ));
+    }
+
+    fn sound(&self) -> Handle<AudioSource> { self.sound.clone() }
+
+    fn hud_icon(&self) -> ImageNode {
+        ImageNode::from_atlas_image(
+            self.bullet.clone(),
+            TextureAtlas { layout: self.layout.clone(), index: 0 },
+        )
+        .with_color(ROCKET_TINT)
+    }
+}
+
+/// Rockets explode when `bullet_collision` marks them (they hit something)
+/// or when their fuse runs out mid-air.
+pub fn detonate_rockets(
+    mut commands: Commands,
+    time: Res<Time>,
+    hit: Query<(&Transform, &Rocket), Added<MarkedForDespawn>>,
+    mut flying: Query<(Entity, &Transform, &mut Rocket), Without<MarkedForDespawn>>,
+    mut explosions: EventWriter<ExplosionEvent>,
+) {
+    let blast = |pos: Vec2, damage: f32| ExplosionEvent {
+        pos,
+        radius: BLAST_RADIUS,
+        damage,
+        knockback: BLAST_KNOCKBACK,
+        hurts_player: false,
+    };
+
+    for (tf, rocket) in &hit {
+        explosions.write(blast(tf.translation.truncate(), rocket.damage));
+    }
+
+    for (entity, tf, mut rocket) in &mut flying {
+        rocket.fuse.tick(time.delta());
+        if rocket.fuse.finished() {
+            explosions.write(blast(tf.translation.truncate(), rocket.damage));
+            commands.entity(entity).try_insert(MarkedForDespawn);
+        }
+    }
+}
@@ -1,47 +1,209 @@
 pub mod zapper;
 pub mod beam_rifle;
-
-pub use beam_rifle::BeamRifleRes;
+pub mod launcher;
+pub mod shotgun;
+pub mod charge_cannon;
+pub mod laser;
+pub mod mods;
 
 use bevy::prelude::*;
 use crate::GameEntity;
-use crate::bullet::{Bullet, BulletOwner, Velocity, AnimationTimer, AnimationFrameCount, Piercing, HitEnemies};
-use crate::collidable::Collider;
+use crate::stats::{Stat, StatSheet};
 
 #[derive(Component, Clone)]
 pub struct Weapon {
-    pub weapon_type: WeaponType,
+    pub id: WeaponId,
     pub fire_rate: f32,
     pub bullet_speed: f32,
     pub damage: f32,
     pub bullet_size: f32,
     pub shoot_timer: Timer,
+    /// Piercing pickups. Filled in from the stat sheet on the copy that
+    /// `Weapon::scaled` hands to `WeaponBehavior::fire`.
     pub piercing_pickups: u32,
+    /// Wall bounces per bullet from Ricochet Rounds pickups.
+    pub bounce_pickups: u32,
+    /// 0..1 — how far a charge weapon has wound up. Unused by other kinds.
+    pub charge: f32,
+    pub ammo: Ammo,
+    /// Installed mods, at most `mods::MOD_SLOTS`.
+    pub mods: Vec<mods::WeaponMod>,
 }
 
-#[derive(Clone, Copy, PartialEq, Debug)]
-pub enum WeaponType {
-    Zapper,
-    BeamRifle,
+/// What limits how much a weapon can fire. Each variant carries both its
+/// tuning and its live state, so every weapon tracks its own.
+#[derive(Clone, Debug)]
+pub enum Ammo {
+    /// Fires forever, paced only by `shoot_timer`.
+    Unlimited,
+    /// Shots come out of a magazine; an empty magazine reloads from `reserve`.
+    Magazine {
+        size: u32,
+        loaded: u32,
+        reserve: u32,
+        max_reserve: u32,
+        reload: Timer,
+        reloading: bool,
+    },
+    /// Each shot adds heat. Reaching 1.0 locks the weapon until it has fully cooled.
+    Heat {
+        heat: f32,
+        per_shot: f32,
+        cool_per_sec: f32,
+        overheated: bool,
+    },
+    /// Draws from the inventory's shared `Battery`.
+    Battery { per_shot: f32 },
 }
 
-impl WeaponType {
-    pub fn name(self) -> &'static str {
+impl Ammo {
+    pub fn magazine(size: u32, reserve: u32, reload_secs: f32) -> Self {
+        Ammo::Magazine {
+            size,
+            loaded: size,
+            reserve,
+            max_reserve: reserve,
+            reload: Timer::from_seconds(reload_secs, TimerMode::Once),
+            reloading: false,
+        }
+    }
+
+    pub fn heat(per_shot: f32, cool_per_sec: f32) -> Self {
+        Ammo::Heat { heat: 0.0, per_shot, cool_per_sec, overheated: false }
+    }
+
+    /// Starts a reload if there is anything to reload. Returns whether it did.
+    pub fn start_reload(&mut self) -> bool {
         match self {
-            WeaponType::Zapper => "Zapper",
-            WeaponType::BeamRifle => "Beam Rifle",
+            Ammo::Magazine { size, loaded, reserve, reload, reloading, .. }
+                if !*reloading && *loaded < *size && *reserve > 0 =>
+            {
+                reload.reset();
+                *reloading = true;
+                true
+            }
+            _ => false,
         }
     }
-}
 
-impl Weapon {
-    pub fn new(weapon_type: WeaponType) -> Self {
-        match weapon_type {
-            WeaponType::Zapper => zapper::new(),
-            WeaponType::BeamRifle => beam_rifle::new(),
+    /// Top up spare ammo by `mags` magazines' worth. Returns whether anything changed.
+    pub fn refill(&mut self, mags: u32) -> bool {
+        match self {
+            Ammo::Magazine { size, reserve, max_reserve, .. } if *reserve < *max_reserve => {
+                *reserve = (*reserve + *size * mags).min(*max_reserve);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn tick(&mut self, delta: std::time::Duration) {
+        match self {
+            Ammo::Magazine { size, loaded, reserve, reload, reloading, .. } => {
+                if *reloading {
+                    reload.tick(delta);
+                    if reload.finished() {
+                        let moved = (*size - *loaded).min(*reserve);
+                        *loaded += moved;
+                        *reserve -= moved;
+                        *reloading = false;
+                    }
+                } else if *loaded == 0 && *reserve > 0 {
+                    // Empty magazines reload on their own.
+                    reload.reset();
+                    *reloading = true;
+                }
+            }
+            Ammo::Heat { heat, cool_per_sec, overheated, .. } => {
+                *heat = (*heat - *cool_per_sec * delta.as_secs_f32()).max(0.0);
+                if *heat == 0.0 {
+                    *overheated = false;
+                }
+            }
+            Ammo::Unlimited | Ammo::Battery { .. } => {}
         }
     }
+}
 
+/// Energy pool shared by every battery-powered weapon the player carries.
+#[derive(Clone, Debug)]
+pub struct Battery {
+    pub current: f32,
+    pub max: f32,
+    pub recharge_per_sec: f32,
+}
+
+impl Default for Battery {
+    fn default() -> Self {
+        Self { current: 100.0, max: 100.0, recharge_per_sec: 12.0 }
+    }
+}
+
+/// Stable identifier for a kind of weapon. Each weapon module exports its own `ID`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct WeaponId(pub &'static str);
+
+/// How a weapon responds to the fire button being held.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TriggerMode {
+    /// Fires whenever the button is down and the shot timer allows.
+    Auto,
+    /// Winds up over `full_secs` while held and fires on release.
+    Charge { full_secs: f32 },
+    /// Fires every frame while held; the shot timer only paces the sound.
+    Continuous,
+}
+
+/// Everything that makes one weapon kind different from another: base stats,
+/// how it fires, what it sounds like and its HUD icon. Implement it in the
+/// weapon's own module and register it in `WeaponPlugin::build`.
+pub trait WeaponBehavior: Send + Sync + 'static {
+    fn id(&self) -> WeaponId;
+    fn name(&self) -> &'static str;
+    /// A fresh weapon of this kind with its base stats.
+    fn base_weapon(&self) -> Weapon;
+    /// Load sprites and sounds. Called once at startup.
+    fn load(&mut self, asset_server: &AssetServer, atlases: &mut Assets<TextureAtlasLayout>);
+    /// Spawn whatever the weapon shoots from `pos` toward `dir`.
+    fn fire(&self, commands: &mut Commands, weapon: &Weapon, pos: Vec2, dir: Vec2);
+    fn sound(&self) -> Handle<AudioSource>;
+    fn hud_icon(&self) -> ImageNode;
+    fn trigger_mode(&self) -> TriggerMode {
+        TriggerMode::Auto
+    }
+}
+
+/// All known weapon kinds, looked up by `WeaponId`.
+#[derive(Resource, Default)]
+pub struct WeaponRegistry {
+    behaviors: Vec<Box<dyn WeaponBehavior>>,
+}
+
+impl WeaponRegistry {
+    pub fn register(&mut self, behavior: impl WeaponBehavior) -> &mut Self {
+        self.behaviors.push(Box::new(behavior));
+        self
+    }
+
+    pub fn get(&self, id: WeaponId) -> Option<&dyn WeaponBehavior> {
+        self.behaviors.iter().find(|b| b.id() == id).map(|b| b.as_ref())
+    }
+
+    /// A new weapon of the given kind, or `None` if nothing registered that id.
+    pub fn create(&self, id: WeaponId) -> Option<Weapon> {
+        self.get(id).map(|b| b.base_weapon())
+    }
+
+    pub fn ids(&self) -> impl Iterator<Item = WeaponId> + '_ {
+        self.behaviors.iter().map(|b| b.id())
+    }
+
+    pub fn name(&self, id: WeaponId) -> &'static str {
+        self.get(id).map_or("???", |b| b.name())
+    }
+}
+
+impl Weapon {
     pub fn can_shoot(&self) -> bool {
         self.shoot_timer.finished()
     }
@@ -52,6 +214,16 @@ impl Weapon {
 
     pub fn tick(&mut self, delta: std::time::Duration) {
         self.shoot_timer.tick(delta);
+        self.ammo.tick(delta);
+    }
+
+    /// This weapon as the stat sheet says it should fire: damage scaled and
+    /// the sheet's piercing added. The weapon itself keeps its base stats.
+    pub fn scaled(&self, sheet: &StatSheet) -> Weapon {
+        let mut weapon = self.clone();
+        weapon.damage *= sheet.get(Stat::WeaponDamage);
+        weapon.piercing_pickups += sheet.get(Stat::Pierce) as u32;
+        weapon
     }
 
     pub fn effective_pierce_count(&self) -> u32 {
@@ -60,15 +232,56 @@ impl Weapon {
     }
 }
 
-#[derive(Component)]
+#[derive(Component, Clone)]
 pub struct WeaponInventory {
     pub weapons: Vec<Weapon>,
     pub equipped: usize,
+    /// (pos, dir) if the trigger was held this frame. Set by `fire_weapon`,
+    /// consumed by `update_weapon_charge`.
+    pub held_aim: Option<(Vec2, Vec2)>,
+    /// Where a charge weapon was last aimed, so the release has a direction.
+    pub charge_aim: (Vec2, Vec2),
+    pub battery: Battery,
 }
 
 impl WeaponInventory {
     pub fn new(weapon: Weapon) -> Self {
-        Self { weapons: vec![weapon], equipped: 0 }
+        Self {
+            weapons: vec![weapon],
+            equipped: 0,
+            held_aim: None,
+            charge_aim: (Vec2::ZERO, Vec2::X),
+            battery: Battery::default(),
+        }
+    }
+
+    /// Whether the equipped weapon has the ammo, heat headroom or battery
+    /// charge for another shot.
+    pub fn has_ammo(&self) -> bool {
+        match &self.current().ammo {
+            Ammo::Unlimited => true,
+            Ammo::Magazine { loaded, reloading, .. } => *loaded > 0 && !*reloading,
+            Ammo::Heat { overheated, .. } => !*overheated,
+            Ammo::Battery { per_shot } => self.battery.current >= *per_shot,
+        }
+    }
+
+    /// Pay for one shot of the equipped weapon.
+    pub fn spend_shot(&mut self) {
+        let weapon = &mut self.weapons[self.equipped];
+        match &mut weapon.ammo {
+            Ammo::Unlimited => {}
+            Ammo::Magazine { loaded, .. } => *loaded = loaded.saturating_sub(1),
+            Ammo::Heat { heat, per_shot, overheated, .. } => {
+                *heat = (*heat + *per_shot).min(1.0);
+                if *heat >= 1.0 {
+                    *overheated = true;
+                }
+            }
+            Ammo::Battery { per_shot } => {
+                self.battery.current = (self.battery.current - *per_shot).max(0.0);
+            }
+        }
     }
 
     pub fn current(&self) -> &Weapon {
@@ -81,18 +294,13 @@ impl WeaponInventory {
 
     pub fn cycle_next(&mut self) {
         if self.weapons.len() > 1 {
+            // Swapping away drops any wind-up.
+            self.current_mut().charge = 0.0;
             self.equipped = (self.equipped + 1) % self.weapons.len();
         }
     }
-
-    pub fn equipped_name(&self) -> &'static str {
-        self.current().weapon_type.name()
-    }
 }
 
-#[derive(Resource)]
-pub struct BulletRes(pub Handle<Image>, pub Handle<TextureAtlasLayout>);
-
 #[derive(Resource)]
 pub struct EnemyBulletRes(pub Handle<Image>, pub Handle<TextureAtlasLayout>);
 
@@ -105,17 +313,68 @@ pub struct WeaponSounds {
 #[derive(Component)]
 pub struct WeaponNameDisplay;
 
+#[derive(Component)]
+pub struct WeaponIconDisplay;
+
+/// Holds the resource bar; hidden for weapons with unlimited ammo.
+#[derive(Component)]
+pub struct WeaponAmmoBar;
+
+#[derive(Component)]
+pub struct WeaponAmmoFill;
+
+#[derive(Component)]
+pub struct WeaponAmmoText;
+
 pub struct WeaponPlugin;
 
 impl Plugin for WeaponPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, load_weapon_assets)
-            .add_systems(OnEnter(crate::GameState::Playing), spawn_weapon_hud)
+        let mut registry = WeaponRegistry::default();
+        registry
+            .register(zapper::Zapper::default())
+            .register(beam_rifle::BeamRifle::default())
+            .register(launcher::Launcher::default())
+            .register(shotgun::Shotgun::default())
+            .register(charge_cannon::ChargeCannon::default())
+            .register(laser::Laser::default());
+
+        app.insert_resource(registry)
+            .add_event::<mods::BulletHitEvent>()
+            .add_systems(Startup, load_weapon_assets)
+            .add_systems(OnEnter(crate::GameState::Playing), (spawn_weapon_hud, mods::spawn_inspect_panel))
+            .add_systems(
+                Update,
+                (
+                    update_weapon_timers,
+                    update_weapon_hud,
+                    update_ammo_hud,
+                    cycle_weapons,
+                    reload_weapon,
+                    mods::update_inspect_panel,
+                )
+                    .run_if(in_state(crate::GameState::Playing))
+                    .run_if(not(resource_exists::<crate::pause::IsPaused>)),
+            )
             .add_systems(
                 Update,
-                (update_weapon_timers, update_weapon_hud, cycle_weapons)
+                update_weapon_charge
+                    .after(crate::aim::fire_held_weapon)
                     .run_if(in_state(crate::GameState::Playing))
                     .run_if(not(resource_exists::<crate::pause::IsPaused>)),
+            )
+            .add_systems(
+                Update,
+                (
+                    launcher::detonate_rockets.after(crate::bullet::bullet_collision),
+                    shotgun::pellet_falloff.before(crate::bullet::bullet_collision),
+                    laser::trace_laser_beams
+                        .after(crate::aim::fire_held_weapon),
+                    mods::apply_bullet_mods.after(update_weapon_charge),
+                    mods::resolve_bullet_mod_hits.after(crate::bullet::bullet_collision),
+                    mods::fade_chain_arcs,
+                )
+                    .run_if(in_state(crate::GameState::Playing)),
             );
     }
 }
@@ -124,11 +383,11 @@ fn load_weapon_assets(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut texture_atlases: ResMut<Assets<TextureAtlasLayout>>,
+    mut registry: ResMut<WeaponRegistry>,
 ) {
-    let bullet_animate_image: Handle<Image> = asset_server.load("bullet_animation.png");
-    let bullet_animate_layout = TextureAtlasLayout::from_grid(UVec2::splat(100), 3, 1, None, None);
-    let bullet_animate_handle = texture_atlases.add(bullet_animate_layout);
-    commands.insert_resource(BulletRes(bullet_animate_image, bullet_animate_handle));
+    for behavior in registry.behaviors.iter_mut() {
+        behavior.load(&asset_server, &mut texture_atlases);
+    }
 
     let enemy_bullet_image: Handle<Image> = asset_server.load("enemy_bullet_animation.png");
     let enemy_bullet_layout = TextureAtlasLayout::from_grid(UVec2::splat(100), 3, 1, None, None);
@@ -138,26 +397,61 @@ fn load_weapon_assets(
     let laser_sound: Handle<AudioSource> = asset_server.load("audio/laser_zap.ogg");
     let shoot_sound: Handle<AudioSource> = asset_server.load("audio/shoot.ogg");
     commands.insert_resource(WeaponSounds { laser: laser_sound, shoot: shoot_sound });
-
-    let beam_bullet = asset_server.load("beam.png");
-    commands.insert_resource(BeamRifleRes { bullet: beam_bullet });
 }
 
 fn spawn_weapon_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
     let font: Handle<Font> = asset_server.load(crate::FONT_PATH);
-    commands.spawn((
-        Text::new("Zapper"),
-        TextFont { font, font_size: 20.0, ..default() },
-        TextColor(Color::WHITE),
-        Node {
-            position_type: PositionType::Absolute,
-            bottom: Val::Px(10.0),
-            left: Val::Px(10.0),
-            ..default()
-        },
-        WeaponNameDisplay,
-        GameEntity,
-    ));
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(10.0),
+                left: Val::Px(10.0),
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(8.0),
+                ..default()
+            },
+            GameEntity,
+        ))
+        .with_children(|row| {
+            row.spawn((
+                ImageNode::default(),
+                Node { width: Val::Px(28.0), height: Val::Px(28.0), ..default() },
+                WeaponIconDisplay,
+            ));
+            row.spawn((
+                Text::new(""),
+                TextFont { font: font.clone(), font_size: 20.0, ..default() },
+                TextColor(Color::WHITE),
+                WeaponNameDisplay,
+            ));
+            row.spawn((
+                Node {
+                    width: Val::Px(80.0),
+                    height: Val::Px(8.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.1, 0.1, 0.1, 0.8)),
+                WeaponAmmoBar,
+            ))
+            .with_children(|bar| {
+                bar.spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::WHITE),
+                    WeaponAmmoFill,
+                ));
+            });
+            row.spawn((
+                Text::new(""),
+                TextFont { font, font_size: 14.0, ..default() },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                WeaponAmmoText,
+            ));
+        });
 }
 
 fn update_weapon_timers(
@@ -168,16 +462,90 @@ fn update_weapon_timers(
         for weapon in &mut inv.weapons {
             weapon.tick(time.delta());
         }
+        let battery = &mut inv.battery;
+        battery.current = (battery.current + battery.recharge_per_sec * time.delta_secs()).min(battery.max);
     }
 }
 
 fn update_weapon_hud(
+    registry: Res<WeaponRegistry>,
     player_q: Query<&WeaponInventory, With<crate::player::Player>>,
     mut text_q: Query<&mut Text, With<WeaponNameDisplay>>,
+    mut icon_q: Query<&mut ImageNode, With<WeaponIconDisplay>>,
 ) {
     let Ok(inv) = player_q.single() else { return; };
     let Ok(mut text) = text_q.single_mut() else { return; };
-    text.0 = inv.equipped_name().to_string();
+    let id = inv.current().id;
+    let name = registry.name(id);
+    let label = match inv.current().charge {
+        c if c > 0.0 => format!("{name} {:.0}%", c * 100.0),
+        _ => name.to_string(),
+    };
+    if text.0 == label { return; }
+
+    text.0 = label;
+    if let (Ok(mut icon), Some(behavior)) = (icon_q.single_mut(), registry.get(id)) {
+        *icon = behavior.hud_icon();
+    }
+}
+
+/// Fills the bar beside the weapon name: magazine, heat or shared battery,
+/// depending on what the equipped weapon runs on.
+fn update_ammo_hud(
+    player_q: Query<&WeaponInventory, With<crate::player::Player>>,
+    mut bar_q: Query<&mut Node, (With<WeaponAmmoBar>, Without<WeaponAmmoFill>)>,
+    mut fill_q: Query<(&mut Node, &mut BackgroundColor), With<WeaponAmmoFill>>,
+    mut text_q: Query<&mut Text, With<WeaponAmmoText>>,
+) {
+    let Ok(inv) = player_q.single() else { return; };
+    let (Ok(mut bar), Ok((mut fill, mut color)), Ok(mut text)) =
+        (bar_q.single_mut(), fill_q.single_mut(), text_q.single_mut())
+    else {
+        return;
+    };
+
+    let (frac, fill_color, label) = match &inv.current().ammo {
+        Ammo::Unlimited => {
+            bar.display = Display::None;
+            text.0.clear();
+            return;
+        }
+        Ammo::Magazine { size, loaded, reserve, reload, reloading, .. } => {
+            if *reloading {
+                (reload.fraction(), Color::srgb(0.6, 0.6, 0.6), "RELOADING".to_string())
+            } else {
+                (*loaded as f32 / *size as f32, Color::srgb(1.0, 0.85, 0.3), format!("{loaded}/{reserve}"))
+            }
+        }
+        Ammo::Heat { heat, overheated, .. } => {
+            let color = if *overheated {
+                Color::srgb(1.0, 0.15, 0.1)
+            } else {
+                Color::srgb(1.0, 0.6 - heat * 0.4, 0.2)
+            };
+            (*heat, color, if *overheated { "OVERHEAT".to_string() } else { String::new() })
+        }
+        Ammo::Battery { .. } => {
+            let b = &inv.battery;
+            (b.current / b.max, Color::srgb(0.35, 0.7, 1.0), format!("{:.0}%", b.current / b.max * 100.0))
+        }
+    };
+
+    bar.display = Display::Flex;
+    fill.width = Val::Percent(frac.clamp(0.0, 1.0) * 100.0);
+    color.0 = fill_color;
+    if text.0 != label {
+        text.0 = label;
+    }
+}
+
+fn reload_weapon(
+    input: Res<ButtonInput<KeyCode>>,
+    mut player_q: Query<&mut WeaponInventory, With<crate::player::Player>>,
+) {
+    if !input.just_pressed(KeyCode::KeyR) { return; }
+    let Ok(mut inv) = player_q.single_mut() else { return; };
+    inv.current_mut().ammo.start_reload();
 }
 
 fn cycle_weapons(
@@ -189,69 +557,79 @@ fn cycle_weapons(
     inv.cycle_next();
 }
 
+/// Call every frame the fire button is held. Auto weapons shoot whenever
+/// their timer allows and continuous weapons fire every frame. Charge
+/// weapons only note the aim here; `update_weapon_charge` winds them up and
+/// lets the shot go on release.
 pub fn fire_weapon(
     commands: &mut Commands,
     inventory: &mut WeaponInventory,
-    bullet_res: &BulletRes,
-    beam_res: &BeamRifleRes,
-    weapon_sounds: &WeaponSounds,
+    sheet: &StatSheet,
+    registry: &WeaponRegistry,
     pos: Vec2,
     dir: Vec2,
 ) {
-    let sound = {
-        let weapon = inventory.current();
-        match weapon.weapon_type {
-            WeaponType::Zapper => {
-                spawn_bullet(commands, bullet_res, weapon, pos, dir);
-                weapon_sounds.laser.clone()
-            }
-            WeaponType::BeamRifle => {
-                beam_rifle::spawn_bullet(commands, beam_res, weapon, pos, dir);
-                weapon_sounds.shoot.clone()
+    inventory.held_aim = Some((pos, dir));
+    let weapon = inventory.current();
+    let Some(behavior) = registry.get(weapon.id) else {
+        warn!("No weapon registered for {:?}", weapon.id);
+        return;
+    };
+
+    match behavior.trigger_mode() {
+        TriggerMode::Auto => {
+            if !weapon.can_shoot() || !inventory.has_ammo() { return; }
+            behavior.fire(commands, &weapon.scaled(sheet), pos, dir);
+            play_shot(commands, inventory, sheet, behavior);
+        }
+        TriggerMode::Continuous => {
+            // Ammo is paid on the shot timer's beat, not every frame.
+            if !inventory.has_ammo() { return; }
+            behavior.fire(commands, &weapon.scaled(sheet), pos, dir);
+            if weapon.can_shoot() {
+                play_shot(commands, inventory, sheet, behavior);
             }
         }
-    };
-    commands.spawn((AudioPlayer::new(sound), PlaybackSettings::DESPAWN));
-    inventory.current_mut().reset_timer();
+        TriggerMode::Charge { .. } => {}
+    }
 }
 
-pub fn spawn_bullet(
-    commands: &mut Commands,
-    bullet_res: &BulletRes,
-    weapon: &Weapon,
-    pos: Vec2,
-    dir: Vec2,
+/// Pays for the shot and restarts the shot timer at the weapon's delay
+/// sped up by the sheet's fire rate.
+fn play_shot(commands: &mut Commands, inventory: &mut WeaponInventory, sheet: &StatSheet, behavior: &dyn WeaponBehavior) {
+    commands.spawn((AudioPlayer::new(behavior.sound()), PlaybackSettings::DESPAWN));
+    inventory.spend_shot();
+    let weapon = inventory.current_mut();
+    let delay = weapon.fire_rate / sheet.get(Stat::FireRate);
+    weapon.shoot_timer.set_duration(std::time::Duration::from_secs_f32(delay));
+    weapon.reset_timer();
+}
+
+/// Builds charge while the trigger is held and fires the charged shot once
+/// it is released.
+fn update_weapon_charge(
+    mut commands: Commands,
+    time: Res<Time>,
+    registry: Res<WeaponRegistry>,
+    mut player_q: Query<(&mut WeaponInventory, &StatSheet), With<crate::player::Player>>,
 ) {
-    let normalized_dir = dir.normalize_or_zero();
-
-    let mut bullet = commands.spawn((
-        Sprite::from_atlas_image(
-            bullet_res.0.clone(),
-            TextureAtlas {
-                layout: bullet_res.1.clone(),
-                index: 0,
-            },
-        ),
-        Transform {
-            translation: Vec3::new(pos.x, pos.y, 910.0),
-            scale: Vec3::splat(weapon.bullet_size),
-            ..Default::default()
-        },
-        AnimationTimer(Timer::from_seconds(0.2, TimerMode::Repeating)),
-        AnimationFrameCount(3),
-        Velocity(normalized_dir * weapon.bullet_speed),
-        Bullet,
-        BulletOwner::Player,
-        Collider {
-            half_extents: Vec2::splat(5.0),
-        },
-        BulletDamage(weapon.damage),
-        HitEnemies::default(),
-        GameEntity,
-    ));
-    let pierce = weapon.effective_pierce_count();
-    if pierce > 0 {
-        bullet.insert(Piercing(pierce));
+    let Ok((mut inv, sheet)) = player_q.single_mut() else { return; };
+    let held = inv.held_aim.take();
+    let Some(behavior) = registry.get(inv.current().id) else { return; };
+    let TriggerMode::Charge { full_secs } = behavior.trigger_mode() else { return; };
+
+    if let Some(aim) = held {
+        inv.charge_aim = aim;
+        let has_ammo = inv.has_ammo();
+        let weapon = inv.current_mut();
+        if weapon.can_shoot() && has_ammo {
+            weapon.charge = (weapon.charge + time.delta_secs() / full_secs).min(1.0);
+        }
+    } else if inv.current().charge > 0.0 {
+        let (pos, dir) = inv.charge_aim;
+        behavior.fire(&mut commands, &inv.current().scaled(sheet), pos, dir);
+        play_shot(&mut commands, &mut inv, sheet, behavior);
+        inv.current_mut().charge = 0.0;
     }
 }
 
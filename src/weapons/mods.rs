@@ -0,0 +1,353 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+use super::{BulletDamage, WeaponInventory, WeaponRegistry};
+use crate::broom::Deflected;
+use crate::bullet::{Bullet, BulletOwner, HitEnemies, Ricochet, Velocity};
+use crate::collidable::Collider;
+use crate::damage::{DamageEvent, DamageKind, DamageSource};
+use crate::enemies::{Enemy, Reaper};
+use crate::items::SentryShot;
+use crate::player::Player;
+use crate::status::{ApplyStatusEvent, StatusKind};
+use crate::GameEntity;
+
+/// Mods a single weapon can hold.
+pub const MOD_SLOTS: usize = 3;
+
+/// A per-weapon upgrade found in chests. Installed mods are copied onto
+/// every bullet the weapon fires as the matching component below.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WeaponMod {
+    Ricochet,
+    Split,
+    Homing,
+    Freeze,
+    ChainLightning,
+    VacuumPull,
+}
+
+pub const ALL_MODS: [WeaponMod; 6] = [
+    WeaponMod::Ricochet,
+    WeaponMod::Split,
+    WeaponMod::Homing,
+    WeaponMod::Freeze,
+    WeaponMod::ChainLightning,
+    WeaponMod::VacuumPull,
+];
+
+impl WeaponMod {
+    pub fn name(self) -> &'static str {
+        match self {
+            WeaponMod::Ricochet => "Ricochet",
+            WeaponMod::Split => "Splitter",
+            WeaponMod::Homing => "Seeker",
+            WeaponMod::Freeze => "Cryo",
+            WeaponMod::ChainLightning => "Arc",
+            WeaponMod::VacuumPull => "Vacuum",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            WeaponMod::Ricochet => "bounces off walls twice",
+            WeaponMod::Split => "splits in two on hit",
+            WeaponMod::Homing => "curves toward enemies",
+            WeaponMod::Freeze => "slows enemies it hits; three hits freeze them",
+            WeaponMod::ChainLightning => "arcs to nearby enemies, stunning them",
+            WeaponMod::VacuumPull => "drags enemies to the impact",
+        }
+    }
+}
+
+// ── Bullet components ──────────────────────────────────────────────────────
+
+/// Steers toward the nearest enemy. Honoured by `move_bullets`.
+#[derive(Component)]
+pub struct SeekEnemies {
+    pub turn_rate: f32,
+    pub range: f32,
+}
+
+#[derive(Component)]
+pub struct SplitOnHit;
+
+/// Fragment from a split bullet — never picks up mods of its own.
+#[derive(Component)]
+pub struct SplitShard;
+
+#[derive(Component)]
+pub struct FreezeOnHit;
+
+#[derive(Component)]
+pub struct ChainOnHit {
+    pub jumps: u32,
+}
+
+#[derive(Component)]
+pub struct VacuumOnHit;
+
+/// Sent by `bullet_collision` whenever a player bullet damages an enemy.
+#[derive(Event)]
+pub struct BulletHitEvent {
+    pub bullet: Entity,
+    pub enemy: Entity,
+    pub pos: Vec2,
+}
+
+#[derive(Component)]
+pub struct ChainArc(Timer);
+
+#[derive(Component)]
+pub struct WeaponInspectPanel;
+
+#[derive(Component)]
+pub struct WeaponInspectText;
+
+// ── Tuning ─────────────────────────────────────────────────────────────────
+
+const RICOCHET_BOUNCES: u32 = 2;
+const SEEK_TURN_RATE: f32 = 4.0;
+const SEEK_RANGE: f32 = 320.0;
+const SPLIT_ANGLE: f32 = 0.5;
+const SPLIT_DAMAGE_FRAC: f32 = 0.5;
+const CHILL_SECS: f32 = 2.5;
+const CHAIN_JUMPS: u32 = 3;
+const CHAIN_RANGE: f32 = 160.0;
+const CHAIN_DAMAGE_FRAC: f32 = 0.5;
+const CHAIN_STUN_SECS: f32 = 0.4;
+const VACUUM_RADIUS: f32 = 140.0;
+const VACUUM_PULL: f32 = 420.0;
+
+// ── Systems ────────────────────────────────────────────────────────────────
+
+/// Copies the equipped weapon's mods (and bounces from Ricochet Rounds
+/// pickups) onto bullets the player just fired. Bullets the broom sent back
+/// and sentry bolts weren't fired by the weapon and are left alone.
+pub fn apply_bullet_mods(
+    mut commands: Commands,
+    player_q: Query<&WeaponInventory, With<Player>>,
+    new_bullets: Query<(Entity, &BulletOwner), (Added<Bullet>, Without<SplitShard>, Without<Deflected>, Without<SentryShot>)>,
+) {
+    let Ok(inv) = player_q.single() else { return };
+    let weapon = inv.current();
+    let mods = &weapon.mods;
+    let bounces = weapon.bounce_pickups
+        + if mods.contains(&WeaponMod::Ricochet) { RICOCHET_BOUNCES } else { 0 };
+    if mods.is_empty() && bounces == 0 { return; }
+
+    for (entity, owner) in &new_bullets {
+        if !matches!(owner, BulletOwner::Player) { continue; }
+        let mut bullet = commands.entity(entity);
+        if bounces > 0 {
+            bullet.insert(Ricochet(bounces));
+        }
+        for m in mods {
+            match m {
+                WeaponMod::Ricochet => {}
+                WeaponMod::Split => { bullet.insert(SplitOnHit); }
+                WeaponMod::Homing => { bullet.insert(SeekEnemies { turn_rate: SEEK_TURN_RATE, range: SEEK_RANGE }); }
+                WeaponMod::Freeze => { bullet.insert(FreezeOnHit); }
+                WeaponMod::ChainLightning => { bullet.insert(ChainOnHit { jumps: CHAIN_JUMPS }); }
+                WeaponMod::VacuumPull => { bullet.insert(VacuumOnHit); }
+            }
+        }
+    }
+}
+
+/// On-hit effects for modded bullets: split, chill, chain and vacuum.
+pub fn resolve_bullet_mod_hits(
+    mut commands: Commands,
+    mut hits: EventReader<BulletHitEvent>,
+    bullets: Query<(
+        &Sprite,
+        &Transform,
+        &Velocity,
+        &BulletDamage,
+        Has<SplitOnHit>,
+        Has<FreezeOnHit>,
+        Option<&ChainOnHit>,
+        Has<VacuumOnHit>,
+    ), With<Bullet>>,
+    mut enemies: Query<
+        (Entity, &Transform, &mut crate::enemies::Velocity),
+        (With<Enemy>, Without<Reaper>, Without<Bullet>),
+    >,
+    mut statuses: EventWriter<ApplyStatusEvent>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    let mut chilled_now = HashSet::new();
+
+    for hit in hits.read() {
+        let Ok((sprite, tf, vel, damage, split, freeze, chain, vacuum)) = bullets.get(hit.bullet) else {
+            continue;
+        };
+
+        if split {
+            for side in [-1.0, 1.0] {
+                let mut shard_tf = *tf;
+                shard_tf.scale *= 0.7;
+                let mut seen = HitEnemies::default();
+                seen.0.insert(hit.enemy);
+                commands.spawn((
+                    sprite.clone(),
+                    shard_tf,
+                    Velocity(Vec2::from_angle(side * SPLIT_ANGLE).rotate(vel.0)),
+                    Bullet,
+                    BulletOwner::Player,
+                    Collider { half_extents: Vec2::splat(4.0) },
+                    BulletDamage(damage.0 * SPLIT_DAMAGE_FRAC),
+                    seen,
+                    SplitShard,
+                    GameEntity,
+                ));
+            }
+        }
+
+        // Enough chill stacks freeze the enemy solid (see `status`).
+        if freeze && chilled_now.insert(hit.enemy) {
+            statuses.write(ApplyStatusEvent { target: hit.enemy, kind: StatusKind::Slowed, stacks: 1, secs: CHILL_SECS });
+        }
+
+        if let Some(chain) = chain {
+            let mut from = hit.pos;
+            let mut struck = vec![hit.enemy];
+            for _ in 0..chain.jumps {
+                let next = enemies
+                    .iter()
+                    .filter(|(e, ..)| !struck.contains(e))
+                    .map(|(e, t, ..)| (e, t.translation.truncate()))
+                    .filter(|(_, p)| p.distance(from) <= CHAIN_RANGE)
+                    .min_by(|a, b| a.1.distance(from).total_cmp(&b.1.distance(from)));
+                let Some((e, p)) = next else { break };
+                damage_events.write(DamageEvent {
+                    source: DamageSource::Player,
+                    target: e,
+                    amount: damage.0 * CHAIN_DAMAGE_FRAC,
+                    kind: DamageKind::Bullet,
+                });
+                statuses.write(ApplyStatusEvent { target: e, kind: StatusKind::Stunned, stacks: 1, secs: CHAIN_STUN_SECS });
+                spawn_arc(&mut commands, from, p);
+                struck.push(e);
+                from = p;
+            }
+        }
+
+        if vacuum {
+            for (_, etf, mut evel) in &mut enemies {
+                let offset = hit.pos - etf.translation.truncate();
+                let dist = offset.length();
+                if dist <= VACUUM_RADIUS && dist > 1.0 {
+                    evel.velocity += offset / dist * VACUUM_PULL * (1.0 - dist / VACUUM_RADIUS * 0.5);
+                }
+            }
+        }
+    }
+}
+
+fn spawn_arc(commands: &mut Commands, from: Vec2, to: Vec2) {
+    let d = to - from;
+    let mid = (from + to) * 0.5;
+    commands.spawn((
+        Sprite {
+            color: Color::srgba(0.6, 0.85, 1.0, 0.9),
+            custom_size: Some(Vec2::new(d.length(), 3.0)),
+            ..default()
+        },
+        Transform::from_xyz(mid.x, mid.y, 905.0).with_rotation(Quat::from_rotation_z(d.y.atan2(d.x))),
+        ChainArc(Timer::from_seconds(0.15, TimerMode::Once)),
+        GameEntity,
+    ));
+}
+
+pub fn fade_chain_arcs(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut arcs: Query<(Entity, &mut Sprite, &mut ChainArc)>,
+) {
+    for (entity, mut sprite, mut arc) in &mut arcs {
+        arc.0.tick(time.delta());
+        sprite.color.set_alpha(0.9 * (1.0 - arc.0.fraction()));
+        if arc.0.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Install a mod on the equipped weapon, or the first weapon with a free
+/// slot. With every slot taken it replaces the equipped weapon's oldest
+/// mod. Returns the weapon index it went on.
+pub fn install_mod(inv: &mut WeaponInventory, m: WeaponMod) -> usize {
+    let fits = |w: &super::Weapon| w.mods.len() < MOD_SLOTS && !w.mods.contains(&m);
+    let index = if fits(inv.current()) {
+        inv.equipped
+    } else {
+        inv.weapons.iter().position(fits).unwrap_or(inv.equipped)
+    };
+    let mods = &mut inv.weapons[index].mods;
+    if mods.contains(&m) {
+        return index;
+    }
+    if mods.len() >= MOD_SLOTS {
+        mods.remove(0);
+    }
+    mods.push(m);
+    index
+}
+
+// ── Inspect panel ──────────────────────────────────────────────────────────
+
+pub fn spawn_inspect_panel(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(10.0),
+                bottom: Val::Px(50.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+            Visibility::Hidden,
+            WeaponInspectPanel,
+            GameEntity,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(""),
+                TextFont { font: asset_server.load(crate::FONT_PATH), font_size: 14.0, ..default() },
+                TextColor(Color::WHITE),
+                WeaponInspectText,
+            ));
+        });
+}
+
+/// I toggles the panel listing every carried weapon and its mod slots.
+pub fn update_inspect_panel(
+    input: Res<ButtonInput<KeyCode>>,
+    registry: Res<WeaponRegistry>,
+    player_q: Query<&WeaponInventory, With<Player>>,
+    mut panel_q: Query<&mut Visibility, With<WeaponInspectPanel>>,
+    mut text_q: Query<&mut Text, With<WeaponInspectText>>,
+) {
+    let Ok(mut vis) = panel_q.single_mut() else { return };
+    if input.just_pressed(KeyCode::KeyI) {
+        *vis = if *vis == Visibility::Hidden { Visibility::Visible } else { Visibility::Hidden };
+    }
+    if *vis == Visibility::Hidden { return; }
+
+    let (Ok(inv), Ok(mut text)) = (player_q.single(), text_q.single_mut()) else { return };
+    let mut out = String::from("Weapons (I to close)\n");
+    for (i, weapon) in inv.weapons.iter().enumerate() {
+        let marker = if i == inv.equipped { ">" } else { " " };
+        out.push_str(&format!("\n{marker} {}\n", registry.name(weapon.id)));
+        for slot in 0..MOD_SLOTS {
+            match weapon.mods.get(slot) {
+                Some(m) => out.push_str(&format!("    [{}] {}\n", m.name(), m.description())),
+                None => out.push_str("    [ empty ]\n"),
+            }
+        }
+    }
+    if text.0 != out {
+        text.0 = out;
+    }
+}
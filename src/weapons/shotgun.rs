@@ -0,0 +1,125 @@
+use bevy::prelude::*;
+use super::{Ammo, BulletDamage, Weapon, WeaponBehavior, WeaponId};
+use crate::bullet::{Bullet, BulletOwner, Velocity, AnimationTimer, AnimationFrameCount, Piercing, HitEnemies, MarkedForDespawn};
+use crate::collidable::Collider;
+use crate::GameEntity;
+
+pub const ID: WeaponId = WeaponId("shotgun");
+
+const PELLETS: usize = 7;
+const SPREAD: f32 = 0.5;
+/// Pellets hit for full damage up to here...
+const FALLOFF_START: f32 = 90.0;
+/// ...fade to `MIN_DAMAGE_FRAC` by here, and vanish past it.
+const MAX_RANGE: f32 = 320.0;
+const MIN_DAMAGE_FRAC: f32 = 0.3;
+const PELLET_TINT: Color = Color::srgb(1.0, 0.9, 0.45);
+
+/// Close-range spread. Each pellet loses damage the further it flies.
+#[derive(Default)]
+pub struct Shotgun {
+    bullet: Handle<Image>,
+    layout: Handle<TextureAtlasLayout>,
+    sound: Handle<AudioSource>,
+}
+
+/// Where a pellet was fired from and what it hit for point-blank.
+#[derive(Component)]
+pub struct PelletFalloff {
+    origin: Vec2,
+    base_damage: f32,
+}
+
+impl WeaponBehavior for Shotgun {
+    fn id(&self) -> WeaponId { ID }
+
+    fn name(&self) -> &'static str { "Shotgun" }
+
+    fn base_weapon(&self) -> Weapon {
+        Weapon {
+            id: ID,
+            fire_rate: 0.75,
+            bullet_speed: 800.0,
+            damage: 12.0,
+            bullet_size: 0.15,
+            shoot_timer: Timer::from_seconds(0.75, TimerMode::Once),
+            piercing_pickups: 0,
+            bounce_pickups: 0,
+            charge: 0.0,
+            mods: Vec::new(),
+            ammo: Ammo::magazine(6, 24, 1.5),
+        }
+    }
+
+    fn load(&mut self, asset_server: &AssetServer, atlases: &mut Assets<TextureAtlasLayout>) {
+        self.bullet = asset_server.load("bullet_animation.png");
+        self.layout = atlases.add(TextureAtlasLayout::from_grid(UVec2::splat(100), 3, 1, None, None));
+        self.sound = asset_server.load("audio/shoot.ogg");
+    }
+
+    fn fire(&self, commands: &mut Commands, weapon: &Weapon, pos: Vec2, dir: Vec2) {
+        let base_angle = dir.y.atan2(dir.x);
+        let pierce = weapon.effective_pierce_count();
+
+        for i in 0..PELLETS {
+            let t = i as f32 / (PELLETS - 1) as f32 - 0.5;
+            let angle = base_angle + t * SPREAD + rand::random_range(-0.04..0.04);
+            let speed = weapon.bullet_speed * rand::random_range(0.9..1.1);
+
+            let mut sprite = Sprite::from_atlas_image(
+                self.bullet.clone(),
+                TextureAtlas { layout: self.layout.clone(), index: 0 },
+            );
+            sprite.color = PELLET_TINT;
+
+            let mut pellet = commands.spawn((
+                sprite,
+                Transform {
+                    translation: Vec3::new(pos.x, pos.y, 910.0),
+                    scale: Vec3::splat(weapon.bullet_size),
+                    ..Default::default()
+                },
+                AnimationTimer(Timer::from_seconds(0.1, TimerMode::Repeating)),
+                AnimationFrameCount(3),
+                Velocity(Vec2::from_angle(angle) * speed),
+                Bullet,
+                BulletOwner::Player,
+                Collider { half_extents: Vec2::splat(3.0) },
+                BulletDamage(weapon.damage),
+                HitEnemies::default(),
+                PelletFalloff { origin: pos, base_damage: weapon.damage },
+                GameEntity,
+            ));
+            if pierce > 0 {
+                pellet.insert(Piercing(pierce));
+            }
+        }
+    }
+
+    fn sound(&self) -> Handle<AudioSource> { self.sound.clone() }
+
+    fn hud_icon(&self) -> ImageNode {
+        ImageNode::from_atlas_image(
+            self.bullet.clone(),
+            TextureAtlas { layout: self.layout.clone(), index: 0 },
+        )
+        .with_color(PELLET_TINT)
+    }
+}
+
+/// Scales pellet damage down with distance travelled and drops pellets that
+/// have flown past their range.
+pub fn pellet_falloff(
+    mut commands: Commands,
+    mut pellets: Query<(Entity, &Transform, &PelletFalloff, &mut BulletDamage), Without<MarkedForDespawn>>,
+) {
+    for (entity, tf, falloff, mut damage) in &mut pellets {
+        let dist = tf.translation.truncate().distance(falloff.origin);
+        if dist > MAX_RANGE {
+            commands.entity(entity).try_insert(MarkedForDespawn);
+            continue;
+        }
+        let t = ((dist - FALLOFF_START) / (MAX_RANGE - FALLOFF_START)).clamp(0.0, 1.0);
+        damage.0 = falloff.base_damage * (1.0 - t * (1.0 - MIN_DAMAGE_FRAC));
+    }
+}
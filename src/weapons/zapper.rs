@@ -1,14 +1,86 @@
 use bevy::prelude::*;
-use super::{Weapon, WeaponType};
-
-pub fn new() -> Weapon {
-    Weapon {
-        weapon_type: WeaponType::Zapper,
-        fire_rate: 0.7,
-        bullet_speed: 700.0,
-        damage: 25.0,
-        bullet_size: 0.25,
-        shoot_timer: Timer::from_seconds(0.5, TimerMode::Once),
-        piercing_pickups: 0,
+use super::{Ammo, BulletDamage, Weapon, WeaponBehavior, WeaponId};
+use crate::bullet::{Bullet, BulletOwner, Velocity, AnimationTimer, AnimationFrameCount, Piercing, HitEnemies};
+use crate::collidable::Collider;
+use crate::GameEntity;
+
+pub const ID: WeaponId = WeaponId("zapper");
+
+/// The starting sidearm: animated energy bolts that can pick up piercing.
+#[derive(Default)]
+pub struct Zapper {
+    bullet: Handle<Image>,
+    layout: Handle<TextureAtlasLayout>,
+    sound: Handle<AudioSource>,
+}
+
+impl WeaponBehavior for Zapper {
+    fn id(&self) -> WeaponId { ID }
+
+    fn name(&self) -> &'static str { "Zapper" }
+
+    fn base_weapon(&self) -> Weapon {
+        Weapon {
+            id: ID,
+            fire_rate: 0.7,
+            bullet_speed: 700.0,
+            damage: 25.0,
+            bullet_size: 0.25,
+            shoot_timer: Timer::from_seconds(0.5, TimerMode::Once),
+            piercing_pickups: 0,
+            bounce_pickups: 0,
+            charge: 0.0,
+            mods: Vec::new(),
+            ammo: Ammo::Unlimited,
+        }
+    }
+
+    fn load(&mut self, asset_server: &AssetServer, atlases: &mut Assets<TextureAtlasLayout>) {
+        self.bullet = asset_server.load("bullet_animation.png");
+        self.layout = atlases.add(TextureAtlasLayout::from_grid(UVec2::splat(100), 3, 1, None, None));
+        self.sound = asset_server.load("audio/laser_zap.ogg");
+    }
+
+    fn fire(&self, commands: &mut Commands, weapon: &Weapon, pos: Vec2, dir: Vec2) {
+        let normalized_dir = dir.normalize_or_zero();
+
+        let mut bullet = commands.spawn((
+            Sprite::from_atlas_image(
+                self.bullet.clone(),
+                TextureAtlas {
+                    layout: self.layout.clone(),
+                    index: 0,
+                },
+            ),
+            Transform {
+                translation: Vec3::new(pos.x, pos.y, 910.0),
+                scale: Vec3::splat(weapon.bullet_size),
+                ..Default::default()
+            },
+            AnimationTimer(Timer::from_seconds(0.2, TimerMode::Repeating)),
+            AnimationFrameCount(3),
+            Velocity(normalized_dir * weapon.bullet_speed),
+            Bullet,
+            BulletOwner::Player,
+            Collider {
+                half_extents: Vec2::splat(5.0),
+            },
+            BulletDamage(weapon.damage),
+            HitEnemies::default(),
+            GameEntity,
+        ));
+        let pierce = weapon.effective_pierce_count();
+        if pierce > 0 {
+            bullet.insert(Piercing(pierce));
+        }
+    }
+
+    fn sound(&self) -> Handle<AudioSource> { self.sound.clone() }
+
+    fn hud_icon(&self) -> ImageNode {
+        ImageNode::from_atlas_image(
+            self.bullet.clone(),
+            TextureAtlas { layout: self.layout.clone(), index: 0 },
+        )
     }
 }
@@ -1,5 +1,8 @@
 use bevy::prelude::*;
 
+/// HP of a freshly placed or fully sealed window.
+pub const MAX_HEALTH: f32 = 50.0;
+
 #[derive(Component)]
 pub struct Window;
 